@@ -1,5 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use jlrs::{
+    data::managed::array::data::accessor::Accessor,
     memory::{gc::Gc, target::frame::GcFrame},
     prelude::*,
 };
@@ -349,6 +350,36 @@ fn set_ranked_array_2d_set_arr_bits(frame: &mut GcFrame, c: &mut Criterion) {
     })
 }
 
+#[inline(never)]
+fn access_vector_any_get_value(frame: &mut GcFrame, c: &mut Criterion) {
+    frame.scope(|mut frame| {
+        let arr = unsafe { Value::eval_string(&mut frame, "Any[:foo, :bar, :baz, :qux]") }
+            .unwrap()
+            .cast::<VectorAny>()
+            .unwrap();
+        let accessor = unsafe { arr.value_data() };
+
+        c.bench_function("VectorAny_get_value", |b| {
+            b.iter(|| black_box(accessor.get_value(&frame, 2)))
+        });
+    })
+}
+
+#[inline(never)]
+fn access_vector_any_get_value_checked(frame: &mut GcFrame, c: &mut Criterion) {
+    frame.scope(|mut frame| {
+        let arr = unsafe { Value::eval_string(&mut frame, "Any[:foo, :bar, :baz, :qux]") }
+            .unwrap()
+            .cast::<VectorAny>()
+            .unwrap();
+        let accessor = unsafe { arr.value_data() };
+
+        c.bench_function("VectorAny_get_value_checked", |b| {
+            b.iter(|| black_box(accessor.get_value_checked(&frame, 2)))
+        });
+    })
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     let mut julia = Builder::new().start_local().unwrap();
 
@@ -437,6 +468,12 @@ fn criterion_benchmark(c: &mut Criterion) {
 
             frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
             access_ranked_array_2d_get_arr_bits(&mut frame, c);
+
+            frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+            access_vector_any_get_value(&mut frame, c);
+
+            frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+            access_vector_any_get_value_checked(&mut frame, c);
         });
     })
 }
@@ -233,6 +233,10 @@ extern "C" {
 
     pub fn jl_gc_collect(arg0: crate::types::jl_gc_collection_t);
 
+    pub fn jl_gc_get_total_bytes(bytes: *mut i64);
+
+    pub fn jl_gc_live_bytes() -> i64;
+
     pub fn jl_gc_add_finalizer(v: *mut crate::types::jl_value_t, f: *mut crate::types::jl_value_t);
 
     pub fn jl_gc_add_ptr_finalizer(
@@ -513,6 +517,11 @@ extern "C" {
         s: *mut crate::types::jl_sym_t,
     ) -> std::ffi::c_int;
 
+    pub fn jl_module_exports_p(
+        m: *mut crate::types::jl_module_t,
+        var: *mut crate::types::jl_sym_t,
+    ) -> std::ffi::c_int;
+
     pub fn jl_cpu_threads() -> std::ffi::c_int;
 
     pub fn jl_is_debugbuild() -> std::ffi::c_int;
@@ -561,7 +570,7 @@ extern "C" {
 
     pub fn jl_throw(e: *mut crate::types::jl_value_t) -> !;
 
-    // pub fn jl_process_events() -> std::ffi::c_int;
+    pub fn jl_process_events() -> std::ffi::c_int;
 
     pub fn jl_stdout_stream() -> *mut crate::types::JL_STREAM;
 
@@ -940,6 +949,13 @@ extern "C" {
         result: *mut std::ffi::c_void,
     ) -> crate::types::jlrs_catch_t;
 
+    pub fn jlrs_try_catch_with_backtrace(
+        callback: *mut std::ffi::c_void,
+        trampoline: crate::types::jlrs_try_catch_trampoline_t,
+        result: *mut std::ffi::c_void,
+        backtrace: *mut *mut crate::types::jl_value_t,
+    ) -> crate::types::jlrs_catch_t;
+
     pub fn jlrs_dimtuple_type(rank: usize) -> *mut crate::types::jl_datatype_t;
 
     pub fn jlrs_tuple_of(
@@ -953,6 +969,13 @@ extern "C" {
         nargs: u32,
     ) -> *mut crate::types::jl_value_t;
 
+    pub fn jlrs_call_in_world(
+        f: *mut crate::types::jl_value_t,
+        args: *mut *mut crate::types::jl_value_t,
+        nargs: u32,
+        world: usize,
+    ) -> *mut crate::types::jl_value_t;
+
     pub fn jlrs_datatype_layout(
         t: *mut crate::types::jl_datatype_t,
     ) -> *const crate::types::jl_datatype_layout_t;
@@ -1096,6 +1119,25 @@ extern "C" {
 
     pub fn jlrs_set_nthreads_per_pool(nthreads_per_pool: *const i16);
 
+    pub fn jlrs_set_code_coverage(code_coverage: i8);
+
+    pub fn jlrs_set_malloc_log(malloc_log: i8);
+
+    pub fn jlrs_set_cpu_target(cpu_target: *const std::os::raw::c_char);
+
+    pub fn jlrs_set_nmarkthreads(nmarkthreads: i16);
+
+    pub fn jlrs_set_trace_compile(trace_compile: *const std::os::raw::c_char);
+
+    #[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11",)))]
+    pub fn jlrs_set_trace_dispatch(trace_dispatch: *const std::os::raw::c_char);
+
+    pub fn jlrs_set_nsweepthreads(nsweepthreads: i8);
+
+    pub fn jlrs_set_heap_size_hint(heap_size_hint: u64);
+
+    pub fn jlrs_get_nsweepthreads() -> i8;
+
     // Added in Julia 1.11
 
     #[cfg(not(any(feature = "julia-1-10",)))]
@@ -38,6 +38,34 @@ extern "C"
         return res;
     }
 
+    jlrs_catch_t jlrs_try_catch_with_backtrace(void *callback, jlrs_try_catch_trampoline_t trampoline, void *result, jl_value_t **backtrace)
+    {
+        jlrs_catch_t res = {JLRS_CATCH_OK, 0};
+        *backtrace = NULL;
+
+#ifndef JLRS_WINDOWS_LTS
+        JL_TRY
+        {
+            res = trampoline(callback, result);
+        }
+        JL_CATCH
+        {
+#if JULIA_VERSION_MINOR >= 11
+            jl_value_t *exc = jl_current_exception(jl_current_task);
+#else
+            jl_value_t *exc = jl_current_exception();
+#endif
+            // The backtrace must be captured while the exception is still on the exception stack.
+            *backtrace = jl_get_backtrace();
+            jlrs_catch_t the_exc = {JLRS_CATCH_EXCEPTION, exc};
+            return the_exc;
+        }
+#else
+    res = trampoline(callback, result);
+#endif
+        return res;
+    }
+
     jl_value_t *jlrs_call_unchecked(jl_function_t *f, jl_value_t **args, uint32_t nargs)
     {
         jl_value_t *v;
@@ -56,6 +84,24 @@ extern "C"
         return v;
     }
 
+    jl_value_t *jlrs_call_in_world(jl_function_t *f, jl_value_t **args, uint32_t nargs, size_t world)
+    {
+        jl_value_t *v;
+        jl_task_t *ct = jl_current_task;
+        nargs++; // add f to args
+        jl_value_t **argv;
+        JL_GC_PUSHARGS(argv, nargs);
+        argv[0] = (jl_value_t *)f;
+        for (unsigned int i = 1; i < nargs; i++)
+            argv[i] = args[i - 1];
+        size_t last_age = ct->world_age;
+        ct->world_age = world;
+        v = jl_apply(argv, nargs);
+        ct->world_age = last_age;
+        JL_GC_POP();
+        return v;
+    }
+
 #if JULIA_VERSION_MINOR <= 10
     const jl_datatype_layout_t *jl_datatype_layout(jl_datatype_t *t)
     {
@@ -103,6 +149,53 @@ extern "C"
         jl_options.nthreads_per_pool = nthreads_per_pool;
     }
 
+    void jlrs_set_code_coverage(int8_t code_coverage)
+    {
+        jl_options.code_coverage = code_coverage;
+    }
+
+    void jlrs_set_malloc_log(int8_t malloc_log)
+    {
+        jl_options.malloc_log = malloc_log;
+    }
+
+    void jlrs_set_cpu_target(const char *cpu_target)
+    {
+        jl_options.cpu_target = cpu_target;
+    }
+
+    void jlrs_set_nmarkthreads(int16_t nmarkthreads)
+    {
+        jl_options.nmarkthreads = nmarkthreads;
+    }
+
+    void jlrs_set_trace_compile(const char *trace_compile)
+    {
+        jl_options.trace_compile = trace_compile;
+    }
+
+#if JULIA_VERSION_MINOR >= 12
+    void jlrs_set_trace_dispatch(const char *trace_dispatch)
+    {
+        jl_options.trace_dispatch = trace_dispatch;
+    }
+#endif
+
+    void jlrs_set_nsweepthreads(int8_t nsweepthreads)
+    {
+        jl_options.nsweepthreads = nsweepthreads;
+    }
+
+    void jlrs_set_heap_size_hint(uint64_t heap_size_hint)
+    {
+        jl_options.heap_size_hint = heap_size_hint;
+    }
+
+    int8_t jlrs_get_nsweepthreads(void)
+    {
+        return jl_options.nsweepthreads;
+    }
+
     jl_datatype_t *jlrs_dimtuple_type(size_t rank)
     {
         // printf("Rank %zu\n", rank);
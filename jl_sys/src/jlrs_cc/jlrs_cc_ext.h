@@ -27,11 +27,16 @@ extern "C"
 
     void jlrs_unsized_scope(size_t frame_size, jlrs_unsized_scope_trampoline_t trampoline, void *callback, void *result);
     jlrs_catch_t jlrs_try_catch(void *callback, jlrs_try_catch_trampoline_t trampoline, void *result);
+    jlrs_catch_t jlrs_try_catch_with_backtrace(void *callback, jlrs_try_catch_trampoline_t trampoline, void *result, jl_value_t **backtrace);
+
+    // Exported by libjulia, but not declared in the public headers.
+    JL_DLLEXPORT jl_value_t *jl_get_backtrace(void);
 
     jl_datatype_t *jlrs_dimtuple_type(size_t rank);
     jl_value_t *jlrs_tuple_of(jl_value_t **values, size_t n);
 
     jl_value_t *jlrs_call_unchecked(jl_function_t *f, jl_value_t **args, uint32_t nargs);
+    jl_value_t *jlrs_call_in_world(jl_function_t *f, jl_value_t **args, uint32_t nargs, size_t world);
 
     int jlrs_datatype_has_layout(jl_datatype_t *t);
 
@@ -56,6 +61,18 @@ extern "C"
 
     void jlrs_set_nthreadpools(int8_t nthreadpools);
     void jlrs_set_nthreads_per_pool(const int16_t *nthreads_per_pool);
+    void jlrs_set_code_coverage(int8_t code_coverage);
+    void jlrs_set_malloc_log(int8_t malloc_log);
+    void jlrs_set_cpu_target(const char *cpu_target);
+    void jlrs_set_nmarkthreads(int16_t nmarkthreads);
+    void jlrs_set_trace_compile(const char *trace_compile);
+#if JULIA_VERSION_MINOR >= 12
+    void jlrs_set_trace_dispatch(const char *trace_dispatch);
+#endif
+    void jlrs_set_nsweepthreads(int8_t nsweepthreads);
+    void jlrs_set_heap_size_hint(uint64_t heap_size_hint);
+    // option field getters
+    int8_t jlrs_get_nsweepthreads(void);
     // tvar field getters
     jl_sym_t *jlrs_tvar_name(jl_tvar_t *tvar);
     jl_value_t *jlrs_tvar_lb(jl_tvar_t *tvar);
@@ -0,0 +1,62 @@
+//! Cancel async tasks.
+//!
+//! A [`CancellationToken`] is a shared flag that can be used to cancel an [`AsyncTask`]. The
+//! token is returned by [`AsyncTask::cancellation_token`], the async runtime checks it before
+//! the task is started. If the token has been cancelled by then, the task is skipped and no
+//! result is sent, so the receiving half of the result channel is closed.
+//!
+//! A running task is never interrupted by the runtime. Dropping it while it awaits a Julia task
+//! would be unsound, because that Julia task keeps running and can still use the data owned by
+//! the dropped task. Instead, a running task can check its token cooperatively with
+//! [`CancellationToken::check`], e.g. between two calls, and return early if it has been
+//! cancelled.
+//!
+//! Cancelling a token after the task has completed has no effect. All async tasks that have been
+//! dispatched with an [`AsyncHandle`] can be cancelled with [`AsyncHandle::cancel_all`].
+//!
+//! [`AsyncTask`]: crate::async_util::task::AsyncTask
+//! [`AsyncTask::cancellation_token`]: crate::async_util::task::AsyncTask::cancellation_token
+//! [`AsyncHandle`]: crate::runtime::handle::async_handle::AsyncHandle
+//! [`AsyncHandle::cancel_all`]: crate::runtime::handle::async_handle::AsyncHandle::cancel_all
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::error::{JlrsError, JlrsResult};
+
+/// A shared flag that can be used to cancel async tasks.
+///
+/// Clones of a token share the same flag, a cancelled token can't be reset.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a new token that hasn't been cancelled.
+    pub fn new() -> Self {
+        CancellationToken(Arc::new(AtomicBool::new(false)))
+    }
+
+    /// Cancel this token and all its clones.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release)
+    }
+
+    /// Returns `true` if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Returns `JlrsError::Cancelled` if this token has been cancelled.
+    ///
+    /// This can be used inside a task to stop before doing more work, e.g. between two calls
+    /// that don't suspend the task.
+    pub fn check(&self) -> JlrsResult<()> {
+        if self.is_cancelled() {
+            Err(JlrsError::Cancelled)?
+        }
+
+        Ok(())
+    }
+}
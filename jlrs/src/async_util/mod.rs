@@ -1,2 +1,3 @@
+pub mod cancellation_token;
 pub mod future;
 pub mod task;
@@ -1,6 +1,7 @@
 use std::{future::Future, time::Duration};
 
 use crate::{
+    async_util::{cancellation_token::CancellationToken, future::GcSafeFuture},
     call::Call,
     inline_static_ref,
     prelude::{AsyncGcFrame, JlrsResult, Target, Value},
@@ -21,6 +22,18 @@ pub trait AsyncTask: 'static + Send {
 
     /// Run this task.
     fn run<'frame>(self, frame: AsyncGcFrame<'frame>) -> impl Future<Output = Self::Output>;
+
+    /// Returns the token that can be used to cancel this task.
+    ///
+    /// This method is called once before the task is started. By default `None` is returned and
+    /// the task can only be cancelled with [`AsyncHandle::cancel_all`]. See the
+    /// [`cancellation_token`] module for more information.
+    ///
+    /// [`AsyncHandle::cancel_all`]: crate::runtime::handle::async_handle::AsyncHandle::cancel_all
+    /// [`cancellation_token`]: crate::async_util::cancellation_token
+    fn cancellation_token(&self) -> Option<CancellationToken> {
+        None
+    }
 }
 
 /// Persistent task
@@ -68,7 +81,10 @@ pub trait PersistentTask: 'static + Send {
 
     /// Method that is called when all handles to the task have been dropped.
     ///
-    /// This method is called with the same frame as `init`.
+    /// This method is called with the same frame as `init` after the last call to `run` has
+    /// completed, and can be used to clean up the state, e.g. by closing files or flushing
+    /// buffers. The runtime doesn't shut down until all persistent tasks have exited, so this
+    /// method is also called if the `AsyncHandle` is dropped before the `PersistentHandle`.
     fn exit<'task>(
         &mut self,
         _frame: AsyncGcFrame<'task>,
@@ -78,6 +94,77 @@ pub trait PersistentTask: 'static + Send {
     }
 }
 
+/// Adapter that turns a Rust future into an [`AsyncTask`].
+///
+/// Many futures, like those returned by an async database driver, don't need access to Julia
+/// while they're pending but their output must be processed with Julia. A `FutureTask` awaits
+/// such a future and calls the continuation with the result once it has resolved. The future is
+/// polled on the runtime thread in a GC-safe state, so the garbage collector can run while it's
+/// pending. The continuation is called with the task's frame and can interact with Julia.
+///
+/// ```
+/// # use jlrs::{async_util::task::FutureTask, prelude::*};
+/// # fn main() {
+/// let (julia, thread_handle) = Builder::new()
+///     .async_runtime(Tokio::<1>::new(false))
+///     .spawn()
+///     .unwrap();
+///
+/// // The future stands in for some async operation that doesn't need Julia.
+/// let task = FutureTask::new(async { 4.0f64 }, |mut frame, x: f64| {
+///     Value::new(&mut frame, x.sqrt()).unbox::<f64>()
+/// });
+///
+/// let res = julia
+///     .task(task)
+///     .try_dispatch()
+///     .expect("unable to dispatch task")
+///     .blocking_recv()
+///     .expect("unable to receive result")
+///     .expect("FutureTask failed");
+///
+/// assert_eq!(res, 2.0);
+///
+/// std::mem::drop(julia);
+/// thread_handle.join().unwrap();
+/// # }
+/// ```
+pub struct FutureTask<F, C> {
+    future: F,
+    continuation: C,
+}
+
+impl<F, C, U> FutureTask<F, C>
+where
+    F: Future + Send + 'static,
+    C: for<'frame> FnOnce(AsyncGcFrame<'frame>, F::Output) -> U + Send + 'static,
+    U: Send + 'static,
+{
+    /// Create a new `FutureTask` that awaits `future` and calls `continuation` with its output.
+    #[inline]
+    pub fn new(future: F, continuation: C) -> Self {
+        FutureTask {
+            future,
+            continuation,
+        }
+    }
+}
+
+impl<F, C, U> AsyncTask for FutureTask<F, C>
+where
+    F: Future + Send + 'static,
+    C: for<'frame> FnOnce(AsyncGcFrame<'frame>, F::Output) -> U + Send + 'static,
+    U: Send + 'static,
+{
+    type Output = U;
+
+    async fn run<'frame>(self, frame: AsyncGcFrame<'frame>) -> Self::Output {
+        // Safety: tasks are always polled on a thread known to Julia.
+        let output = unsafe { GcSafeFuture::new(self.future) }.await;
+        (self.continuation)(frame, output)
+    }
+}
+
 /// Sleep for `duration`.
 ///
 /// The function calls `Base.sleep`. If `duration` is less than 1ms this function returns
@@ -2,7 +2,8 @@
 //!
 //! This module provides the [`Call`], [`CallAsync`] and [`ProvideKeywords`] traits. Their methods
 //! can be used to call Julia functions, including inner and outer constructors; schedule a
-//! function call as a new Julia task; and provide keyword arguments respectively.
+//! function call as a new Julia task; and provide keyword arguments respectively. A function can
+//! also be broadcast into an existing array with [`Value::broadcast_into`].
 //!
 //! Let's add a few numbers with Julia's `+` function:
 //!
@@ -280,11 +281,17 @@ use jl_sys::{jl_call, jl_exception_occurred, jl_kwcall_func, jlrs_call_unchecked
 use crate::{
     args::Values,
     data::managed::{
+        array::{dimensions::Dims, Array, ArrayBase},
         private::ManagedPriv,
         value::{Value, ValueResult},
+        Managed,
     },
     error::{AccessError, JlrsResult},
-    memory::{context::ledger::Ledger, target::Target},
+    inline_static_ref,
+    memory::{
+        context::ledger::Ledger,
+        target::{Target, TargetException},
+    },
     prelude::ValueData,
     private::Private,
 };
@@ -606,14 +613,100 @@ impl<'data> Call<'data> for WithKeywords<'_, 'data> {
     }
 }
 
+impl<'scope, 'data> Value<'scope, 'data> {
+    /// Broadcast this function over `args` and write the result to `dest`, this is equivalent
+    /// to `broadcast!(self, dest, args...)` or `dest .= self.(args...)` in Julia.
+    ///
+    /// No new array is allocated for the result, the data of `dest` is overwritten. The shape of
+    /// each array in `args` must be compatible with the shape of `dest`, i.e. every dimension
+    /// must either be 1 or equal to the corresponding dimension of `dest`. If it isn't,
+    /// `AccessError::BroadcastShapeMismatch` is returned. Other arguments are checked by Julia, if
+    /// Julia throws an exception it's caught and returned.
+    ///
+    /// Safety: this method lets you call arbitrary Julia functions which can't be checked for
+    /// correctness. More information can be found in the [`safety`] module. No accessors to the
+    /// data of `dest` or any of the arguments must exist.
+    ///
+    /// [`safety`]: crate::safety
+    pub unsafe fn broadcast_into<'target, 'value, T, V, Tgt, const M: isize, const N: usize>(
+        self,
+        target: Tgt,
+        dest: ArrayBase<'value, 'data, T, M>,
+        args: V,
+    ) -> JlrsResult<TargetException<'target, 'data, (), Tgt>>
+    where
+        'scope: 'value,
+        V: Values<'value, 'data, N>,
+        Tgt: Target<'target>,
+    {
+        let dest_dims = dest.dimensions().to_dimensions();
+        for arg in args.as_slice(Private).iter().copied() {
+            let Ok(arr) = arg.cast::<Array>() else {
+                continue;
+            };
+
+            let arg_dims = arr.dimensions().to_dimensions();
+            let compatible = arg_dims.as_slice().iter().enumerate().all(|(i, &n)| {
+                let dest_n = dest_dims.as_slice().get(i).copied().unwrap_or(1);
+                n == 1 || n == dest_n
+            });
+
+            if !compatible {
+                Err(AccessError::BroadcastShapeMismatch {
+                    arg: arg_dims,
+                    dest: dest_dims.clone(),
+                })?;
+            }
+        }
+
+        let func = inline_static_ref!(BROADCAST_INTO, Value, "Base.broadcast!", &target);
+        let args = args.into_extended_with_start([self, dest.as_value()], Private);
+        let res = match func.call(&target, args.as_ref()) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.ptr()),
+        };
+
+        Ok(target.exception_from_ptr(res, Private))
+    }
+
+    /// Call this function with `args`, and pass the exception to `handler` if one is thrown.
+    ///
+    /// If the call succeeds, the result is rooted in `target` and returned. Otherwise the
+    /// exception is rooted in `target` and `handler` is called with it. The handler can inspect
+    /// the exception, e.g. to check if it's a `BoundsError` or a `MethodError`, and either
+    /// return a replacement or convert it to an error. Unlike converting the result with
+    /// [`IntoJlrsResult`], the exception isn't converted to a string. At most one slot of
+    /// `target` is used.
+    ///
+    /// Safety: this method lets you call arbitrary Julia functions which can't be checked for
+    /// correctness. More information can be found in the [`safety`] module. This method doesn't
+    /// check if any of the arguments is currently borrowed from Rust.
+    ///
+    /// [`IntoJlrsResult`]: crate::convert::into_jlrs_result::IntoJlrsResult
+    /// [`safety`]: crate::safety
+    pub unsafe fn call_handled<'target, 'value, V, Tgt, F, const N: usize>(
+        self,
+        target: Tgt,
+        args: V,
+        handler: F,
+    ) -> JlrsResult<ValueData<'target, 'data, Tgt>>
+    where
+        V: Values<'value, 'data, N>,
+        Tgt: Target<'target>,
+        F: FnOnce(ValueData<'target, 'data, Tgt>) -> JlrsResult<ValueData<'target, 'data, Tgt>>,
+    {
+        match self.call(&target, args) {
+            Ok(res) => Ok(target.data_from_ptr(res.ptr(), Private)),
+            Err(exc) => handler(target.data_from_ptr(exc.ptr(), Private)),
+        }
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "async")] {
         use crate::{
             memory::target::frame::AsyncGcFrame,
-            data::managed::{
-                Managed,
-                function::Function
-            },
+            data::managed::function::Function,
             async_util::{
                 future::JuliaFuture,
             }
@@ -0,0 +1,91 @@
+use crate::{
+    call::Call,
+    data::managed::{
+        private::ManagedPriv,
+        simple_vector::SimpleVector,
+        string::JuliaString,
+        value::{Value, ValueResult},
+    },
+    inline_static_ref,
+    memory::{
+        scope::LocalScope,
+        target::{Target, TargetException},
+    },
+    private::Private,
+};
+
+/// The backtrace of a caught exception.
+///
+/// A `Backtrace` is provided to the exception handler of [`catch_exceptions_with_backtrace`], it
+/// wraps the raw backtrace data that Julia captured when the exception was caught.
+///
+/// [`catch_exceptions_with_backtrace`]: crate::catch::catch_exceptions_with_backtrace
+#[derive(Clone, Copy, Debug)]
+pub struct Backtrace<'scope> {
+    raw: SimpleVector<'scope>,
+}
+
+impl<'scope> Backtrace<'scope> {
+    #[inline]
+    pub(crate) unsafe fn new(raw: Value<'scope, 'static>) -> Self {
+        Backtrace {
+            raw: raw.cast_unchecked(),
+        }
+    }
+
+    /// Returns the raw backtrace data, a `SimpleVector` with the instruction pointers and the
+    /// interpreter frames.
+    #[inline]
+    pub fn as_simple_vector(self) -> SimpleVector<'scope> {
+        self.raw
+    }
+
+    /// Convert the raw backtrace data to the backtrace that `catch_backtrace()` would have
+    /// returned in the catch block.
+    pub fn to_value<'target, Tgt>(self, target: Tgt) -> ValueResult<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let reformat = inline_static_ref!(REFORMAT_BT, Value, "Base._reformat_bt", &target);
+        let data = self.raw.data();
+
+        // Safety: the raw backtrace contains two arrays, which are rooted by the simple vector.
+        // Base._reformat_bt is called with these two arrays.
+        unsafe {
+            let bt = data.get(&target, 0).unwrap_unchecked().as_managed();
+            let bt2 = data.get(&target, 1).unwrap_unchecked().as_managed();
+            reformat.call2(target, bt, bt2)
+        }
+    }
+
+    /// Render the backtrace, this is equivalent to `sprint(Base.show_backtrace, bt)` in Julia.
+    ///
+    /// If Julia throws an exception while the backtrace is rendered, it's caught and returned.
+    pub fn render<'target, Tgt>(self, target: Tgt) -> TargetException<'target, 'static, String, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let sprint = inline_static_ref!(SPRINT, Value, "Base.sprint", &target);
+        let show_backtrace =
+            inline_static_ref!(SHOW_BACKTRACE, Value, "Base.show_backtrace", &target);
+
+        // Safety: Base.sprint is called with Base.show_backtrace and a backtrace, it returns a
+        // String.
+        let res = target.local_scope::<_, 1>(|mut frame| unsafe {
+            let bt = match self.to_value(&mut frame) {
+                Ok(bt) => bt,
+                Err(e) => return Err(e.unwrap_non_null(Private)),
+            };
+
+            match sprint.call2(&frame, show_backtrace, bt) {
+                Ok(s) => {
+                    let bytes = s.as_value().cast_unchecked::<JuliaString>().as_bytes();
+                    Ok(String::from_utf8_lossy(bytes).into_owned())
+                }
+                Err(e) => Err(e.ptr()),
+            }
+        });
+
+        unsafe { target.exception_from_ptr(res, Private) }
+    }
+}
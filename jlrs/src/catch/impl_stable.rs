@@ -6,8 +6,9 @@ use std::{
     ptr::{null_mut, NonNull},
 };
 
-use jl_sys::{jlrs_catch_t, jlrs_catch_tag_t, jlrs_try_catch};
+use jl_sys::{jlrs_catch_t, jlrs_catch_tag_t, jlrs_try_catch, jlrs_try_catch_with_backtrace};
 
+use super::Backtrace;
 use crate::{
     data::managed::{private::ManagedPriv, Managed},
     memory::target::unrooted::Unrooted,
@@ -56,6 +57,59 @@ where
     }
 }
 
+/// Call `func`, if an exception is thrown it is caught and `exception_handler` is called with
+/// the exception and its backtrace. Both are guaranteed to be rooted inside the exception
+/// handler.
+///
+/// The backtrace is captured when the exception is caught, this is equivalent to calling
+/// `catch_backtrace()` in a catch block in Julia. Capturing it is not free, prefer
+/// [`catch_exceptions`] if the backtrace isn't used.
+///
+/// Safety:
+///
+/// If an exception is thrown, there must be no pending drops. Only local scopes may be created in
+/// `func`.
+pub unsafe fn catch_exceptions_with_backtrace<F, H, T, E>(
+    func: F,
+    exception_handler: H,
+) -> Result<T, E>
+where
+    F: FnOnce() -> T,
+    H: for<'exc> FnOnce(Value<'exc, 'static>, Backtrace<'exc>) -> E,
+{
+    let mut func = Some(func);
+    let func = &mut func;
+    let trampoline = trampoline_for(func).unwrap_unchecked();
+    let mut result = MaybeUninit::<T>::uninit();
+    let mut backtrace = null_mut();
+
+    let res = jlrs_try_catch_with_backtrace(
+        func as *mut _ as *mut _,
+        trampoline,
+        (&mut result) as *mut _ as *mut _,
+        &mut backtrace,
+    );
+
+    match res.tag {
+        jlrs_catch_tag_t::Ok => Ok(result.assume_init()),
+        jlrs_catch_tag_t::Exception => {
+            let ptr = NonNull::new_unchecked(res.error.cast());
+            let bt_ptr = NonNull::new_unchecked(backtrace);
+            let unrooted = Unrooted::new();
+            unrooted.local_scope::<_, 2>(|mut frame| {
+                // Root the exception and backtrace because we're not in an actual catch block.
+                let v = Value::wrap_non_null(ptr, Private).root(&mut frame);
+                let bt = Value::wrap_non_null(bt_ptr, Private).root(&mut frame);
+                Err(exception_handler(v, Backtrace::new(bt)))
+            })
+        }
+        jlrs_catch_tag_t::Panic => {
+            let err: Box<Box<dyn Any + Send>> = Box::from_raw(res.error.cast());
+            std::panic::resume_unwind(err)
+        }
+    }
+}
+
 #[inline]
 unsafe extern "C" fn trampoline<'frame, F: FnOnce() -> T, T>(
     func: &mut Option<F>,
@@ -7,9 +7,17 @@
 //! aborted. The main disadvantage of the checked variants is that a new try-catch block is
 //! created every time the function is called and creating such a block is relatively expensive.
 //!
+//! When it's cheap to check in advance whether a function would throw, a prechecked variant can
+//! be used instead. These variants check the conditions that would cause an exception to be
+//! thrown in Rust, and only call the function if it can't throw. An error is returned otherwise.
+//! For example, [`Accessor::get_value_checked`] checks that the index is in-bounds and that the
+//! element isn't an undefined reference before converting it to a `Value`.
+//!
 //! Instead of using the checked variants you can create a try-catch block from Rust with
 //! [`catch_exceptions`]. This function takes two closures, think of them as the content of the
-//! try and catch blocks respectively.
+//! try and catch blocks respectively. If you need the backtrace of the exception, e.g. to log
+//! it, use [`catch_exceptions_with_backtrace`] instead. The backtrace can only be captured when
+//! the exception is caught, the exception handler receives it as a [`Backtrace`].
 //!
 //! Because exceptions work by jumping to the nearest enclosing catch block, you must guarantee
 //! that there are no pending drops when an exception is thrown. See this [blog post] for more
@@ -19,14 +27,17 @@
 //! any scope we jump out of is removed from the GC stack. Dynamic scopes (i.e. scopes that
 //! provide a `GcFrame`) depend on `Drop` so jumping out of them is not sound.
 //!
+//! [`Accessor::get_value_checked`]: crate::data::managed::array::data::accessor::Accessor::get_value_checked
 //! [blog post]: https://blog.rust-lang.org/inside-rust/2021/01/26/ffi-unwind-longjmp.html#pofs-and-stack-deallocating-functions
 
 use std::ptr::NonNull;
 
+mod backtrace;
 #[path = "impl_stable.rs"]
 mod imp;
 
-pub use imp::catch_exceptions;
+pub use backtrace::Backtrace;
+pub use imp::{catch_exceptions, catch_exceptions_with_backtrace};
 use jl_sys::jl_value_t;
 
 use crate::{data::managed::private::ManagedPriv, prelude::Value, private::Private};
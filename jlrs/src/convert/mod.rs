@@ -7,5 +7,7 @@ pub mod into_julia;
 pub mod into_simple_vector;
 #[cfg(feature = "jlrs-ndarray")]
 pub mod ndarray;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod to_symbol;
 pub mod unbox;
@@ -1,4 +1,10 @@
 //! Borrow data from Julia arrays as `ndarray`'s `ArrayView` and `ArrayViewMut`.
+//!
+//! Julia arrays are stored in column-major order, the views that are created by this module
+//! always have column-major (Fortran) strides, so indexing a view with `[i, j]` accesses the same
+//! element as `A[i+1, j+1]` in Julia. If you need a view whose memory layout is row-major (C
+//! order), use one of the `transposed` methods. These views have their axes reversed, so the
+//! element `A[i+1, j+1]` is found at index `[j, i]`.
 
 use ndarray::{ArrayView, ArrayViewMut, IntoDimension, IxDyn, Shape, ShapeBuilder};
 
@@ -37,6 +43,14 @@ pub trait NdArrayView<'view, T>: private::NdArrayPriv {
     fn compatible_array_view<U>(&'view self) -> ArrayView<'view, U, IxDyn>
     where
         T: Compatible<U>;
+
+    /// Borrow the data in the array as an `ArrayView` with its axes reversed.
+    ///
+    /// The returned view has a row-major (C order) layout.
+    #[inline]
+    fn array_view_transposed(&'view self) -> ArrayView<'view, T, IxDyn> {
+        self.array_view().reversed_axes()
+    }
 }
 
 /// Trait to borrow Julia arrays with inline data as `ndarray`'s `ArrayViewMut`.
@@ -48,6 +62,14 @@ pub trait NdArrayViewMut<'view, T>: NdArrayView<'view, T> {
     fn compatible_array_view_mut<U>(&'view mut self) -> ArrayViewMut<'view, U, IxDyn>
     where
         T: Compatible<U>;
+
+    /// Mutably borrow the data in the array as an `ArrayViewMut` with its axes reversed.
+    ///
+    /// The returned view has a row-major (C order) layout.
+    #[inline]
+    fn array_view_mut_transposed(&'view mut self) -> ArrayViewMut<'view, T, IxDyn> {
+        self.array_view_mut().reversed_axes()
+    }
 }
 
 impl<'borrow: 'view, 'view, 'array, 'data, T, L, const N: isize> NdArrayView<'view, L>
@@ -0,0 +1,102 @@
+//! Serialize and deserialize Julia data with the `Serialization` standard library.
+//!
+//! Julia's `Serialization.serialize` and `Serialization.deserialize` can round-trip arbitrary
+//! Julia data, including data with circular references, to and from a sequence of bytes. The
+//! functions in this module call these functions and move the bytes between Rust and Julia.
+//!
+//! The serialized format is not guaranteed to be stable between different versions of Julia,
+//! it's only intended to be read back by the same version of Julia and the same version of the
+//! code that defines the serialized types.
+
+use std::io::Write;
+
+use crate::{
+    call::Call,
+    convert::into_jlrs_result::IntoJlrsResult,
+    data::managed::{
+        array::TypedVector,
+        module::Module,
+        value::{Value, ValueData},
+        Managed,
+    },
+    error::{JlrsError, JlrsResult},
+    inline_static_ref,
+    memory::{
+        scope::LocalScope,
+        target::{frame::LocalGcFrame, Target},
+    },
+};
+
+/// Serialize `value` with `Serialization.serialize` and write the serialized bytes to `writer`.
+///
+/// The `Serialization` standard library is loaded if it hasn't been loaded yet. If an exception
+/// is thrown, e.g. because `value` contains data that can't be serialized, it's caught and
+/// returned. This requires five slots on the GC stack.
+pub fn serialize_value<W>(value: Value, writer: &mut W) -> JlrsResult<()>
+where
+    W: Write + ?Sized,
+{
+    value
+        .unrooted_target()
+        .local_scope::<_, 5>(|mut frame| unsafe {
+            let io_buffer = inline_static_ref!(IO_BUFFER, Value, "Base.IOBuffer", &frame);
+            let take = inline_static_ref!(TAKE, Value, "Base.take!", &frame);
+            let serialize = serialization_function(&mut frame, "serialize")?;
+
+            // Safety: the functions are called with valid arguments, the bytes are written to the
+            // writer before the frame is popped.
+            let io = io_buffer.call0(&mut frame).into_jlrs_result()?;
+            serialize.call2(&mut frame, io, value).into_jlrs_result()?;
+            let bytes = take
+                .call1(&mut frame, io)
+                .into_jlrs_result()?
+                .cast::<TypedVector<u8>>()?;
+
+            writer
+                .write_all(bytes.bits_data().as_slice())
+                .map_err(JlrsError::other)?;
+
+            Ok(())
+        })
+}
+
+/// Deserialize `bytes` with `Serialization.deserialize` and root the result in `target`.
+///
+/// The `Serialization` standard library is loaded if it hasn't been loaded yet. If an exception
+/// is thrown, e.g. because `bytes` is not valid serialized data, it's caught and returned. This
+/// requires five slots on the GC stack.
+pub fn deserialize_value<'target, Tgt>(
+    bytes: &[u8],
+    target: Tgt,
+) -> JlrsResult<ValueData<'target, 'static, Tgt>>
+where
+    Tgt: Target<'target>,
+{
+    target.with_local_scope::<_, _, 5>(|target, mut frame| unsafe {
+        let io_buffer = inline_static_ref!(IO_BUFFER, Value, "Base.IOBuffer", &frame);
+        let deserialize = serialization_function(&mut frame, "deserialize")?;
+
+        // Safety: the functions are called with valid arguments, the result is rooted in
+        // `target` before the frame is popped.
+        let bytes = TypedVector::<u8>::from_bytes(&mut frame, bytes).into_jlrs_result()?;
+        let io = io_buffer
+            .call1(&mut frame, bytes.as_value())
+            .into_jlrs_result()?;
+        let value = deserialize.call1(&mut frame, io).into_jlrs_result()?;
+
+        Ok(value.root(target))
+    })
+}
+
+// Loads the Serialization module and returns the function `name`.
+unsafe fn serialization_function<'target, const N: usize>(
+    frame: &mut LocalGcFrame<'target, N>,
+    name: &str,
+) -> JlrsResult<Value<'target, 'static>> {
+    let module = Module::main(&*frame)
+        .require(&mut *frame, "Serialization")
+        .into_jlrs_result()?
+        .cast::<Module>()?;
+
+    module.global(frame, name)
+}
@@ -23,7 +23,7 @@
 //! [`DataType`]: crate::data::managed::datatype::DataType
 //! [`IntoJulia`]: crate::convert::into_julia::IntoJulia
 
-use std::ffi::c_void;
+use std::{ffi::c_void, fmt::Display};
 
 use jl_sys::{
     jl_unbox_float32, jl_unbox_float64, jl_unbox_int16, jl_unbox_int32, jl_unbox_int64,
@@ -32,7 +32,7 @@ use jl_sys::{
 };
 
 use super::into_julia::IntoJulia;
-use crate::data::managed::value::Value;
+use crate::{data::managed::value::Value, error::JlrsResult};
 
 /// A trait implemented by types that can be extracted from a Julia value with [`Value::unbox`].
 ///
@@ -69,6 +69,19 @@ pub unsafe trait Unbox {
     unsafe fn unbox(value: Value) -> Self::Output {
         value.data_ptr().cast::<Self::Output>().as_ref().clone()
     }
+
+    /// Unbox the value as `Self::Output`, or return an error if its contents are invalid.
+    ///
+    /// This method is called by [`Value::unbox`] after the layout has been checked. The default
+    /// implementation calls [`Unbox::unbox`] and never fails. It must be overridden if not every
+    /// bit pattern of the data is a valid instance of `Self::Output`, e.g. when `Self` is a Rust
+    /// enum that represents a Julia enum.
+    ///
+    /// Safety: `Self::Output` must be the correct layout for the data that `value` points to.
+    #[inline]
+    unsafe fn try_unbox(value: Value) -> JlrsResult<Self::Output> {
+        Ok(Self::unbox(value))
+    }
 }
 
 macro_rules! impl_unboxer {
@@ -105,3 +118,26 @@ impl_unboxer!(isize, jlrs_unbox_long);
 unsafe impl<T: IntoJulia> Unbox for *mut T {
     type Output = Self;
 }
+
+/// Rust integer types that a Julia integer can be unboxed to with [`Value::unbox_checked`].
+///
+/// [`Value::unbox_checked`]: crate::data::managed::value::Value::unbox_checked
+pub trait BoundedInteger: TryFrom<i128> + Display {
+    /// The smallest value of this type.
+    const MIN: Self;
+    /// The largest value of this type.
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded_integer {
+    ($($type:ty),+) => {
+        $(
+            impl BoundedInteger for $type {
+                const MIN: Self = <$type>::MIN;
+                const MAX: Self = <$type>::MAX;
+            }
+        )+
+    };
+}
+
+impl_bounded_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -10,7 +10,14 @@ use crate::{
 /// This trait is automatically derived when bindings for enums are generated with
 /// `JlrsCore.Reflect.reflect`.
 ///
+/// When this trait is derived, the derived implementation of [`Unbox`] checks that the unboxed
+/// value represents a known variant with [`Enum::from_super`]. [`Value::unbox`] returns an error
+/// if it doesn't. [`Value::unbox_unchecked`] doesn't check the discriminant, the value must
+/// represent a known variant.
+///
 /// Safety: the implementation must correctly map the Rust to the Julia enum.
+///
+/// [`Unbox`]: crate::convert::unbox::Unbox
 pub unsafe trait Enum {
     /// The type that represents this enum.
     type Super: IntegerType;
@@ -20,4 +27,10 @@ pub unsafe trait Enum {
 
     /// Convert `self` to its representation
     fn as_super(&self) -> Self::Super;
+
+    /// Convert a representation back to the variant it represents. Returns `None` if
+    /// `discriminant` doesn't represent any of the variants of this enum.
+    fn from_super(discriminant: Self::Super) -> Option<Self>
+    where
+        Self: Sized;
 }
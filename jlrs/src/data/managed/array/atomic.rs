@@ -0,0 +1,209 @@
+//! Atomic operations on the elements of arrays.
+//!
+//! The elements of an array whose element type is a primitive integer type or `Bool` can be
+//! accessed atomically with [`ArrayBase::atomic_get`], [`ArrayBase::atomic_set`] and
+//! [`ArrayBase::atomic_cas`]. These methods operate directly on the data of the array, no Julia
+//! functions are called. This makes it possible to use a Julia array as the backing storage of a
+//! lock-free data structure that is shared between threads.
+//!
+//! The memory ordering is expressed with [`MemoryOrder`], and follows the same rules as Julia's
+//! atomic field operations: `MemoryOrder::NotAtomic` can't be used, loads can't use a release
+//! ordering, and stores can't use an acquire ordering.
+//!
+//! This module is only available if the `unstable` feature is enabled.
+
+use std::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+    AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
+
+use jl_sys::inlined::jlrs_array_data_fast;
+
+use super::{
+    dimensions::{Dims, DimsRankAssert, DimsRankCheck},
+    ArrayBase,
+};
+use crate::{
+    data::{
+        layout::{is_bits::IsBits, valid_layout::ValidField},
+        managed::{private::ManagedPriv, value::memory_order::MemoryOrder},
+        types::construct_type::ConstructType,
+    },
+    error::{AccessError, JlrsResult},
+    private::Private,
+};
+
+/// Element types that can be accessed atomically.
+///
+/// This trait is implemented for the primitive integer types and `bool`. It can't be implemented
+/// outside of jlrs.
+pub unsafe trait AtomicElement: ConstructType + ValidField + IsBits + Copy {
+    #[doc(hidden)]
+    unsafe fn atomic_load(ptr: *mut Self, order: Ordering, _: Private) -> Self;
+
+    #[doc(hidden)]
+    unsafe fn atomic_store(ptr: *mut Self, value: Self, order: Ordering, _: Private);
+
+    #[doc(hidden)]
+    unsafe fn atomic_compare_exchange(
+        ptr: *mut Self,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+        _: Private,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_element {
+    ($type:ty, $atomic:ty) => {
+        unsafe impl AtomicElement for $type {
+            #[inline]
+            unsafe fn atomic_load(ptr: *mut Self, order: Ordering, _: Private) -> Self {
+                <$atomic>::from_ptr(ptr).load(order)
+            }
+
+            #[inline]
+            unsafe fn atomic_store(ptr: *mut Self, value: Self, order: Ordering, _: Private) {
+                <$atomic>::from_ptr(ptr).store(value, order)
+            }
+
+            #[inline]
+            unsafe fn atomic_compare_exchange(
+                ptr: *mut Self,
+                current: Self,
+                new: Self,
+                success: Ordering,
+                failure: Ordering,
+                _: Private,
+            ) -> Result<Self, Self> {
+                <$atomic>::from_ptr(ptr).compare_exchange(current, new, success, failure)
+            }
+        }
+    };
+}
+
+impl_atomic_element!(bool, AtomicBool);
+impl_atomic_element!(u8, AtomicU8);
+impl_atomic_element!(u16, AtomicU16);
+impl_atomic_element!(u32, AtomicU32);
+impl_atomic_element!(u64, AtomicU64);
+impl_atomic_element!(usize, AtomicUsize);
+impl_atomic_element!(i8, AtomicI8);
+impl_atomic_element!(i16, AtomicI16);
+impl_atomic_element!(i32, AtomicI32);
+impl_atomic_element!(i64, AtomicI64);
+impl_atomic_element!(isize, AtomicIsize);
+
+impl<T: AtomicElement, const N: isize> ArrayBase<'_, '_, T, N> {
+    /// Atomically load the element at `index` with the memory ordering `order`.
+    ///
+    /// An error is returned if `index` is out-of-bounds, or if `order` is
+    /// `MemoryOrder::NotAtomic`, `MemoryOrder::Release` or `MemoryOrder::AcquireRelease`.
+    ///
+    /// Safety: other code that accesses this element concurrently must use atomic operations.
+    pub unsafe fn atomic_get<D: Dims>(self, index: D, order: MemoryOrder) -> JlrsResult<T> {
+        let order = load_ordering(order)?;
+        let ptr = self.element_ptr(index)?;
+        Ok(T::atomic_load(ptr, order, Private))
+    }
+
+    /// Atomically store `value` at `index` with the memory ordering `order`.
+    ///
+    /// An error is returned if `index` is out-of-bounds, or if `order` is
+    /// `MemoryOrder::NotAtomic`, `MemoryOrder::Acquire` or `MemoryOrder::AcquireRelease`.
+    ///
+    /// Safety: other code that accesses this element concurrently must use atomic operations.
+    /// Mutating Julia data is generally unsafe, you must guarantee that you're allowed to mutate
+    /// this array.
+    pub unsafe fn atomic_set<D: Dims>(
+        self,
+        index: D,
+        value: T,
+        order: MemoryOrder,
+    ) -> JlrsResult<()> {
+        let order = store_ordering(order)?;
+        let ptr = self.element_ptr(index)?;
+        T::atomic_store(ptr, value, order, Private);
+        Ok(())
+    }
+
+    /// Atomically replace the element at `index` with `desired` if it's equal to `expected`.
+    ///
+    /// If the element has been replaced, `Ok(Ok(expected))` is returned. Otherwise the element is
+    /// left unchanged and `Ok(Err(current))` is returned. The memory ordering `order` is used if
+    /// the element is replaced, the strongest valid load ordering that isn't stronger than
+    /// `order` is used if it isn't. An error is returned if `index` is out-of-bounds, or if
+    /// `order` is `MemoryOrder::NotAtomic`.
+    ///
+    /// Safety: other code that accesses this element concurrently must use atomic operations.
+    /// Mutating Julia data is generally unsafe, you must guarantee that you're allowed to mutate
+    /// this array.
+    pub unsafe fn atomic_cas<D: Dims>(
+        self,
+        index: D,
+        expected: T,
+        desired: T,
+        order: MemoryOrder,
+    ) -> JlrsResult<Result<T, T>> {
+        let (success, failure) = cas_orderings(order)?;
+        let ptr = self.element_ptr(index)?;
+        Ok(T::atomic_compare_exchange(
+            ptr, expected, desired, success, failure, Private,
+        ))
+    }
+
+    fn element_ptr<D: Dims>(self, index: D) -> JlrsResult<*mut T> {
+        let _ = DimsRankAssert::<D, N>::ASSERT_VALID_RANK;
+        let dims = self.dimensions();
+        let Some(idx) = dims.index_of(&index) else {
+            Err(AccessError::InvalidIndex {
+                idx: index.to_dimensions(),
+                sz: dims.to_dimensions(),
+            })?
+        };
+
+        // Safety: the index is in-bounds, the elements are stored inline.
+        unsafe {
+            let data = jlrs_array_data_fast(self.unwrap(Private)).cast::<T>();
+            Ok(data.add(idx))
+        }
+    }
+}
+
+fn load_ordering(order: MemoryOrder) -> JlrsResult<Ordering> {
+    match order {
+        MemoryOrder::Unordered | MemoryOrder::Monotonic => Ok(Ordering::Relaxed),
+        MemoryOrder::Acquire => Ok(Ordering::Acquire),
+        MemoryOrder::SequentiallyConsistent => Ok(Ordering::SeqCst),
+        _ => Err(AccessError::InvalidMemoryOrder {
+            order: order.as_str(),
+        })?,
+    }
+}
+
+fn store_ordering(order: MemoryOrder) -> JlrsResult<Ordering> {
+    match order {
+        MemoryOrder::Unordered | MemoryOrder::Monotonic => Ok(Ordering::Relaxed),
+        MemoryOrder::Release => Ok(Ordering::Release),
+        MemoryOrder::SequentiallyConsistent => Ok(Ordering::SeqCst),
+        _ => Err(AccessError::InvalidMemoryOrder {
+            order: order.as_str(),
+        })?,
+    }
+}
+
+fn cas_orderings(order: MemoryOrder) -> JlrsResult<(Ordering, Ordering)> {
+    match order {
+        MemoryOrder::Unordered | MemoryOrder::Monotonic => {
+            Ok((Ordering::Relaxed, Ordering::Relaxed))
+        }
+        MemoryOrder::Acquire => Ok((Ordering::Acquire, Ordering::Acquire)),
+        MemoryOrder::Release => Ok((Ordering::Release, Ordering::Relaxed)),
+        MemoryOrder::AcquireRelease => Ok((Ordering::AcqRel, Ordering::Acquire)),
+        MemoryOrder::SequentiallyConsistent => Ok((Ordering::SeqCst, Ordering::SeqCst)),
+        MemoryOrder::NotAtomic => Err(AccessError::InvalidMemoryOrder {
+            order: order.as_str(),
+        })?,
+    }
+}
@@ -26,6 +26,7 @@ use std::{
 use jl_sys::{
     inlined::jlrs_array_data_fast, jl_array_del_end, jl_array_grow_end, jl_array_ptr_1d_append,
     jl_array_ptr_1d_push, jl_value_t, jlrs_array_typetagdata, jlrs_arrayref, jlrs_arrayset,
+    jlrs_datatype_first_ptr,
 };
 
 use super::copied::CopiedArray;
@@ -84,6 +85,62 @@ pub trait Accessor<'scope, 'data, T, const N: isize> {
         }
     }
 
+    /// Converts the element at `index` to a `Value` and returns it without creating a try-catch
+    /// block.
+    ///
+    /// The only exception that can be thrown when an element is converted to a `Value` is an
+    /// `UndefRefError`, which is thrown if the element is an undefined reference. Rather than
+    /// catching this exception like [`Accessor::get_value`] does, this method checks if the
+    /// element is defined before converting it. This avoids the relatively expensive setup of a
+    /// try-catch block, which makes a difference when many elements are accessed.
+    ///
+    /// If `index` is not in-bounds, `AccessError::InvalidIndex` is returned. If the element is an
+    /// undefined reference, `AccessError::UndefRef` is returned.
+    fn get_value_checked<'target, D: Dims, Tgt: Target<'target>>(
+        &self,
+        target: Tgt,
+        index: D,
+    ) -> JlrsResult<ValueData<'target, 'data, Tgt>> {
+        let _ = DimsRankAssert::<D, N>::ASSERT_VALID_RANK;
+        let array = *self.array();
+        let dims = array.dimensions();
+        let Some(idx) = dims.index_of(&index) else {
+            Err(AccessError::InvalidIndex {
+                idx: index.to_dimensions(),
+                sz: dims.to_dimensions(),
+            })?
+        };
+
+        // Safety: the index is in-bounds. If the elements are references or contain references,
+        // the (first) reference is checked before jlrs_arrayref is called so it can't throw an
+        // UndefRefError.
+        unsafe {
+            let data = jlrs_array_data_fast(array.unwrap(Private));
+            let is_undef = if array.ptr_array() {
+                let elem = data.cast::<AtomicPtr<jl_value_t>>().add(idx);
+                (*elem).load(Ordering::Relaxed).is_null()
+            } else if array.has_inline_with_refs_layout() {
+                let ty = array.element_type().cast_unchecked::<DataType>();
+                let first_ptr = jlrs_datatype_first_ptr(ty.unwrap(Private)) as usize;
+                let elem = data
+                    .cast::<u8>()
+                    .add(idx * array.element_size())
+                    .cast::<AtomicPtr<jl_value_t>>()
+                    .add(first_ptr);
+                (*elem).load(Ordering::Relaxed).is_null()
+            } else {
+                false
+            };
+
+            if is_undef {
+                Err(AccessError::UndefRef)?;
+            }
+
+            let v = jlrs_arrayref(array.unwrap(Private), idx);
+            Ok(ValueRef::wrap(NonNull::new_unchecked(v)).root(target))
+        }
+    }
+
     /// Converts the element at `index` to a `Value` and returns it.
     ///
     /// Safety: `index` must be in-bounds.
@@ -109,6 +109,8 @@
 //! [isbits]: crate::data::layout::is_bits
 //! [managed type]: crate::data::managed
 
+#[cfg(feature = "unstable")]
+pub mod atomic;
 pub mod data;
 pub mod dimensions;
 pub mod tracked;
@@ -118,9 +120,11 @@ use std::ptr::null_mut;
 use std::{
     ffi::c_void,
     fmt::{Debug, Formatter, Result as FmtResult},
+    iter::Copied,
     marker::PhantomData,
     mem::MaybeUninit,
     ptr::NonNull,
+    slice,
 };
 
 use jl_sys::{
@@ -131,17 +135,17 @@ use jl_sys::{
     jlrs_array_is_pointer_array, jlrs_array_is_union_array, jlrs_array_len,
 };
 use jlrs_macros::julia_version;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
-#[julia_version(until = "1.10")]
-use self::dimensions::Dims;
 use self::{
     data::accessor::{
-        BitsAccessor, BitsAccessorMut, BitsUnionAccessor, BitsUnionAccessorMut,
+        AccessorMut1D, BitsAccessor, BitsAccessorMut, BitsUnionAccessor, BitsUnionAccessorMut,
         IndeterminateAccessor, IndeterminateAccessorMut, InlineAccessor, InlineAccessorMut,
         ManagedAccessor, ManagedAccessorMut, ValueAccessor, ValueAccessorMut,
     },
-    dimensions::{ArrayDimensions, DimsExt, DimsRankAssert, DimsRankCheck, RankedDims},
-    tracked::{TrackedArrayBase, TrackedArrayBaseMut},
+    dimensions::{ArrayDimensions, Dims, DimsExt, DimsRankAssert, DimsRankCheck, RankedDims},
+    tracked::{TrackedArrayBase, TrackedArrayBaseMut, TrackedBytes, TrackedMutSlice, TrackedSlice},
 };
 use super::{
     string::{JuliaString, StringData},
@@ -149,8 +153,12 @@ use super::{
     union::Union,
 };
 use crate::{
+    call::{Call, ProvideKeywords},
     catch::{catch_exceptions, unwrap_exc},
-    convert::ccall_types::{CCallArg, CCallReturn},
+    convert::{
+        ccall_types::{CCallArg, CCallReturn},
+        into_jlrs_result::IntoJlrsResult,
+    },
     data::{
         layout::{
             is_bits::IsBits,
@@ -167,11 +175,15 @@ use crate::{
         },
     },
     error::{AccessError, ArrayLayoutError, InstantiationError, TypeError, CANNOT_DISPLAY_TYPE},
+    inline_static_ref,
     memory::{
         get_tls,
-        target::{unrooted::Unrooted, TargetResult},
+        target::{unrooted::Unrooted, TargetException, TargetResult},
+    },
+    prelude::{
+        DataType, JlrsResult, LocalScope, Managed, Module, Target, TargetType, Value, ValueData,
+        ValueResult,
     },
-    prelude::{DataType, JlrsResult, LocalScope, Managed, Target, TargetType, Value, ValueData},
     private::Private,
 };
 
@@ -1829,6 +1841,532 @@ impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N> {
     pub fn track_exclusive(self) -> JlrsResult<TrackedArrayBaseMut<'scope, 'data, T, N>> {
         TrackedArrayBaseMut::track_exclusive(self)
     }
+
+    /// Track this array, allowing shared access, and return a slice of its `isbits` data.
+    ///
+    /// The array remains tracked until the returned [`TrackedSlice`] is dropped. An error is
+    /// returned if the array is already tracked exclusively. In debug builds, dereferencing the
+    /// slice panics if the array has been resized since the slice was created.
+    ///
+    /// [`TrackedSlice`] dereferences to `[T]`, so the data can be processed in batches without
+    /// copying it with slice methods like `chunks` and `windows`. The array remains tracked while
+    /// these iterators are in use because they borrow from the guard.
+    pub fn bits_data_tracked(self) -> JlrsResult<TrackedSlice<'scope, 'data, T, N>>
+    where
+        T: ConstructType + ValidField + IsBits,
+    {
+        TrackedSlice::new(self)
+    }
+
+    /// Track this array, enforcing exclusive access, and return a mutable slice of its `isbits`
+    /// data.
+    ///
+    /// The array remains tracked until the returned [`TrackedMutSlice`] is dropped. An error is
+    /// returned if the array is already tracked.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// its content, and that no running Julia code is accessing this data.
+    pub unsafe fn bits_data_mut_tracked(self) -> JlrsResult<TrackedMutSlice<'scope, 'data, T, N>>
+    where
+        T: ConstructType + ValidField + IsBits,
+    {
+        TrackedMutSlice::new(self)
+    }
+
+    /// Track this array, allowing shared access, and return the raw bytes of its `isbits` data.
+    ///
+    /// The elements of an array are stored contiguously, the returned [`TrackedBytes`] derefs to
+    /// a byte slice of length `len * size_of::<T>()` without copying any data. This is useful to
+    /// feed the contents of an array to a hasher or writer. The array remains tracked until the
+    /// returned guard is dropped. An error is returned if the array is already tracked
+    /// exclusively.
+    ///
+    /// Padding bytes are uninitialized and must not be read, so
+    /// `ArrayLayoutError::HasPadding` is returned if the element type contains padding.
+    pub fn as_raw_bytes(self) -> JlrsResult<TrackedBytes<'scope, 'data, T, N>>
+    where
+        T: ConstructType + ValidField + IsBits,
+    {
+        TrackedBytes::new(self)
+    }
+}
+
+// Copying
+impl<'scope, 'data, T: ConstructType, const N: isize> ArrayBase<'scope, 'data, T, N> {
+    /// Copy the elements of `src` to `self`.
+    ///
+    /// Both arrays must have the same length, otherwise `AccessError::ArrayLengthMismatch` is
+    /// returned. Both arrays are constructed from `T`, but this doesn't guarantee that their
+    /// element types are equal because an array can be cast without a typecheck. If both arrays
+    /// have the same element type and the elements are stored inline and are isbits data,
+    /// they're copied directly. Otherwise, `Base.copyto!` is called, which converts the elements
+    /// to the element type of `self` and ensures the GC is made aware of the new references. If
+    /// this function throws an exception it's caught, rooted and returned.
+    ///
+    /// Safety:
+    ///
+    /// No accessors to the data of `self` or `src` must exist.
+    pub unsafe fn copyto<'target, 'src_data, Tgt, const M: isize>(
+        &mut self,
+        target: Tgt,
+        src: ArrayBase<'_, 'src_data, T, M>,
+    ) -> JlrsResult<TargetException<'target, 'data, (), Tgt>>
+    where
+        'src_data: 'data,
+        Tgt: Target<'target>,
+    {
+        let dst_len = self.length();
+        let src_len = src.length();
+        if dst_len != src_len {
+            Err(AccessError::ArrayLengthMismatch { dst_len, src_len })?;
+        }
+
+        if self.has_bits_layout() && self.element_type() == src.element_type() {
+            let n_bytes = dst_len * self.element_size();
+            // Use copy rather than copy_nonoverlapping, src and self can be the same array.
+            std::ptr::copy(
+                src.data_ptr().cast::<u8>(),
+                self.data_ptr().cast::<u8>(),
+                n_bytes,
+            );
+
+            return Ok(Ok(()));
+        }
+
+        let func = inline_static_ref!(COPYTO, Value, "Base.copyto!", &target);
+        let res = match func.call2(&target, self.as_value(), src.as_value()) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.ptr()),
+        };
+
+        Ok(target.exception_from_ptr(res, Private))
+    }
+
+    /// Copy the `isbits` data of this array to a `SmallVec`.
+    ///
+    /// The elements are stored inline if the array has at most `M` elements, otherwise they're
+    /// stored on the heap.
+    ///
+    /// Safety:
+    ///
+    /// No mutable accessors to this data must exist.
+    #[cfg(feature = "smallvec")]
+    pub unsafe fn to_smallvec<const M: usize>(&self) -> SmallVec<[T; M]>
+    where
+        T: ValidField + IsBits + Clone,
+    {
+        SmallVec::from(self.bits_data().as_slice())
+    }
+}
+
+// In-place operations
+impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    /// Swap the elements at the linear indices `i` and `j`.
+    ///
+    /// The array is tracked exclusively while the elements are swapped, an error is returned if
+    /// it's already tracked. If either index is out of bounds,
+    /// `AccessError::OutOfBoundsArrayIndex` is returned. If the array doesn't own its data,
+    /// `AccessError::ArrayDataNotOwned` is returned.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// its content, and that no running Julia code is accessing this data.
+    pub unsafe fn swap(&mut self, i: usize, j: usize) -> JlrsResult<()> {
+        let mut data = self.owned_bits_data_mut_tracked()?;
+
+        let len = data.len();
+        for idx in [i, j] {
+            if idx >= len {
+                Err(AccessError::OutOfBoundsArrayIndex { idx, len })?;
+            }
+        }
+
+        data.swap(i, j);
+        Ok(())
+    }
+
+    /// Set all elements of this array to `value`.
+    ///
+    /// The data is written directly, no Julia function is called and `value` doesn't have to be
+    /// rooted. This can be used to initialize an array allocated with [`TypedArray::new`], whose
+    /// contents are undefined. Because the elements are isbits they don't contain references to
+    /// Julia data, so no write barrier is needed.
+    ///
+    /// The array is tracked exclusively while it's filled, an error is returned if it's already
+    /// tracked. If the array doesn't own its data, `AccessError::ArrayDataNotOwned` is returned.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// its content, and that no running Julia code is accessing this data.
+    ///
+    /// [`TypedArray::new`]: crate::data::managed::array::ArrayBase::new
+    pub unsafe fn fill(&mut self, value: T) -> JlrsResult<()>
+    where
+        T: Clone,
+    {
+        self.owned_bits_data_mut_tracked()?.fill(value);
+        Ok(())
+    }
+
+    unsafe fn owned_bits_data_mut_tracked(
+        self,
+    ) -> JlrsResult<TrackedMutSlice<'scope, 'data, T, N>> {
+        if self.how() == How::PointerToOwner {
+            Err(AccessError::ArrayDataNotOwned)?;
+        }
+
+        self.bits_data_mut_tracked()
+    }
+}
+
+// Resizing
+impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N> {
+    /// Insert `inc` elements at the end of this array by calling `jl_array_grow_end`.
+    ///
+    /// This is a shorthand for tracking this array exclusively and calling
+    /// [`AccessorMut1D::grow_end`] on a mutable accessor, which is much faster than calling
+    /// `Base.push!` in a loop. Only arrays of rank 1 can be resized,
+    /// `ArrayLayoutError::RankMismatch` is returned if this array has a different rank. An error
+    /// is also returned if the array is already tracked. If an exception is thrown, e.g. because
+    /// the array shares its data with another array, it's caught and returned. Since Julia 1.11
+    /// an array wraps a `Memory`, growing the array past the capacity of its `Memory` replaces it
+    /// with a larger one.
+    ///
+    /// The new elements are not initialized: they can contain old data that was removed earlier
+    /// or garbage. Elements that are references to Julia data are undefined until they're set.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// this array, and that no running Julia code is accessing its data. The new elements must
+    /// be set before they're read.
+    pub unsafe fn grow_end<'target, Tgt>(
+        self,
+        target: Tgt,
+        inc: usize,
+    ) -> JlrsResult<TargetException<'target, 'static, (), Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        let mut tracked = self.forget_rank().set_rank::<1>()?.track_exclusive()?;
+        Ok(tracked.indeterminate_data_mut().grow_end(target, inc))
+    }
+
+    /// Remove `dec` elements from the end of this array by calling `jl_array_del_end`.
+    ///
+    /// This is a shorthand for tracking this array exclusively and calling
+    /// [`AccessorMut1D::del_end`] on a mutable accessor. Only arrays of rank 1 can be resized,
+    /// `ArrayLayoutError::RankMismatch` is returned if this array has a different rank. An error
+    /// is also returned if the array is already tracked. If an exception is thrown, e.g. because
+    /// `dec` is larger than the length of the array, it's caught and returned. The capacity of
+    /// the array isn't reduced.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// this array, and that no running Julia code is accessing its data.
+    pub unsafe fn delete_end<'target, Tgt>(
+        self,
+        target: Tgt,
+        dec: usize,
+    ) -> JlrsResult<TargetException<'target, 'static, (), Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        let mut tracked = self.forget_rank().set_rank::<1>()?.track_exclusive()?;
+        Ok(tracked.indeterminate_data_mut().del_end(target, dec))
+    }
+}
+
+// Reshaping
+impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N> {
+    /// Reshape this array to `dims` without copying its data.
+    ///
+    /// The reshaped array is created by calling `Base.reshape`, it has the same element type as
+    /// this array and shares its data. If the number of elements of `dims` is not equal to the
+    /// length of this array, `InstantiationError::ArraySizeMismatch` is returned. Reshaping an
+    /// array to zero dimensions is not supported, `InstantiationError::ZeroDimensionalReshape` is
+    /// returned if `dims` is empty.
+    ///
+    /// Because the data is shared, accessing the data of one array while an accessor to the
+    /// data of the other exists is not tracked.
+    pub fn try_reshape<'target, Tgt, const M: usize>(
+        &self,
+        target: Tgt,
+        dims: [usize; M],
+    ) -> JlrsResult<ArrayBaseData<'target, 'data, Tgt, T, -1>>
+    where
+        Tgt: Target<'target>,
+    {
+        if M == 0 {
+            Err(InstantiationError::ZeroDimensionalReshape)?;
+        }
+
+        let dim_size = dims.size();
+        let vec_size = self.length();
+        if dim_size != vec_size {
+            Err(InstantiationError::ArraySizeMismatch { dim_size, vec_size })?;
+        }
+
+        let reshape = inline_static_ref!(RESHAPE, Value, "Base.reshape", &target);
+
+        // Safety: Base.reshape is called with this array and a tuple of dimensions with the same
+        // number of elements, it returns an array that shares its data with this array.
+        unsafe {
+            let arr = target.local_scope::<_, 1>(|mut frame| {
+                let tuple = sized_dim_tuple(&mut frame, &dims);
+                reshape
+                    .call_unchecked(&frame, [self.as_value(), tuple])
+                    .ptr()
+            });
+
+            Ok(target.data_from_ptr(arr.cast(), Private))
+        }
+    }
+
+    /// Reshape this array to `dims` without copying its data, the rank of the reshaped array is
+    /// tracked at the type level.
+    ///
+    /// This method behaves like [`ArrayBase::try_reshape`], but returns an array with rank `M`.
+    /// The rank is typically inferred from the type of the result, e.g. when a
+    /// [`TypedRankedArray`] is expected. If `M` is not -1 it must be equal to the number of
+    /// dimensions, otherwise this method fails to compile.
+    ///
+    /// The reshaped array has the same `'data` lifetime as this array because their data is
+    /// shared, the reshaped array keeps the data alive in Julia.
+    pub fn reshape<'target, Tgt, const M: isize, const K: usize>(
+        &self,
+        target: Tgt,
+        dims: [usize; K],
+    ) -> JlrsResult<ArrayBaseData<'target, 'data, Tgt, T, M>>
+    where
+        Tgt: Target<'target>,
+    {
+        let _ = DimsRankAssert::<[usize; K], M>::ASSERT_VALID_RANK;
+
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let arr = self.try_reshape(&mut frame, dims)?;
+            // Safety: the rank of the reshaped array is K, which is compatible with M.
+            unsafe { Ok(target.data_from_ptr(arr.unwrap_non_null(Private).cast(), Private)) }
+        })
+    }
+}
+
+// Sorting
+impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N> {
+    /// Sort this array in place, this is equivalent to `sort!(self)` in Julia.
+    ///
+    /// The array is tracked exclusively while it's sorted, an error is returned if it's already
+    /// tracked. If Julia throws an exception, e.g. because the array is not one-dimensional or
+    /// its elements can't be compared, it's caught and returned. This requires one slot on the
+    /// GC stack.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// its content, and that no running Julia code is accessing this data.
+    pub unsafe fn sort<'target, Tgt>(&mut self, target: &Tgt) -> JlrsResult<()>
+    where
+        Tgt: Target<'target>,
+    {
+        let sort = inline_static_ref!(SORT, Value, "Base.sort!", target);
+        let _tracked = self.track_exclusive()?;
+
+        target.local_scope::<_, 1>(|mut frame| {
+            sort.call1(&mut frame, self.as_value()).into_jlrs_result()?;
+            Ok(())
+        })
+    }
+
+    /// Sort this array in place using the comparison function `lt`, this is equivalent to
+    /// `sort!(self; lt)` in Julia.
+    ///
+    /// `lt` must be a function that takes two elements and returns `true` if the first is less
+    /// than the second. The array is tracked exclusively while it's sorted, an error is returned
+    /// if it's already tracked. If Julia throws an exception it's caught and returned. This
+    /// requires two slots on the GC stack.
+    ///
+    /// Safety:
+    ///
+    /// Mutating Julia data is generally unsafe. You must guarantee that you're allowed to mutate
+    /// its content, and that no running Julia code is accessing this data. `lt` can execute
+    /// arbitrary Julia code.
+    pub unsafe fn sort_by<'target, Tgt>(&mut self, target: &Tgt, lt: Value) -> JlrsResult<()>
+    where
+        Tgt: Target<'target>,
+    {
+        let sort = inline_static_ref!(SORT, Value, "Base.sort!", target);
+        let _tracked = self.track_exclusive()?;
+
+        target.local_scope::<_, 2>(|mut frame| {
+            let kws = crate::named_tuple!(&mut frame, "lt" => lt);
+            sort.provide_keywords(kws)?
+                .call1(&mut frame, self.as_value())
+                .into_jlrs_result()?;
+            Ok(())
+        })
+    }
+
+    /// Returns the permutation that sorts this array, this is equivalent to `sortperm(self)` in
+    /// Julia.
+    ///
+    /// The permutation contains 1-based indices. The array is tracked while the permutation is
+    /// computed, an error is returned if it's already tracked exclusively. If Julia throws an
+    /// exception it's caught and returned. This requires one slot on the GC stack.
+    ///
+    /// Safety:
+    ///
+    /// No running Julia code must be mutating this data.
+    pub unsafe fn sortperm<'target, Tgt>(
+        &self,
+        target: Tgt,
+    ) -> JlrsResult<TypedVectorData<'target, 'static, Tgt, isize>>
+    where
+        Tgt: Target<'target>,
+    {
+        let sortperm = inline_static_ref!(SORTPERM, Value, "Base.sortperm", &target);
+        let _tracked = self.track_shared()?;
+
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let perm = sortperm
+                .call1(&mut frame, self.as_value())
+                .into_jlrs_result()?
+                .cast::<TypedVector<isize>>()?;
+
+            // Safety: the permutation is a new array, it doesn't borrow any data.
+            Ok(target.data_from_ptr(perm.unwrap_non_null(Private), Private))
+        })
+    }
+}
+
+// Structured matrices
+impl<'scope, 'data, T> ArrayBase<'scope, 'data, T, 2> {
+    /// Returns the diagonal of this matrix as a new vector, this is equivalent to
+    /// `LinearAlgebra.diag(self)` in Julia.
+    ///
+    /// The LinearAlgebra package must have been loaded, otherwise `AccessError::ModuleNotFound`
+    /// is returned. If Julia throws an exception it's caught and returned.
+    ///
+    /// Safety:
+    ///
+    /// No mutable accessors to the data of this matrix must exist.
+    pub unsafe fn diagonal<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        self.call_linear_algebra(target, "diag")
+    }
+
+    /// Wrap this matrix in a `LinearAlgebra.Diagonal`, this is equivalent to
+    /// `LinearAlgebra.Diagonal(self)` in Julia.
+    ///
+    /// The diagonal of the matrix is copied. The LinearAlgebra package must have been loaded,
+    /// otherwise `AccessError::ModuleNotFound` is returned. If Julia throws an exception it's
+    /// caught and returned.
+    ///
+    /// Safety:
+    ///
+    /// No mutable accessors to the data of this matrix must exist.
+    pub unsafe fn diagonal_matrix<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        self.call_linear_algebra(target, "Diagonal")
+    }
+
+    /// Returns an upper triangular view of this matrix, this is equivalent to
+    /// `LinearAlgebra.UpperTriangular(self)` in Julia.
+    ///
+    /// The view shares its data with this matrix. The LinearAlgebra package must have been
+    /// loaded, otherwise `AccessError::ModuleNotFound` is returned. If Julia throws an exception
+    /// it's caught and returned.
+    ///
+    /// Safety:
+    ///
+    /// No accessors to the data of this matrix must exist while the view is used mutably, and the
+    /// view must not be used mutably while accessors to the data of this matrix exist.
+    pub unsafe fn upper_triangular<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        self.call_linear_algebra(target, "UpperTriangular")
+    }
+
+    /// Returns a lower triangular view of this matrix, this is equivalent to
+    /// `LinearAlgebra.LowerTriangular(self)` in Julia.
+    ///
+    /// The view shares its data with this matrix. The LinearAlgebra package must have been
+    /// loaded, otherwise `AccessError::ModuleNotFound` is returned. If Julia throws an exception
+    /// it's caught and returned.
+    ///
+    /// Safety:
+    ///
+    /// No accessors to the data of this matrix must exist while the view is used mutably, and the
+    /// view must not be used mutably while accessors to the data of this matrix exist.
+    pub unsafe fn lower_triangular<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        self.call_linear_algebra(target, "LowerTriangular")
+    }
+
+    /// Returns a symmetric view of the upper triangle of this matrix, this is equivalent to
+    /// `LinearAlgebra.Symmetric(self)` in Julia.
+    ///
+    /// The view shares its data with this matrix. The LinearAlgebra package must have been
+    /// loaded, otherwise `AccessError::ModuleNotFound` is returned. If Julia throws an exception,
+    /// e.g. because the matrix is not square, it's caught and returned.
+    ///
+    /// Safety:
+    ///
+    /// No accessors to the data of this matrix must exist while the view is used mutably, and the
+    /// view must not be used mutably while accessors to the data of this matrix exist.
+    pub unsafe fn symmetric<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        self.call_linear_algebra(target, "Symmetric")
+    }
+
+    unsafe fn call_linear_algebra<'target, Tgt>(
+        self,
+        target: Tgt,
+        name: &str,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        let Some(lin_alg) = Module::package_root_module(&target, "LinearAlgebra") else {
+            Err(AccessError::ModuleNotFound {
+                module: "LinearAlgebra".into(),
+            })?
+        };
+
+        let func = lin_alg.global(&target, name)?.as_value();
+        Ok(func.call1(target, self.as_value()))
+    }
 }
 
 // Layout checks
@@ -2112,6 +2650,47 @@ impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N> {
         Ok(InlineAccessor::new(self))
     }
 
+    /// Copy the inline elements of this array to `dst`.
+    ///
+    /// The elements are copied with a single `memcpy`, without creating an accessor. If the array
+    /// doesn't have an inline layout `ArrayLayoutError::NotInline` is returned. If `L` is not a
+    /// valid field layout for the element type `TypeError::InvalidLayout` is returned. If the
+    /// length of `dst` is not equal to the length of the array
+    /// `AccessError::ArrayLengthMismatch` is returned.
+    ///
+    /// Safety:
+    ///
+    /// No mutable accessors to this data must exist. If the elements contain references to Julia
+    /// data, these references are only valid while they're reachable from this array.
+    pub unsafe fn copy_inline_data_into<L>(&self, dst: &mut [L]) -> JlrsResult<()>
+    where
+        L: ValidField,
+    {
+        if !self.has_inline_layout() {
+            Err(ArrayLayoutError::NotInline {
+                element_type: self.element_type().display_string_or(CANNOT_DISPLAY_TYPE),
+            })?;
+        }
+
+        let ty = self.element_type();
+        if !L::valid_field(ty) {
+            Err(TypeError::InvalidLayout {
+                value_type: self.element_type().display_string_or(CANNOT_DISPLAY_TYPE),
+            })?;
+        }
+
+        let src_len = self.length();
+        let dst_len = dst.len();
+        if dst_len != src_len {
+            Err(AccessError::ArrayLengthMismatch { dst_len, src_len })?;
+        }
+
+        let src = self.data_ptr().cast::<L>();
+        std::ptr::copy_nonoverlapping(src, dst.as_mut_ptr(), src_len);
+
+        Ok(())
+    }
+
     /// Create an accessor for inline data with layout `L` without checking any invariants.
     ///
     /// Safety:
@@ -2562,6 +3141,33 @@ impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N> {
     }
 }
 
+// Iterators
+impl<'scope, 'data, T, const N: isize> ArrayBase<'scope, 'data, T, N>
+where
+    T: ConstructType + ValidField + IsBits + Copy,
+{
+    /// Returns an iterator over copies of the elements of this array in column-major order.
+    ///
+    /// Safety:
+    ///
+    /// No mutable accessors to this data must exist.
+    pub unsafe fn typed_iter<'borrow>(&'borrow self) -> Copied<slice::Iter<'borrow, T>> {
+        self.bits_data().into_slice().iter().copied()
+    }
+
+    /// Returns an iterator over mutable references to the elements of this array in
+    /// column-major order.
+    ///
+    /// Safety:
+    ///
+    /// No other accessors to this data must exist. Mutating Julia data is generally unsafe. You
+    /// must guarantee that you're allowed to mutate its content, and that no running Julia code
+    /// is accessing this data.
+    pub unsafe fn typed_iter_mut<'borrow>(&'borrow mut self) -> slice::IterMut<'borrow, T> {
+        self.bits_data_mut().into_mut_slice().iter_mut()
+    }
+}
+
 // Conversions
 impl<'scope, 'data, T> ArrayBase<'scope, 'data, T, -1> {
     /// Sets the rank of this array to `N` if `N` is equal to the rank of `self` at runtime.
@@ -11,9 +11,12 @@
 ///
 /// [`ArrayBase::track_shared`]: crate::data::managed::array::ArrayBase::track_shared
 /// [`ArrayBase::track_exclusive`]: crate::data::managed::array::ArrayBase::track_exclusive
-use std::{ops::Deref, ptr::NonNull};
+use std::{
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
 
-use jl_sys::jlrs_array_data_owner;
+use jl_sys::{inlined::jlrs_array_data_fast, jlrs_array_data_owner};
 
 use super::{
     data::accessor::{
@@ -21,6 +24,7 @@ use super::{
         IndeterminateAccessor, IndeterminateAccessorMut, InlineAccessor, InlineAccessorMut,
         ManagedAccessor, ManagedAccessorMut, ValueAccessor, ValueAccessorMut,
     },
+    dimensions::Dims,
     ArrayBase, Unknown,
 };
 use crate::{
@@ -34,7 +38,7 @@ use crate::{
     },
     error::{ArrayLayoutError, TypeError, CANNOT_DISPLAY_TYPE},
     memory::context::ledger::Ledger,
-    prelude::{JlrsResult, Managed, Value},
+    prelude::{DataType, JlrsResult, Managed, Value},
     private::Private,
 };
 
@@ -773,6 +777,242 @@ impl<T, const N: isize> Drop for TrackedArrayBaseMut<'_, '_, T, N> {
     }
 }
 
+/// An immutable slice of the `isbits` data of a tracked array.
+///
+/// The array remains tracked until this guard is dropped. It can be created by calling
+/// [`ArrayBase::bits_data_tracked`].
+///
+/// Growing or shrinking a vector can cause Julia to reallocate its data, which invalidates
+/// slices of the old data. In debug builds the slice remembers the data pointer and length of the
+/// array when it's created, dereferencing it panics if either has changed since.
+///
+/// [`ArrayBase::bits_data_tracked`]: crate::data::managed::array::ArrayBase::bits_data_tracked
+pub struct TrackedSlice<'scope, 'data, T, const N: isize> {
+    tracked: TrackedArrayBase<'scope, 'data, T, N>,
+    generation: Generation,
+}
+
+impl<'scope, 'data, T, const N: isize> TrackedSlice<'scope, 'data, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    pub(crate) fn new(array: ArrayBase<'scope, 'data, T, N>) -> JlrsResult<Self> {
+        let tracked = TrackedArrayBase::track_shared(array)?;
+        let generation = Generation::current(array);
+        Ok(TrackedSlice {
+            tracked,
+            generation,
+        })
+    }
+}
+
+impl<T, const N: isize> Deref for TrackedSlice<'_, '_, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            let array = self.tracked.data;
+            self.generation.assert_current(array);
+            let sz = array.dimensions().size();
+            let ptr = jlrs_array_data_fast(array.unwrap(Private)).cast::<T>();
+            std::slice::from_raw_parts(ptr, sz)
+        }
+    }
+}
+
+/// A mutable slice of the `isbits` data of an exclusively tracked array.
+///
+/// The array remains tracked until this guard is dropped. It can be created by calling
+/// [`ArrayBase::bits_data_mut_tracked`].
+///
+/// [`ArrayBase::bits_data_mut_tracked`]: crate::data::managed::array::ArrayBase::bits_data_mut_tracked
+///
+/// Like [`TrackedSlice`], dereferencing this slice panics in debug builds if the array has been
+/// resized since the slice was created.
+pub struct TrackedMutSlice<'scope, 'data, T, const N: isize> {
+    tracked: TrackedArrayBaseMut<'scope, 'data, T, N>,
+    generation: Generation,
+}
+
+impl<'scope, 'data, T, const N: isize> TrackedMutSlice<'scope, 'data, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    pub(crate) fn new(array: ArrayBase<'scope, 'data, T, N>) -> JlrsResult<Self> {
+        let tracked = TrackedArrayBaseMut::track_exclusive(array)?;
+        let generation = Generation::current(array);
+        Ok(TrackedMutSlice {
+            tracked,
+            generation,
+        })
+    }
+}
+
+impl<T, const N: isize> Deref for TrackedMutSlice<'_, '_, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            let array = self.tracked.data;
+            self.generation.assert_current(array);
+            let sz = array.dimensions().size();
+            let ptr = jlrs_array_data_fast(array.unwrap(Private)).cast::<T>();
+            std::slice::from_raw_parts(ptr, sz)
+        }
+    }
+}
+
+impl<T, const N: isize> DerefMut for TrackedMutSlice<'_, '_, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            let array = self.tracked.data;
+            self.generation.assert_current(array);
+            let sz = array.dimensions().size();
+            let ptr = jlrs_array_data_fast(array.unwrap(Private)).cast::<T>();
+            std::slice::from_raw_parts_mut(ptr, sz)
+        }
+    }
+}
+
+/// The raw bytes of the `isbits` data of a tracked array.
+///
+/// The array remains tracked until this guard is dropped. It can be created by calling
+/// [`ArrayBase::as_raw_bytes`].
+///
+/// [`ArrayBase::as_raw_bytes`]: crate::data::managed::array::ArrayBase::as_raw_bytes
+pub struct TrackedBytes<'scope, 'data, T, const N: isize> {
+    tracked: TrackedArrayBase<'scope, 'data, T, N>,
+    generation: Generation,
+}
+
+impl<'scope, 'data, T, const N: isize> TrackedBytes<'scope, 'data, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    pub(crate) fn new(array: ArrayBase<'scope, 'data, T, N>) -> JlrsResult<Self> {
+        // Padding bytes are uninitialized, they must not be exposed.
+        let element_type = array.element_type();
+        if let Ok(ty) = element_type.cast::<DataType>() {
+            if has_padding(ty) {
+                Err(ArrayLayoutError::HasPadding {
+                    element_type: element_type.display_string_or(CANNOT_DISPLAY_TYPE),
+                })?
+            }
+        }
+
+        let tracked = TrackedArrayBase::track_shared(array)?;
+        let generation = Generation::current(array);
+        Ok(TrackedBytes {
+            tracked,
+            generation,
+        })
+    }
+}
+
+impl<T, const N: isize> Deref for TrackedBytes<'_, '_, T, N>
+where
+    T: ConstructType + ValidField + IsBits,
+{
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            let array = self.tracked.data;
+            self.generation.assert_current(array);
+            let sz = array.dimensions().size() * std::mem::size_of::<T>();
+            let ptr = jlrs_array_data_fast(array.unwrap(Private)).cast::<u8>();
+            std::slice::from_raw_parts(ptr, sz)
+        }
+    }
+}
+
+// Returns `true` if there are gaps between the fields of `ty` or after its last field, or if
+// any of its fields contain padding. Fields of isbits types are stored in declaration order.
+fn has_padding(ty: DataType) -> bool {
+    let Some(n_fields) = ty.n_fields() else {
+        return false;
+    };
+
+    let mut expected_offset = 0;
+    for idx in 0..n_fields as usize {
+        // Safety: the field exists.
+        let (offset, size, field_type) = unsafe {
+            (
+                ty.field_offset_unchecked(idx),
+                ty.field_size_unchecked(idx),
+                ty.field_type_unchecked(idx),
+            )
+        };
+
+        if offset != expected_offset {
+            return true;
+        }
+
+        if let Ok(field_type) = field_type.cast::<DataType>() {
+            if has_padding(field_type) {
+                return true;
+            }
+        }
+
+        expected_offset += size;
+    }
+
+    n_fields != 0 && ty.size() != Some(expected_offset)
+}
+
+// The data pointer and length of an array when a tracked slice was created. Only debug builds
+// store and check them.
+#[derive(Clone, Copy)]
+struct Generation {
+    #[cfg(debug_assertions)]
+    ptr: *mut std::ffi::c_void,
+    #[cfg(debug_assertions)]
+    len: usize,
+}
+
+impl Generation {
+    #[inline]
+    fn current<T, const N: isize>(array: ArrayBase<'_, '_, T, N>) -> Self {
+        #[cfg(debug_assertions)]
+        unsafe {
+            Generation {
+                ptr: jlrs_array_data_fast(array.unwrap(Private)),
+                len: array.length(),
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = array;
+            Generation {}
+        }
+    }
+
+    #[inline]
+    fn assert_current<T, const N: isize>(self, array: ArrayBase<'_, '_, T, N>) {
+        #[cfg(debug_assertions)]
+        {
+            let current = Self::current(array);
+            assert!(
+                self.ptr == current.ptr && self.len == current.len,
+                "the array has been resized since this slice was created"
+            );
+        }
+
+        #[cfg(not(debug_assertions))]
+        let _ = array;
+    }
+}
+
 pub type TrackedArray<'scope, 'data> = TrackedArrayBase<'scope, 'data, Unknown, -1>;
 pub type TrackedTypedArray<'scope, 'data, T> = TrackedArrayBase<'scope, 'data, T, -1>;
 pub type TrackedRankedArray<'scope, 'data, const N: isize> =
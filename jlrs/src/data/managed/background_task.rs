@@ -1,7 +1,10 @@
 //! Task delegated to a background thread that can call into Julia.
 
+#[cfg(feature = "tokio-rt")]
+use std::sync::OnceLock;
 use std::{
     fmt,
+    future::Future,
     marker::{PhantomData, PhantomPinned},
     mem::{self, MaybeUninit},
     os::raw::c_void,
@@ -217,6 +220,21 @@ pub type BackgroundTaskRef<'scope, T> = Ref<'scope, 'static, BackgroundTask<'sco
 /// [`BackgroundTaskRef`].
 pub type BackgroundTaskRet<T> = BackgroundTaskRef<'static, T>;
 
+unsafe impl<T> CCallReturn for BackgroundTaskRet<T>
+where
+    T: 'static + HasLayout<'static, 'static>,
+    T::Layout: IsBits + Clone,
+{
+    type CCallReturnType = Value<'static, 'static>;
+    type FunctionReturnType = BackgroundTask<'static, T>;
+    type ReturnAs = Self;
+
+    #[inline]
+    unsafe fn return_or_throw(self) -> Self::ReturnAs {
+        self
+    }
+}
+
 /// [`BackgroundTask`] or [`BackgroundTaskRef`], depending on the target type `Tgt`.
 pub type BackgroundTaskData<'target, T, Tgt> =
     <Tgt as TargetType<'target>>::Data<'static, BackgroundTask<'target, T>>;
@@ -317,6 +335,23 @@ where
 
     unsafe impl<L> Send for Sendable<L> {}
 
+    // Julia waits until the task has been notified before it fetches the result, so the task
+    // must be notified even if `func` returns an error or panics.
+    struct NotifyOnDrop<'scope, T>(BackgroundTask<'scope, T>)
+    where
+        T: 'static + HasLayout<'static, 'static>,
+        T::Layout: IsBits + Clone + CCallReturn;
+
+    impl<T> Drop for NotifyOnDrop<'_, T>
+    where
+        T: 'static + HasLayout<'static, 'static>,
+        T::Layout: IsBits + Clone + CCallReturn,
+    {
+        fn drop(&mut self) {
+            unsafe { self.0.notify() }
+        }
+    }
+
     unsafe {
         target.with_local_scope::<_, _, 1>(|target, mut frame| {
             let task = BackgroundTask::new(&mut frame);
@@ -325,15 +360,11 @@ where
             let handle = thread::spawn(move || {
                 let task_ref = task_ref.inner();
                 let task = task_ref.as_managed();
+                let _notify = NotifyOnDrop(task);
 
-                match func() {
-                    Ok(res) => {
-                        task.set(res);
-                        task.notify();
-                        Ok(())
-                    }
-                    Err(e) => Err(e),
-                }
+                let res = func()?;
+                task.set(res);
+                Ok(())
             });
 
             task.set_join_handle(handle);
@@ -342,6 +373,76 @@ where
     }
 }
 
+/// Spawn a new background task that runs `future` to completion.
+///
+/// The future is polled on a new thread which can't call into Julia. If the `tokio-rt` feature
+/// is enabled it's executed by a current-thread tokio runtime that is shared by all background
+/// futures, so it can use tokio's timers and, if the `tokio-net` feature is enabled, its I/O
+/// drivers. If this runtime can't be created, fetching the task throws an exception.
+///
+/// This function is used to export `async fn`s with the `julia_module` macro.
+pub fn spawn_background_future<'target, T, F, Tgt>(
+    target: Tgt,
+    future: F,
+) -> BackgroundTaskData<'target, T, Tgt>
+where
+    F: 'static + Send + Future<Output = JlrsResult<T::Layout>>,
+    T: 'static + HasLayout<'static, 'static>,
+    T::Layout: IsBits + Clone + CCallReturn,
+    Tgt: Target<'target>,
+{
+    spawn_background_task::<T, _, _>(target, move || block_on(future)?)
+}
+
+#[cfg(feature = "tokio-rt")]
+fn block_on<F: Future>(future: F) -> JlrsResult<F::Output> {
+    // The runtime is created when the first background future is spawned. Multiple threads can
+    // call `block_on` concurrently, one of them drives the timers and I/O at a time.
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime.block_on(future));
+    }
+
+    let mut builder = tokio::runtime::Builder::new_current_thread();
+    builder.enable_time();
+
+    #[cfg(feature = "tokio-net")]
+    builder.enable_io();
+
+    // If another thread has initialized the runtime in the meantime, this one is dropped.
+    let runtime = builder.build().map_err(JlrsError::other)?;
+    Ok(RUNTIME.get_or_init(|| runtime).block_on(future))
+}
+
+#[cfg(not(feature = "tokio-rt"))]
+fn block_on<F: Future>(future: F) -> JlrsResult<F::Output> {
+    use std::{
+        pin::pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return Ok(output),
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
 // Should only be called from Julia.
 unsafe extern "C" fn background_task_fetch<T>(handle: BackgroundTask<T>) -> ValueRet
 where
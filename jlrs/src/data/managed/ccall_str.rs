@@ -0,0 +1,114 @@
+//! A Julia `String` used as a `Cstring` argument of a `ccall`ed function.
+//!
+//! When a function that takes a [`CCallStr`] is exported with the `julia_module` macro, the
+//! generated Julia function takes a `String` and `ccall`s the Rust function with a `Cstring`.
+//! Julia checks that the string doesn't contain any null bytes before calling the function, an
+//! `ArgumentError` is thrown if it does. The Rust function receives a pointer to the data of the
+//! string, which can be accessed as a `&CStr` without copying it.
+//!
+//! ```ignore
+//! use jlrs::{data::managed::ccall_str::CCallStr, prelude::*};
+//!
+//! fn c_str_len(s: CCallStr) -> usize {
+//!     s.as_c_str().to_bytes().len()
+//! }
+//!
+//! julia_module! {
+//!     become c_str_module_init_fn;
+//!
+//!     fn c_str_len(s: CCallStr) -> usize;
+//! }
+//! ```
+
+use std::{
+    ffi::{c_char, CStr},
+    marker::PhantomData,
+    ptr::NonNull,
+    str,
+};
+
+use super::{string::JuliaString, value::ValueData, Managed};
+use crate::{
+    convert::ccall_types::CCallArg,
+    data::types::construct_type::{ConstructType, TypeVarEnv},
+    error::{JlrsError, JlrsResult},
+    inline_static_ref,
+    memory::target::Target,
+    prelude::Value,
+};
+
+/// A null-terminated string passed from Julia to Rust as a `Cstring`.
+///
+/// See the [module-level docs] for more information.
+///
+/// [module-level docs]: self
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct CCallStr<'scope>(NonNull<c_char>, PhantomData<&'scope CStr>);
+
+impl<'scope> CCallStr<'scope> {
+    /// Returns the string as a `CStr`.
+    #[inline]
+    pub fn as_c_str(self) -> &'scope CStr {
+        // Safety: Julia has checked the string is null-terminated and contains no other null
+        // bytes, the string is rooted until the function returns.
+        unsafe { CStr::from_ptr(self.0.as_ptr()) }
+    }
+
+    /// Returns the string as a string slice, or an error if the string is not encoded as
+    /// UTF-8.
+    #[inline]
+    pub fn as_str(self) -> JlrsResult<&'scope str> {
+        Ok(str::from_utf8(self.as_c_str().to_bytes()).map_err(JlrsError::other)?)
+    }
+
+    /// Returns the string as a string slice without checking if the string is properly encoded.
+    ///
+    /// Safety: the string must be properly encoded.
+    #[inline]
+    pub unsafe fn as_str_unchecked(self) -> &'scope str {
+        str::from_utf8_unchecked(self.as_c_str().to_bytes())
+    }
+}
+
+unsafe impl<'scope> CCallArg for CCallStr<'scope> {
+    type CCallArgType = CstringTypeConstructor;
+    type FunctionArgType = JuliaString<'scope>;
+}
+
+/// Construct a new `Base.Cstring` type object.
+pub struct CstringTypeConstructor;
+
+unsafe impl ConstructType for CstringTypeConstructor {
+    type Static = CstringTypeConstructor;
+
+    const CACHEABLE: bool = false;
+
+    #[inline]
+    fn construct_type_uncached<'target, Tgt>(target: Tgt) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        Self::base_type(&target).unwrap().root(target)
+    }
+
+    #[inline]
+    fn construct_type_with_env_uncached<'target, Tgt>(
+        target: Tgt,
+        _: &TypeVarEnv,
+    ) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        Self::base_type(&target).unwrap().root(target)
+    }
+
+    #[inline]
+    fn base_type<'target, Tgt>(target: &Tgt) -> Option<Value<'target, 'static>>
+    where
+        Tgt: Target<'target>,
+    {
+        let value = inline_static_ref!(STATIC, Value, "Base.Cstring", target);
+        Some(value)
+    }
+}
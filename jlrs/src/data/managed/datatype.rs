@@ -23,6 +23,7 @@ use jlrs_macros::julia_version;
 
 use super::{type_name::TypeName, value::ValueData, Ref};
 use crate::{
+    call::Call,
     catch::{catch_exceptions, unwrap_exc},
     convert::to_symbol::ToSymbol,
     data::{
@@ -39,7 +40,7 @@ use crate::{
         types::{construct_type::TypeVarEnv, typecheck::Typecheck},
     },
     error::{InstantiationError, JlrsResult},
-    impl_julia_typecheck,
+    impl_julia_typecheck, inline_static_ref,
     memory::target::{unrooted::Unrooted, Target, TargetResult},
     private::Private,
 };
@@ -213,6 +214,26 @@ impl<'scope> DataType<'scope> {
         None
     }
 
+    /// Returns the names of all fields of this type in declaration order.
+    ///
+    /// Symbols are globally rooted, so the names can be used for as long as this type can.
+    pub fn field_name_symbols(self) -> Vec<Symbol<'scope>> {
+        self.field_names_iter_priv().collect()
+    }
+
+    /// Returns an iterator over the names of all fields of this type in declaration order.
+    ///
+    /// Unlike [`DataType::field_name_symbols`], this doesn't allocate. An error is returned for
+    /// each name that isn't a valid UTF-8 encoded string.
+    pub fn field_names_iter(self) -> impl Iterator<Item = JlrsResult<&'scope str>> {
+        self.field_names_iter_priv().map(|sym| sym.as_str())
+    }
+
+    fn field_names_iter_priv(self) -> impl Iterator<Item = Symbol<'scope>> {
+        let n_names = self.field_names().len();
+        (0..n_names).filter_map(move |idx| self.field_name(idx))
+    }
+
     /// Returns the instance if this type is a singleton.
     #[inline]
     pub fn instance(self) -> Option<Value<'scope, 'static>> {
@@ -244,6 +265,10 @@ impl<'scope> DataType<'scope> {
     }
 
     /// Returns true if this is an abstract type.
+    ///
+    /// This only depends on how the type has been declared, the body of a `UnionAll` like
+    /// `Array{T, N}` is not abstract but can't be instantiated either. Use
+    /// [`DataType::is_concrete_type`] to check if a type can have instances.
     #[inline]
     pub fn is_abstract(self) -> bool {
         // Safety: the pointer points to valid data
@@ -257,7 +282,10 @@ impl<'scope> DataType<'scope> {
         unsafe { jl_sys::jlrs_datatype_mutable(self.unwrap(Private)) != 0 }
     }
 
-    /// Returns true if this type can have instances
+    /// Returns true if this type can have instances.
+    ///
+    /// Abstract types and types with free type parameters, e.g. the body of a `UnionAll` like
+    /// `Array{T, N}`, are not concrete.
     #[inline]
     pub fn is_concrete_type(self) -> bool {
         // Safety: the pointer points to valid data
@@ -285,6 +313,23 @@ impl<'scope> DataType<'scope> {
         unsafe { jl_sys::jlrs_datatype_isinlinealloc(self.unwrap(Private)) != 0 }
     }
 
+    /// Returns how values of this type are passed to and returned from a function called with
+    /// `ccall`.
+    ///
+    /// Bits-types are passed by value, mutable and non-concrete types are passed as a pointer to
+    /// a boxed value. Values of other immutable types might be passed either way, these should
+    /// be passed as a `TypedValue` or `Value` instead. This can be used to check the signature of
+    /// a function before it's exported.
+    pub fn ccall_abi(self) -> CCallAbi {
+        if self.is_bits() {
+            CCallAbi::ByValue
+        } else if self.mutable() || !self.is_concrete_type() {
+            CCallAbi::Boxed
+        } else {
+            CCallAbi::Ambiguous
+        }
+    }
+
     /// Whether this is declared with 'primitive type' keyword (sized, no fields, and immutable)
     #[inline]
     pub fn is_primitive_type(self) -> bool {
@@ -564,6 +609,24 @@ impl<'scope> DataType<'scope> {
         unsafe { Some(jlrs_datatype_first_ptr(self.unwrap(Private)) != -1) }
     }
 
+    /// Returns the type that instances of `self` and `other` are promoted to, this is equivalent
+    /// to `promote_type(self, other)` in Julia.
+    ///
+    /// The promoted type isn't necessarily a `DataType`, e.g. `promote_type(Nothing, Int)` is
+    /// `Union{Nothing, Int}`. If Julia throws an exception it's caught and returned.
+    pub fn promote_type<'target, Tgt>(
+        self,
+        target: Tgt,
+        other: DataType,
+    ) -> ValueResult<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let func = inline_static_ref!(PROMOTE_TYPE, Value, "Base.promote_type", &target);
+        // Safety: Base.promote_type is called with two types.
+        unsafe { func.call2(target, self.as_value(), other.as_value()) }
+    }
+
     /// Wraps this type as a `UnionAll` if it has free `TypeVar`s, returns `self` otherwise.
     #[inline]
     pub fn rewrap<'target, Tgt: Target<'target>>(
@@ -1170,6 +1233,18 @@ impl<'scope> ManagedPriv<'scope, '_> for DataType<'scope> {
     }
 }
 
+/// How values of a [`DataType`] are passed across `ccall`, see [`DataType::ccall_abi`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CCallAbi {
+    /// Values are passed by value, the Rust type must have the same layout.
+    ByValue,
+    /// Values are passed as a pointer to a boxed value.
+    Boxed,
+    /// Values might be passed by value or boxed, whether a value is boxed is not specified. Use
+    /// a `TypedValue` or `Value` to pass these values.
+    Ambiguous,
+}
+
 impl_construct_type_managed!(DataType, 1, jl_datatype_type);
 
 /// A reference to a [`DataType`] that has not been explicitly rooted.
@@ -0,0 +1,465 @@
+//! Managed type for `Dict`.
+//!
+//! A `JuliaDict<K, V>` is a `Dict{K, V}` whose key and value types are constructed from the type
+//! constructors `K` and `V`. Entries are accessed with the functions Julia provides for this
+//! purpose, e.g. `haskey`, `getindex`, `setindex!` and `iterate`, so any `Dict` created in Julia
+//! can be used from Rust and vice versa.
+
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData, ptr::NonNull};
+
+use jl_sys::jl_value_t;
+
+use crate::{
+    call::Call,
+    convert::{
+        ccall_types::{CCallArg, CCallReturn},
+        into_jlrs_result::IntoJlrsResult,
+        into_julia::IntoJulia,
+    },
+    data::{
+        layout::{
+            nothing::Nothing,
+            valid_layout::{ValidField, ValidLayout},
+        },
+        managed::{
+            datatype::DataType,
+            private::ManagedPriv,
+            union_all::UnionAll,
+            value::{Value, ValueData, ValueRef},
+            Managed, Ref,
+        },
+        types::{
+            construct_type::{ConstructType, TypeVarEnv},
+            typecheck::Typecheck,
+        },
+    },
+    error::JlrsResult,
+    inline_static_ref,
+    memory::{
+        scope::LocalScope,
+        target::{frame::GcFrame, reusable_slot::ReusableSlot, Target, TargetResult, TargetType},
+    },
+    private::Private,
+};
+
+/// A `Dict{K, V}`.
+#[repr(transparent)]
+pub struct JuliaDict<'scope, 'data, K, V>(
+    NonNull<jl_value_t>,
+    PhantomData<K>,
+    PhantomData<V>,
+    PhantomData<&'scope ()>,
+    PhantomData<&'data mut ()>,
+);
+
+impl<K: ConstructType, V: ConstructType> JuliaDict<'_, '_, K, V> {
+    /// Create a new, empty `Dict{K, V}`.
+    ///
+    /// If Julia throws an exception it's caught and returned as an error. This requires two slots
+    /// on the GC stack.
+    pub fn new<'target, Tgt>(target: Tgt) -> JlrsResult<JuliaDictData<'target, 'static, K, V, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 2>(|target, mut frame| {
+            let ty = Self::construct_type(&mut frame);
+
+            // Safety: the type constructor of a Dict can be called without arguments, exceptions
+            // are caught.
+            let dict = unsafe { ty.call0(&mut frame) }.into_jlrs_result()?;
+
+            // Safety: the constructed dict is a Dict{K, V}.
+            unsafe { Ok(dict.cast_unchecked::<JuliaDict<K, V>>().root(target)) }
+        })
+    }
+}
+
+impl<K, V> JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType + IntoJulia,
+    V: ConstructType + IntoJulia,
+{
+    /// Create a new `Dict{K, V}` and insert all entries of `map` into it.
+    ///
+    /// If Julia throws an exception it's caught and returned as an error. This requires four
+    /// slots on the GC stack.
+    pub fn from_hash_map<'target, S, Tgt>(
+        target: Tgt,
+        map: HashMap<K, V, S>,
+    ) -> JlrsResult<JuliaDictData<'target, 'static, K, V, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let mut dict = Self::new(&mut frame)?;
+
+            for (key, value) in map {
+                frame.local_scope::<_, 2>(|mut frame| {
+                    let key = Value::new(&mut frame, key);
+                    let value = Value::new(&mut frame, value);
+                    dict.insert(&frame, key, value)
+                })?;
+            }
+
+            Ok(dict.root(target))
+        })
+    }
+}
+
+impl<'scope, 'data, K: ConstructType, V: ConstructType> JuliaDict<'scope, 'data, K, V> {
+    /// Returns the number of entries in this dictionary.
+    pub fn len(self) -> usize {
+        let value = self.as_value();
+        let ty = value.datatype();
+
+        // Safety: a `JuliaDict` is only created for instances of `Base.Dict`, which is a mutable
+        // type with an `Int` field `count` that holds the number of entries in every supported
+        // version of Julia, so the index is valid and the field is stored inline at its offset.
+        unsafe {
+            let idx = ty.field_index_unchecked("count") as usize;
+            let offset = ty.field_offset_unchecked(idx) as usize;
+            let ptr = value.data_ptr().cast::<u8>().as_ptr().add(offset);
+            ptr.cast::<isize>().read() as usize
+        }
+    }
+
+    /// Returns `true` if this dictionary has no entries.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value associated with `key`, or `None` if the key doesn't exist.
+    ///
+    /// The lookup is performed with `haskey` and `getindex`. If Julia throws an exception it's
+    /// caught and returned as an error. This requires two slots on the GC stack.
+    pub fn get<'target, Tgt>(
+        self,
+        target: Tgt,
+        key: Value<'_, 'data>,
+    ) -> JlrsResult<Option<ValueData<'target, 'data, Tgt>>>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 2>(|target, mut frame| {
+            let haskey = inline_static_ref!(HASKEY, Value, "Base.haskey", &frame);
+            let getindex = inline_static_ref!(GETINDEX, Value, "Base.getindex", &frame);
+
+            // Safety: exceptions are caught.
+            let has_key = unsafe { haskey.call2(&mut frame, self.as_value(), key) }
+                .into_jlrs_result()?
+                .unbox::<bool>()?
+                .as_bool();
+
+            if !has_key {
+                return Ok(None);
+            }
+
+            // Safety: exceptions are caught.
+            let value =
+                unsafe { getindex.call2(&mut frame, self.as_value(), key) }.into_jlrs_result()?;
+
+            Ok(Some(value.root(target)))
+        })
+    }
+
+    /// Insert `value` for `key` by calling `setindex!`. If the key already exists, its value is
+    /// replaced.
+    ///
+    /// The key and value are converted to `K` and `V` by Julia. If Julia throws an exception,
+    /// e.g. because no such conversion exists, it's caught and returned as an error. This
+    /// requires one slot on the GC stack.
+    pub fn insert<'target, Tgt>(
+        &mut self,
+        target: &Tgt,
+        key: Value<'_, 'data>,
+        value: Value<'_, 'data>,
+    ) -> JlrsResult<()>
+    where
+        Tgt: Target<'target>,
+    {
+        target.local_scope::<_, 1>(|mut frame| {
+            let setindex = inline_static_ref!(SETINDEX, Value, "Base.setindex!", &frame);
+
+            // Safety: exceptions are caught.
+            unsafe { setindex.call3(&mut frame, self.as_value(), value, key) }
+                .into_jlrs_result()?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns an iterator over the entries of this dictionary.
+    ///
+    /// The iterator uses Julia's iteration protocol, no copy of the dictionary is made. Every key
+    /// and value that is returned is rooted in `frame`, which takes two slots per entry. The
+    /// dictionary itself is rooted in `frame`, and one additional slot is used to root the state
+    /// of the iterator.
+    pub fn iter<'iter, 'target>(
+        self,
+        frame: &'iter mut GcFrame<'target>,
+    ) -> DictIter<'iter, 'target, 'data> {
+        let dict = self.as_value().root(&mut *frame);
+        let slot = frame.reusable_slot();
+
+        DictIter {
+            frame,
+            slot,
+            dict,
+            state: None,
+            done: false,
+        }
+    }
+}
+
+/// Iterator over the entries of a [`JuliaDict`], created with [`JuliaDict::iter`].
+///
+/// If Julia throws an exception while iterating it's returned as an error, after which the
+/// iterator is exhausted.
+pub struct DictIter<'iter, 'target, 'data> {
+    frame: &'iter mut GcFrame<'target>,
+    slot: ReusableSlot<'target>,
+    dict: Value<'target, 'data>,
+    state: Option<Value<'target, 'data>>,
+    done: bool,
+}
+
+impl<'target, 'data> Iterator for DictIter<'_, 'target, 'data> {
+    type Item = JlrsResult<(Value<'target, 'data>, Value<'target, 'data>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let dict = self.dict;
+        let state = self.state;
+        let slot = &mut self.slot;
+
+        let res = (&mut *self.frame).with_local_scope::<_, _, 2>(|target, mut frame| {
+            let iterate = inline_static_ref!(ITERATE, Value, "Base.iterate", &frame);
+
+            // Safety: exceptions are caught.
+            let next = unsafe {
+                match state {
+                    Some(state) => iterate.call2(&mut frame, dict, state),
+                    None => iterate.call1(&mut frame, dict),
+                }
+            }
+            .into_jlrs_result()?;
+
+            if next.is::<Nothing>() {
+                return Ok(None);
+            }
+
+            // Safety: iterate returns a tuple (key => value, state) if it doesn't return nothing.
+            let pair = next.get_nth_field(&mut frame, 0)?;
+            let key = pair.get_nth_field(&mut *target, 0)?;
+            let value = pair.get_nth_field(&mut *target, 1)?;
+            let new_state = unsafe { next.get_nth_field(&mut *slot, 1)?.as_value() };
+
+            Ok(Some((key, value, new_state)))
+        });
+
+        match res {
+            Ok(Some((key, value, new_state))) => {
+                self.state = Some(new_state);
+                Some(Ok((key, value)))
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<K, V> Debug for JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{:?}", self.as_value())
+    }
+}
+
+impl<K, V> Clone for JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+}
+
+impl<'scope, 'data, K, V> ManagedPriv<'scope, 'data> for JuliaDict<'scope, 'data, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    type Wraps = jl_value_t;
+    type WithLifetimes<'target, 'da> = JuliaDict<'target, 'da, K, V>;
+    const NAME: &'static str = "Dict";
+
+    // Safety: `inner` must not have been freed yet, the result must never be
+    // used after the GC might have freed it.
+    #[inline]
+    unsafe fn wrap_non_null(inner: NonNull<Self::Wraps>, _: Private) -> Self {
+        Self(inner, PhantomData, PhantomData, PhantomData, PhantomData)
+    }
+
+    #[inline]
+    fn unwrap_non_null(self, _: Private) -> NonNull<Self::Wraps> {
+        self.0
+    }
+}
+
+unsafe impl<K, V> Typecheck for JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    fn typecheck(t: DataType) -> bool {
+        let unrooted = t.unrooted_target();
+        unrooted.local_scope::<_, 1>(|mut frame| {
+            let ty = Self::construct_type(&mut frame);
+            t.as_value().subtype(ty)
+        })
+    }
+}
+
+unsafe impl<K, V> ValidLayout for JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    #[inline]
+    fn valid_layout(v: Value) -> bool {
+        if v.is::<DataType>() {
+            // Safety: v is a DataType
+            let dt = unsafe { v.cast_unchecked::<DataType>() };
+            Self::typecheck(dt)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn type_object<'target, Tgt>(target: &Tgt) -> Value<'target, 'static>
+    where
+        Tgt: Target<'target>,
+    {
+        Self::base_type(target).expect("Dict has a base type")
+    }
+
+    const IS_REF: bool = true;
+}
+
+unsafe impl<K, V> ValidField for Option<JuliaDict<'_, '_, K, V>>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    #[inline]
+    fn valid_field(v: Value) -> bool {
+        Option::<ValueRef>::valid_field(v)
+    }
+}
+
+unsafe impl<K, V> ConstructType for JuliaDict<'_, '_, K, V>
+where
+    K: ConstructType,
+    V: ConstructType,
+{
+    type Static = JuliaDict<'static, 'static, K::Static, V::Static>;
+
+    fn construct_type_uncached<'target, Tgt>(target: Tgt) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 2>(|target, mut frame| unsafe {
+            let key_ty = K::construct_type(&mut frame);
+            let value_ty = V::construct_type(&mut frame);
+            Self::base_type(&frame)
+                .unwrap_unchecked()
+                .apply_type_unchecked(target, [key_ty, value_ty])
+        })
+    }
+
+    #[inline]
+    fn base_type<'target, Tgt>(target: &Tgt) -> Option<Value<'target, 'static>>
+    where
+        Tgt: Target<'target>,
+    {
+        let dict = inline_static_ref!(DICT, UnionAll, "Base.Dict", target);
+        Some(dict.as_value())
+    }
+
+    fn construct_type_with_env_uncached<'target, Tgt>(
+        target: Tgt,
+        env: &TypeVarEnv,
+    ) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 3>(|target, mut frame| unsafe {
+            let key_ty = K::construct_type_with_env(&mut frame, env);
+            let value_ty = V::construct_type_with_env(&mut frame, env);
+            Self::base_type(&frame)
+                .unwrap_unchecked()
+                .apply_type_unchecked(&mut frame, [key_ty, value_ty])
+                .cast_unchecked::<DataType>()
+                .wrap_with_env(target, env)
+        })
+    }
+}
+
+/// A reference to a [`JuliaDict`] that has not been explicitly rooted.
+pub type JuliaDictRef<'scope, 'data, K, V> = Ref<'scope, 'data, JuliaDict<'scope, 'data, K, V>>;
+
+/// A [`JuliaDictRef`] with static lifetimes. This is a useful shorthand for signatures of
+/// `ccall`able functions that return a [`JuliaDict`].
+pub type JuliaDictRet<K, V> = Ref<'static, 'static, JuliaDict<'static, 'static, K, V>>;
+
+/// `JuliaDict` or `JuliaDictRef`, depending on the target type `Tgt`.
+pub type JuliaDictData<'target, 'data, K, V, Tgt> =
+    <Tgt as TargetType<'target>>::Data<'data, JuliaDict<'target, 'data, K, V>>;
+
+/// `JuliaResult<JuliaDict>` or `JuliaResultRef<JuliaDictRef>`, depending on the target type
+/// `Tgt`.
+pub type JuliaDictResult<'target, 'data, K, V, Tgt> =
+    TargetResult<'target, 'data, JuliaDict<'target, 'data, K, V>, Tgt>;
+
+unsafe impl<'scope, 'data, K: ConstructType, V: ConstructType> CCallArg
+    for JuliaDict<'scope, 'data, K, V>
+{
+    type CCallArgType = Value<'scope, 'data>;
+    type FunctionArgType = Self;
+}
+
+unsafe impl<K: ConstructType, V: ConstructType> CCallReturn
+    for JuliaDictRef<'static, 'static, K, V>
+{
+    type CCallReturnType = Value<'static, 'static>;
+    type FunctionReturnType = JuliaDict<'static, 'static, K, V>;
+    type ReturnAs = Self;
+
+    #[inline]
+    unsafe fn return_or_throw(self) -> Self::ReturnAs {
+        self
+    }
+}
@@ -288,8 +288,10 @@ macro_rules! impl_debug {
 pub mod array;
 pub mod background_task;
 pub mod ccall_ref;
+pub mod ccall_str;
 pub mod datatype;
 pub mod delegated_task;
+pub mod dict;
 pub mod expr;
 pub mod function;
 pub mod module;
@@ -6,12 +6,12 @@
 
 // todo: jl_new_module
 
-use std::{any::TypeId, marker::PhantomData, ptr::NonNull};
+use std::{any::TypeId, marker::PhantomData, ptr::NonNull, sync::atomic::Ordering};
 
 use jl_sys::{
     jl_base_module, jl_core_module, jl_get_global, jl_is_const, jl_is_imported, jl_main_module,
-    jl_module_t, jl_module_type, jl_set_const, jlrs_module_name, jlrs_module_parent,
-    jlrs_set_global,
+    jl_module_exports_p, jl_module_t, jl_module_type, jl_set_const, jlrs_module_name,
+    jlrs_module_parent, jlrs_set_global,
 };
 use rustc_hash::FxHashMap;
 
@@ -22,22 +22,26 @@ use super::{
     Managed, Ref,
 };
 use crate::{
-    call::Call,
+    call::{Call, ProvideKeywords},
     catch::{catch_exceptions, unwrap_exc},
-    convert::to_symbol::ToSymbol,
+    convert::{into_jlrs_result::IntoJlrsResult, to_symbol::ToSymbol},
     data::{
         layout::nothing::Nothing,
         managed::{
-            function::Function, private::ManagedPriv, symbol::Symbol, union_all::UnionAll,
-            value::Value,
+            array::TypedVector, function::Function, private::ManagedPriv, string::JuliaString,
+            symbol::Symbol, union_all::UnionAll, value::Value,
         },
         static_data::StaticRef,
         types::{construct_type::ConstructType, typecheck::Typecheck},
     },
-    error::{AccessError, JlrsResult, TypeError},
+    error::{AccessError, IntoJuliaError, JlrsResult, TypeError},
     gc_safe::{GcSafeOnceLock, GcSafeRwLock},
     impl_julia_typecheck, inline_static_ref,
-    memory::target::{Target, TargetException, TargetResult},
+    memory::{
+        scope::LocalScope,
+        target::{Target, TargetException, TargetResult},
+    },
+    named_tuple,
     prelude::DataType,
     private::Private,
 };
@@ -100,6 +104,9 @@ impl<'scope> Module<'scope> {
     }
 
     /// Returns the parent of this module.
+    ///
+    /// Root modules like `Main` and `Core` are their own parent, use [`Module::is_root`] to
+    /// check if a module is a root module.
     #[inline]
     pub fn parent(self) -> Module<'scope> {
         // Safety: the pointer points to valid data, the parent is never null
@@ -109,6 +116,12 @@ impl<'scope> Module<'scope> {
         }
     }
 
+    /// Returns `true` if this module is a root module, i.e. if it's its own parent.
+    #[inline]
+    pub fn is_root(self) -> bool {
+        self.parent().unwrap(Private) == self.unwrap(Private)
+    }
+
     /// Access the global at `path`. The result is cached for faster lookup in the future.
     ///
     /// Safety:
@@ -310,8 +323,14 @@ impl<'scope> Module<'scope> {
     /// make the old value unreachable. If an excection is thrown, it's caught, rooted and
     /// returned.
     ///
+    /// Julia throws an exception if the global can't be assigned, e.g. because it's a constant
+    /// or because the binding has been imported from another module. The name isn't required to
+    /// be a valid identifier, names like `+` can be used to assign operators. Julia issues the
+    /// write barrier for the module when the global is assigned.
+    ///
     /// Safety: Mutating Julia data is generally unsafe because it can't be guaranteed mutating
-    /// this value is allowed.
+    /// this value is allowed. Code that has already been compiled might depend on the old value,
+    /// e.g. code that has been precompiled.
     pub unsafe fn set_global<'target, N, Tgt>(
         self,
         target: Tgt,
@@ -358,6 +377,10 @@ impl<'scope> Module<'scope> {
     /// Set a constant in this module. If Julia throws an exception it's caught and rooted in the
     /// current frame, if the exception can't be rooted a `JlrsError::AllocError` is returned. If
     /// no exception is thrown an unrooted reference to the constant is returned.
+    ///
+    /// An `ErrorException` is returned if `name` is already a constant in this module, even in
+    /// versions of Julia that allow constants to be redefined. Julia issues the write barrier for
+    /// the module when the constant is set.
     pub fn set_const<'target, N, Tgt>(
         self,
         target: Tgt,
@@ -374,6 +397,16 @@ impl<'scope> Module<'scope> {
         unsafe {
             let symbol = name.to_symbol_priv(Private);
 
+            if self.is_const(symbol) {
+                let msg = format!(
+                    "invalid redefinition of constant {}.{}",
+                    self.name().as_str().unwrap_or("<Non-UTF8 symbol>"),
+                    symbol.as_str().unwrap_or("<Non-UTF8 symbol>")
+                );
+                let err = msg.into_julia_error(&target).ptr();
+                return target.exception_from_ptr(Err(err), Private);
+            }
+
             let callback = || {
                 jl_set_const(
                     self.unwrap(Private),
@@ -472,6 +505,142 @@ impl<'scope> Module<'scope> {
         }
     }
 
+    /// Returns `true` if this module exports `name`.
+    #[inline]
+    pub fn is_exported<N>(self, name: N) -> bool
+    where
+        N: ToSymbol,
+    {
+        // Safety: the pointer points to valid data, the C API function is called with
+        // valid arguments.
+        unsafe {
+            let symbol = name.to_symbol_priv(Private);
+            jl_module_exports_p(self.unwrap(Private), symbol.unwrap(Private)) != 0
+        }
+    }
+
+    /// Returns the names exported by this module.
+    ///
+    /// This is equivalent to calling [`Module::names`] without `all` and `imported` and keeping
+    /// the names for which [`Module::is_exported`] returns `true`. Only names defined in this
+    /// module are returned, names that are imported from another module and re-exported are not.
+    /// Names that have been declared `public` without being exported are not included either. If
+    /// Julia throws an exception it's caught and returned as an error. This requires two slots
+    /// on the GC stack.
+    pub fn exported_names<'target, Tgt>(self, target: &Tgt) -> JlrsResult<Vec<Symbol<'target>>>
+    where
+        Tgt: Target<'target>,
+    {
+        let mut names = self.names(target, false, false)?;
+        names.retain(|&sym| self.is_exported(sym));
+        Ok(names)
+    }
+
+    /// Returns the names of the bindings in this module, this is equivalent to
+    /// `names(module; all, imported)` in Julia.
+    ///
+    /// If `all` is `false`, only the names that are exported or declared `public` are returned,
+    /// otherwise all names defined in this module are returned. If `imported` is `true`, names
+    /// that are explicitly imported from other modules are included. Deprecated bindings are
+    /// skipped. If Julia throws an exception it's caught and returned as an error. This requires
+    /// two slots on the GC stack.
+    pub fn names<'target, Tgt>(
+        self,
+        target: &Tgt,
+        all: bool,
+        imported: bool,
+    ) -> JlrsResult<Vec<Symbol<'target>>>
+    where
+        Tgt: Target<'target>,
+    {
+        let names = inline_static_ref!(NAMES, Value, "Base.names", target);
+        let is_deprecated = inline_static_ref!(IS_DEPRECATED, Value, "Base.isdeprecated", target);
+
+        target.local_scope::<_, 2>(|mut frame| {
+            let all = if all {
+                Value::true_v(&frame)
+            } else {
+                Value::false_v(&frame)
+            };
+            let imported = if imported {
+                Value::true_v(&frame)
+            } else {
+                Value::false_v(&frame)
+            };
+
+            let kws = named_tuple!(&mut frame, "all" => all, "imported" => imported);
+
+            // Safety: Base.names is called with a module and two Bool keyword arguments,
+            // exceptions are caught.
+            let names = unsafe {
+                names
+                    .provide_keywords(kws)?
+                    .call1(&mut frame, self.as_value())
+            }
+            .into_jlrs_result()?
+            .cast::<TypedVector<Symbol>>()?;
+
+            // Safety: Base.isdeprecated is called with a module and a symbol, it returns a Bool.
+            unsafe {
+                let names = names
+                    .managed_data()
+                    .as_slice()
+                    .iter()
+                    .filter_map(|sym| sym.load(Ordering::Relaxed))
+                    .map(|sym| sym.as_managed())
+                    .filter(|&sym| {
+                        !is_deprecated
+                            .call_unchecked(&frame, [self.as_value(), sym.as_value()])
+                            .as_value()
+                            .unbox_unchecked::<bool>()
+                            .as_bool()
+                    })
+                    // Symbols are never freed, so they can outlive the frame.
+                    .map(|sym| Symbol::wrap_non_null(sym.unwrap_non_null(Private), Private))
+                    .collect();
+
+                Ok(names)
+            }
+        })
+    }
+
+    /// Generate the Rust bindings for the type `name` in this module with
+    /// `JlrsCore.Reflect.reflect` and return the generated code.
+    ///
+    /// This is equivalent to `string(JlrsCore.Reflect.reflect([module.name]))` in Julia. The
+    /// generated code contains the bindings for the type and all types it depends on. An error is
+    /// returned if the global doesn't exist or isn't a `DataType` or `UnionAll`. If Julia throws
+    /// an exception, e.g. because the layout of the type can't be reflected, it's returned as an
+    /// error. This requires four slots on the GC stack.
+    pub fn reflect_type<'target, N, Tgt>(self, target: &Tgt, name: N) -> JlrsResult<String>
+    where
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        let vect = inline_static_ref!(VECT, Value, "Base.vect", target);
+        let reflect = inline_static_ref!(REFLECT, Value, "JlrsCore.Reflect.reflect", target);
+        let string = inline_static_ref!(STRING, Value, "Base.string", target);
+
+        target.local_scope::<_, 4>(|mut frame| unsafe {
+            let symbol = name.to_symbol_priv(Private);
+            let ty = self.global(&mut frame, symbol)?;
+            if !ty.is::<DataType>() && !ty.is::<UnionAll>() {
+                Err(TypeError::NotA {
+                    value: symbol.as_str().unwrap_or("<Non-UTF8 symbol>").into(),
+                    field_type: "Type".into(),
+                })?
+            }
+
+            // Safety: JlrsCore.Reflect.reflect is called with a vector of types, Base.string
+            // converts the result to a String.
+            let types = vect.call1(&mut frame, ty).into_jlrs_result()?;
+            let layouts = reflect.call1(&mut frame, types).into_jlrs_result()?;
+            let code = string.call1(&mut frame, layouts).into_jlrs_result()?;
+
+            Ok(code.cast::<JuliaString>()?.as_str()?.to_owned())
+        })
+    }
+
     /// Returns `true` if `name` is a constant in this module.
     pub fn is_const<N>(self, name: N) -> bool
     where
@@ -511,6 +680,35 @@ impl<'scope> Module<'scope> {
                 module.to_symbol_priv(Private).as_value(),
             )
     }
+
+    /// Evaluate the Julia code `code` in this module and return the value of the last
+    /// expression.
+    ///
+    /// This is equivalent to `include_string(module, code)` in Julia. Unlike
+    /// [`Value::eval_string`], which evaluates a single expression in `Main`, `code` can contain
+    /// multiple top-level expressions that are evaluated in the global scope of this module. If
+    /// Julia throws an exception, e.g. because `code` can't be parsed, it's caught and returned.
+    /// This requires one slot on the GC stack.
+    ///
+    /// Safety: Evaluating arbitrary Julia code can have global side effects, nothing prevents you
+    /// from causing a segmentation fault with a command like `unsafe_load(Ptr{Float64}(C_NULL))`.
+    pub unsafe fn include_string<'target, C, Tgt>(
+        self,
+        target: Tgt,
+        code: C,
+    ) -> ValueResult<'target, 'static, Tgt>
+    where
+        C: AsRef<str>,
+        Tgt: Target<'target>,
+    {
+        let include_string =
+            inline_static_ref!(INCLUDE_STRING, Value, "Base.include_string", &target);
+
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let code = JuliaString::new(&mut frame, code);
+            include_string.call2(target, self.as_value(), code.as_value())
+        })
+    }
 }
 
 impl_julia_typecheck!(Module<'target>, jl_module_type, 'target);
@@ -12,11 +12,15 @@ use jl_sys::{jl_pchar_to_string, jl_string_ptr, jl_string_type, jlrs_string_len}
 
 use super::Ref;
 use crate::{
+    call::Call,
     convert::unbox::Unbox,
-    data::managed::{private::ManagedPriv, value::Value},
+    data::managed::{private::ManagedPriv, value::Value, Managed},
     error::{JlrsError, JlrsResult},
-    impl_julia_typecheck,
-    memory::target::{Target, TargetResult},
+    impl_julia_typecheck, inline_static_ref,
+    memory::{
+        scope::LocalScope,
+        target::{Target, TargetException, TargetResult},
+    },
     private::Private,
 };
 
@@ -76,7 +80,11 @@ impl<'scope> JuliaString<'scope> {
         }
     }
 
-    /// Returns the string as a slice of bytes, including all null characters..
+    /// Returns the string as a slice of bytes, including all null characters.
+    ///
+    /// Julia strings aren't guaranteed to be valid UTF-8. Unlike [`JuliaString::as_str`], this
+    /// method doesn't validate or copy the contents of the string, it returns the raw bytes
+    /// stored by Julia. The slice is valid for as long as the string is rooted.
     #[inline]
     pub fn as_bytes(self) -> &'scope [u8] {
         unsafe {
@@ -87,7 +95,10 @@ impl<'scope> JuliaString<'scope> {
     }
 
     /// Returns the string as a string slice, or an error if it the string contains
-    /// invalid characters
+    /// invalid characters.
+    ///
+    /// The string is terminated at the first null character. Use [`JuliaString::as_bytes`] to
+    /// access the raw contents of strings that aren't valid UTF-8 or contain null characters.
     #[inline]
     pub fn as_str(self) -> JlrsResult<&'scope str> {
         Ok(str::from_utf8(self.as_c_str().to_bytes()).map_err(JlrsError::other)?)
@@ -100,6 +111,99 @@ impl<'scope> JuliaString<'scope> {
     pub unsafe fn as_str_unchecked(self) -> &'scope str {
         str::from_utf8_unchecked(self.as_c_str().to_bytes())
     }
+
+    /// Returns the character that starts at the 1-based byte index `i`, this is equivalent to
+    /// `codepoint(self[i])` in Julia.
+    ///
+    /// If Julia throws an exception, e.g. because `i` is out of bounds or isn't the start of a
+    /// character, it's caught and returned. Surrogate codepoints, which are valid in Julia but
+    /// not in Rust, are replaced with `char::REPLACEMENT_CHARACTER`.
+    pub fn char_at<'target, Tgt>(
+        self,
+        target: Tgt,
+        i: usize,
+    ) -> TargetException<'target, 'static, char, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let getindex = inline_static_ref!(GETINDEX, Value, "Base.getindex", &target);
+        let codepoint = inline_static_ref!(CODEPOINT, Value, "Base.codepoint", &target);
+
+        // Safety: Base.getindex and Base.codepoint are called with a String, an Int and a Char.
+        let res = target.local_scope::<_, 2>(|mut frame| unsafe {
+            let i = Value::new(&mut frame, i as isize);
+            let ch = match getindex.call2(&mut frame, self.as_value(), i) {
+                Ok(ch) => ch,
+                Err(e) => return Err(e.unwrap_non_null(Private)),
+            };
+
+            match codepoint.call1(&frame, ch) {
+                Ok(cp) => {
+                    let cp = cp.as_value().unbox_unchecked::<u32>();
+                    Ok(char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER))
+                }
+                Err(e) => Err(e.ptr()),
+            }
+        });
+
+        unsafe { target.exception_from_ptr(res, Private) }
+    }
+
+    /// Returns the 1-based byte index of the character after the one that starts at `i`, this is
+    /// equivalent to `nextind(self, i)` in Julia.
+    ///
+    /// `i` can be 0 to get the index of the first character. If Julia throws an exception, e.g.
+    /// because `i` is out of bounds, it's caught and returned.
+    pub fn nextind<'target, Tgt>(
+        self,
+        target: Tgt,
+        i: usize,
+    ) -> TargetException<'target, 'static, usize, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let func = inline_static_ref!(NEXTIND, Value, "Base.nextind", &target);
+        // Safety: Base.nextind is called with a String and an Int.
+        unsafe { self.call_with_index(target, func, i) }
+    }
+
+    /// Returns the 1-based byte index of the character before the one that starts at `i`, this
+    /// is equivalent to `prevind(self, i)` in Julia.
+    ///
+    /// `i` can be `self.len() + 1` to get the index of the last character. If Julia throws an
+    /// exception, e.g. because `i` is out of bounds, it's caught and returned.
+    pub fn prevind<'target, Tgt>(
+        self,
+        target: Tgt,
+        i: usize,
+    ) -> TargetException<'target, 'static, usize, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let func = inline_static_ref!(PREVIND, Value, "Base.prevind", &target);
+        // Safety: Base.prevind is called with a String and an Int.
+        unsafe { self.call_with_index(target, func, i) }
+    }
+
+    unsafe fn call_with_index<'target, Tgt>(
+        self,
+        target: Tgt,
+        func: Value,
+        i: usize,
+    ) -> TargetException<'target, 'static, usize, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let res = target.local_scope::<_, 1>(|mut frame| {
+            let i = Value::new(&mut frame, i as isize);
+            match func.call2(&frame, self.as_value(), i) {
+                Ok(idx) => Ok(idx.as_value().unbox_unchecked::<isize>() as usize),
+                Err(e) => Err(e.ptr()),
+            }
+        });
+
+        target.exception_from_ptr(res, Private)
+    }
 }
 
 impl_construct_type_managed!(JuliaString, 1, jl_string_type);
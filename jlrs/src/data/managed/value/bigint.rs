@@ -0,0 +1,141 @@
+//! Convert between Julia's `BigInt` and `num_bigint::BigInt`.
+//!
+//! Julia's `BigInt` wraps a GMP integer, which is stored in sign-magnitude form. The magnitude is
+//! an array of limbs, least significant limb first. The absolute value of the `size` field is
+//! the number of limbs that are in use, its sign is the sign of the integer. Zero has a size of
+//! 0.
+
+use std::{ffi::c_int, slice};
+
+use num_bigint::{BigInt, BigUint, Sign};
+
+use super::{Value, ValueData};
+use crate::{
+    call::{Call, ProvideKeywords},
+    convert::into_jlrs_result::IntoJlrsResult,
+    data::managed::{datatype::DataType, Managed},
+    error::{JlrsError, JlrsResult, TypeError, CANNOT_DISPLAY_TYPE},
+    inline_static_ref,
+    memory::target::Target,
+    named_tuple,
+};
+
+// GMP limbs are pointer-sized on all platforms supported by Julia.
+type Limb = usize;
+
+// The layout of `Base.BigInt`, i.e. GMP's `__mpz_struct`.
+#[repr(C)]
+struct Mpz {
+    alloc: c_int,
+    size: c_int,
+    d: *mut Limb,
+}
+
+impl<'scope, 'data> Value<'scope, 'data> {
+    /// Convert a Julia `BigInt` to a `num_bigint::BigInt`.
+    ///
+    /// The limbs of the GMP integer are copied, the value doesn't have to remain rooted after
+    /// this method returns. An error is returned if `self` is not a `BigInt`.
+    ///
+    /// This method is only available if the `bigint` feature is enabled.
+    pub fn unbox_bigint(self) -> JlrsResult<BigInt> {
+        let unrooted = self.unrooted_target();
+        let ty = inline_static_ref!(BIGINT, DataType, "Base.BigInt", &unrooted);
+        if self.datatype() != ty {
+            Err(TypeError::TypeMismatch {
+                expected: ty.display_string_or(CANNOT_DISPLAY_TYPE),
+                found: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
+            })?
+        }
+
+        // Safety: `self` is a `BigInt`, the first `|size|` limbs are initialized.
+        unsafe {
+            let mpz = self.data_ptr().cast::<Mpz>().as_ref();
+            let n_limbs = mpz.size.unsigned_abs() as usize;
+            let limbs = if n_limbs == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(mpz.d, n_limbs)
+            };
+
+            let sign = if mpz.size < 0 {
+                Sign::Minus
+            } else {
+                Sign::Plus
+            };
+
+            Ok(BigInt::from_biguint(sign, limbs_to_biguint(limbs)))
+        }
+    }
+
+    /// Convert a `num_bigint::BigInt` to a Julia `BigInt`.
+    ///
+    /// A `BigInt` with enough limbs to store `value` is allocated by calling
+    /// `BigInt(; nbits)`, the limbs of `value` are copied to it afterwards. If an exception is
+    /// thrown it's caught and returned. This requires three slots on the GC stack.
+    ///
+    /// This method is only available if the `bigint` feature is enabled.
+    pub fn new_bigint<'target, Tgt>(
+        target: Tgt,
+        value: &BigInt,
+    ) -> JlrsResult<ValueData<'target, 'static, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        let (sign, limbs) = bigint_to_limbs(value);
+        let size = c_int::try_from(limbs.len()).map_err(JlrsError::other)?;
+        let size = if sign == Sign::Minus { -size } else { size };
+        let n_bits = (limbs.len() * Limb::BITS as usize) as isize;
+
+        target.with_local_scope::<_, _, 3>(|target, mut frame| unsafe {
+            let bigint = inline_static_ref!(BIGINT_FN, Value, "Base.BigInt", &frame);
+            let n_bits = Value::new(&mut frame, n_bits);
+            let kws = named_tuple!(&mut frame, "nbits" => n_bits);
+
+            // Safety: the constructor is safe to call. It allocates at least `nbits` bits, so
+            // the limbs can be copied to the new integer.
+            let res = bigint
+                .provide_keywords(kws)?
+                .call0(&mut frame)
+                .into_jlrs_result()?;
+
+            let mpz = res.data_ptr().cast::<Mpz>().as_mut();
+            std::ptr::copy_nonoverlapping(limbs.as_ptr(), mpz.d, limbs.len());
+            mpz.size = size;
+
+            Ok(res.root(target))
+        })
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+fn limbs_to_biguint(limbs: &[Limb]) -> BigUint {
+    let digits = limbs
+        .iter()
+        .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+        .collect();
+    BigUint::new(digits)
+}
+
+#[cfg(target_pointer_width = "32")]
+fn limbs_to_biguint(limbs: &[Limb]) -> BigUint {
+    BigUint::new(limbs.iter().map(|&limb| limb as u32).collect())
+}
+
+#[cfg(target_pointer_width = "64")]
+fn bigint_to_limbs(value: &BigInt) -> (Sign, Vec<Limb>) {
+    let (sign, digits) = value.to_u64_digits();
+    (
+        sign,
+        digits.into_iter().map(|digit| digit as Limb).collect(),
+    )
+}
+
+#[cfg(target_pointer_width = "32")]
+fn bigint_to_limbs(value: &BigInt) -> (Sign, Vec<Limb>) {
+    let (sign, digits) = value.to_u32_digits();
+    (
+        sign,
+        digits.into_iter().map(|digit| digit as Limb).collect(),
+    )
+}
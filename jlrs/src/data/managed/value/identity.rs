@@ -0,0 +1,77 @@
+//! Use Julia data as keys in Rust hash collections.
+//!
+//! A [`Value`] can be compared with `===` by calling [`Value::egal`], and hashed consistently
+//! with that comparison by calling [`Value::object_id`]. [`IdentityValue`] wraps a `Value` and
+//! implements [`Hash`] and [`Eq`] with these methods, so it can be used as a key in a `HashMap`
+//! or `HashSet`. This is the same notion of equality used by an `IdDict` in Julia: mutable data
+//! is compared by identity, immutable data is compared by contents. `==` and `isequal` are not
+//! used, so two different arrays with the same contents are distinct keys.
+//!
+//! ```
+//! use std::collections::HashSet;
+//!
+//! use jlrs::{data::managed::value::identity::IdentityValue, prelude::*};
+//!
+//! # fn main() {
+//! # let mut julia = Builder::new().start_local().unwrap();
+//! julia.local_scope::<_, 2>(|mut frame| {
+//!     let a = unsafe { Value::eval_string(&mut frame, "[1]") }.unwrap();
+//!     let b = unsafe { Value::eval_string(&mut frame, "[1]") }.unwrap();
+//!
+//!     let mut set = HashSet::new();
+//!     set.insert(IdentityValue::new(a));
+//!     set.insert(IdentityValue::new(a));
+//!     set.insert(IdentityValue::new(b));
+//!     assert_eq!(set.len(), 2);
+//! });
+//! # }
+//! ```
+
+use std::hash::{Hash, Hasher};
+
+use crate::prelude::Value;
+
+/// A `Value` that is hashed and compared by identity.
+///
+/// The wrapped value must be rooted for as long as the `IdentityValue` is used, this is the case
+/// if the value has been rooted in a frame that outlives the `'scope` lifetime.
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct IdentityValue<'scope, 'data>(Value<'scope, 'data>);
+
+impl<'scope, 'data> IdentityValue<'scope, 'data> {
+    /// Wrap `value`.
+    #[inline]
+    pub const fn new(value: Value<'scope, 'data>) -> Self {
+        IdentityValue(value)
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub const fn value(self) -> Value<'scope, 'data> {
+        self.0
+    }
+}
+
+impl<'scope, 'data> From<Value<'scope, 'data>> for IdentityValue<'scope, 'data> {
+    #[inline]
+    fn from(value: Value<'scope, 'data>) -> Self {
+        IdentityValue(value)
+    }
+}
+
+impl PartialEq for IdentityValue<'_, '_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.egal(other.0)
+    }
+}
+
+impl Eq for IdentityValue<'_, '_> {}
+
+impl Hash for IdentityValue<'_, '_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.object_id().hash(state)
+    }
+}
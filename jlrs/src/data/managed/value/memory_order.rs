@@ -0,0 +1,42 @@
+//! Memory orderings for atomic field operations.
+//!
+//! Julia's atomic field operations like `modifyfield!` take a memory ordering which is
+//! expressed as a symbol. [`MemoryOrder`] mirrors these orderings.
+
+use crate::{data::managed::symbol::Symbol, memory::target::Target};
+
+/// The memory ordering of an atomic field operation.
+///
+/// Each variant corresponds to one of the memory-order symbols used by Julia, e.g.
+/// `MemoryOrder::SequentiallyConsistent` corresponds to `:sequentially_consistent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryOrder {
+    NotAtomic,
+    Unordered,
+    Monotonic,
+    Acquire,
+    Release,
+    AcquireRelease,
+    SequentiallyConsistent,
+}
+
+impl MemoryOrder {
+    /// Returns the name of the symbol that Julia uses for this ordering.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            MemoryOrder::NotAtomic => "not_atomic",
+            MemoryOrder::Unordered => "unordered",
+            MemoryOrder::Monotonic => "monotonic",
+            MemoryOrder::Acquire => "acquire",
+            MemoryOrder::Release => "release",
+            MemoryOrder::AcquireRelease => "acquire_release",
+            MemoryOrder::SequentiallyConsistent => "sequentially_consistent",
+        }
+    }
+
+    /// Returns the symbol that Julia uses for this ordering.
+    #[inline]
+    pub fn as_symbol<'target, Tgt: Target<'target>>(self, target: &Tgt) -> Symbol<'target> {
+        Symbol::new(target, self.as_str())
+    }
+}
@@ -35,7 +35,13 @@
         jl_atomic_swap_bits
 */
 
+#[cfg(feature = "bigint")]
+mod bigint;
 pub mod field_accessor;
+pub mod identity;
+#[cfg(feature = "unstable")]
+pub mod memory_order;
+pub mod send;
 pub mod tracked;
 pub mod typed;
 
@@ -118,6 +124,7 @@ macro_rules! named_tuple {
 }
 
 use std::{
+    any::type_name,
     ffi::{c_void, CStr, CString},
     marker::PhantomData,
     mem::MaybeUninit,
@@ -135,25 +142,35 @@ use jl_sys::{
     jl_memory_exception, jl_new_struct_uninit, jl_nothing, jl_object_id, jl_pair_type,
     jl_readonlymemory_exception, jl_set_nth_field, jl_stackovf_exception, jl_static_show,
     jl_stderr_obj, jl_stderr_stream, jl_stdout_obj, jl_stdout_stream, jl_subtype, jl_true,
-    jl_typeof_str, jl_undefref_exception, jl_value_t, jlrs_call_unchecked, jlrs_egal,
-    jlrs_field_isptr,
+    jl_typeof_str, jl_undefref_exception, jl_value_t, jlrs_call_in_world, jlrs_call_unchecked,
+    jlrs_egal, jlrs_field_isptr,
 };
 use jlrs_macros::julia_version;
+use rustc_hash::FxHashSet;
 
-use self::{field_accessor::FieldAccessor, typed::TypedValue};
+#[cfg(feature = "unstable")]
+use self::memory_order::MemoryOrder;
+use self::{field_accessor::FieldAccessor, send::SendValue, typed::TypedValue};
 use super::{type_var::TypeVar, Ref};
 use crate::{
     args::Values,
     call::{Call, ProvideKeywords, WithKeywords},
     catch::{catch_exceptions, unwrap_exc},
-    convert::{into_julia::IntoJulia, to_symbol::ToSymbol, unbox::Unbox},
+    convert::{
+        into_jlrs_result::IntoJlrsResult,
+        into_julia::IntoJulia,
+        to_symbol::ToSymbol,
+        unbox::{BoundedInteger, Unbox},
+    },
     data::{
         layout::{
             is_bits::IsBits,
+            tuple::Tuple,
             typed_layout::HasLayout,
             valid_layout::{ValidField, ValidLayout},
         },
         managed::{
+            array::{Array, ArrayData, TypedArrayData},
             datatype::DataType,
             module::Module,
             private::ManagedPriv,
@@ -169,11 +186,17 @@ use crate::{
             typecheck::{NamedTuple, Typecheck},
         },
     },
-    error::{AccessError, IOError, JlrsError, JlrsResult, TypeError, CANNOT_DISPLAY_TYPE},
+    error::{
+        AccessError, IOError, InstantiationError, JlrsError, JlrsResult, RuntimeError, TypeError,
+        CANNOT_DISPLAY_TYPE, CANNOT_DISPLAY_VALUE,
+    },
+    info::Info,
+    inline_static_ref,
     memory::{
         context::ledger::Ledger,
         get_tls,
-        target::{unrooted::Unrooted, Target, TargetException, TargetResult},
+        scope::LocalScope,
+        target::{frame::GcFrame, unrooted::Unrooted, Target, TargetException, TargetResult},
     },
     prelude::NTuple,
     private::Private,
@@ -394,6 +417,93 @@ impl Value<'_, '_> {
         }
     }
 
+    /// Create a new named tuple with the keys `names` and the values `values`.
+    ///
+    /// Unlike the `named_tuple` macro, the keys don't have to be known at compile time. If the
+    /// number of names and values differ, `InstantiationError::NamedTupleSizeMismatch` is
+    /// returned. If a name occurs more than once, `InstantiationError::DuplicateNamedTupleKey` is
+    /// returned. This requires one slot on the GC stack.
+    pub fn try_new_named_tuple<'target, 'value, 'data, Tgt>(
+        target: Tgt,
+        names: &[Symbol<'value>],
+        values: &[Value<'value, 'data>],
+    ) -> JlrsResult<ValueData<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        let n_names = names.len();
+        let n_values = values.len();
+        if n_names != n_values {
+            Err(InstantiationError::NamedTupleSizeMismatch { n_names, n_values })?;
+        }
+
+        let mut unique = FxHashSet::default();
+        for name in names.iter().copied() {
+            if !unique.insert(name) {
+                Err(InstantiationError::DuplicateNamedTupleKey {
+                    name: name.as_str().unwrap_or("<Non-UTF8 symbol>").into(),
+                })?;
+            }
+        }
+
+        unsafe {
+            target.with_local_scope::<_, _, 1>(|target, mut frame| {
+                // Safety: this method can only be called from a thread known to Julia. The
+                // unchecked methods are used because it can be guaranteed they won't throw
+                // an exception for the given arguments.
+                let symbol_type = DataType::symbol_type(&frame).as_value();
+                let name_types = vec![symbol_type; n_names];
+                let field_names: Vec<_> = names.iter().map(|sym| sym.as_value()).collect();
+
+                let names = DataType::anytuple_type(&frame)
+                    .as_value()
+                    .apply_type_unchecked(&frame, &name_types)
+                    .as_value()
+                    .cast_unchecked::<DataType>()
+                    .instantiate_unchecked(&mut frame, &field_names);
+
+                let field_types: Vec<_> =
+                    values.iter().map(|val| val.datatype().as_value()).collect();
+                let field_types = DataType::anytuple_type(&frame)
+                    .as_value()
+                    .apply_type_unchecked(&frame, &field_types)
+                    .as_value();
+
+                let ty = UnionAll::namedtuple_type(&frame)
+                    .as_value()
+                    .apply_type_unchecked(&frame, [names, field_types])
+                    .as_value()
+                    .cast_unchecked::<DataType>();
+
+                Ok(ty.instantiate_unchecked(target, values))
+            })
+        }
+    }
+
+    /// Create a new named tuple from an iterator of `(name, value)` pairs.
+    ///
+    /// The names can be any type that implements [`ToSymbol`], e.g. `&str`. An empty iterator
+    /// creates an empty named tuple. If a name occurs more than once,
+    /// `InstantiationError::DuplicateNamedTupleKey` is returned. This requires one slot on the GC
+    /// stack.
+    pub fn named_tuple_from_iter<'target, 'value, 'data, Tgt, N, I>(
+        target: Tgt,
+        pairs: I,
+    ) -> JlrsResult<ValueData<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+        N: ToSymbol,
+        I: IntoIterator<Item = (N, Value<'value, 'data>)>,
+    {
+        let (names, values): (Vec<_>, Vec<_>) = pairs
+            .into_iter()
+            // Safety: symbols are globally rooted.
+            .map(|(name, value)| (unsafe { name.to_symbol_priv(Private) }, value))
+            .unzip();
+
+        Self::try_new_named_tuple(target, &names, &values)
+    }
+
     /// Apply the given types to `self`.
     ///
     /// If `self` is the [`DataType`] `anytuple_type`, calling this method will return a new
@@ -477,6 +587,141 @@ impl Value<'_, '_> {
             jl_static_show(stream, self.unwrap(Private))
         }
     }
+
+    /// Returns the string representation of this value, this is equivalent to calling
+    /// `Base.repr(self)` in Julia.
+    ///
+    /// Unlike [`Value::show`], this respects custom `show` methods. If `repr` throws an
+    /// exception, it's caught and returned as an error. This requires one slot on the GC stack.
+    ///
+    /// Safety: `Base.show` can be overloaded to execute arbitrary Julia code.
+    pub unsafe fn show_string<'target, Tgt>(self, target: &Tgt) -> JlrsResult<String>
+    where
+        Tgt: Target<'target>,
+    {
+        let repr = inline_static_ref!(REPR, Value, "Base.repr", target);
+
+        target.local_scope::<_, 1>(|mut frame| {
+            let s = repr
+                .call1(&mut frame, self)
+                .into_jlrs_result()?
+                .cast::<JuliaString>()?
+                .as_str()?
+                .to_owned();
+
+            Ok(s)
+        })
+    }
+
+    /// Writes the string representation of this value to `writer`, this is equivalent to
+    /// calling `Base.repr(self)` in Julia and writing the result.
+    ///
+    /// The rendered bytes are written directly from the Julia string without converting them to
+    /// a `String` first. If `repr` throws an exception or writing fails, an error is returned.
+    /// This requires one slot on the GC stack.
+    ///
+    /// Safety: `Base.show` can be overloaded to execute arbitrary Julia code.
+    pub unsafe fn show_to_writer<'target, Tgt, W>(
+        self,
+        target: &Tgt,
+        writer: &mut W,
+    ) -> JlrsResult<()>
+    where
+        Tgt: Target<'target>,
+        W: std::io::Write + ?Sized,
+    {
+        let repr = inline_static_ref!(REPR, Value, "Base.repr", target);
+
+        target.local_scope::<_, 1>(|mut frame| {
+            let s = repr
+                .call1(&mut frame, self)
+                .into_jlrs_result()?
+                .cast::<JuliaString>()?;
+
+            writer.write_all(s.as_bytes()).map_err(JlrsError::other)?;
+            Ok(())
+        })
+    }
+
+    /// Returns the compact string representation of this value, this is equivalent to calling
+    /// `Base.repr(self; context = :compact => true)` in Julia.
+    ///
+    /// If `repr` throws an exception, it's caught and returned as an error. This requires three
+    /// slots on the GC stack.
+    ///
+    /// Safety: `Base.show` can be overloaded to execute arbitrary Julia code.
+    pub unsafe fn show_compact<'target, Tgt>(self, target: &Tgt) -> JlrsResult<String>
+    where
+        Tgt: Target<'target>,
+    {
+        let repr = inline_static_ref!(REPR, Value, "Base.repr", target);
+        let pair = inline_static_ref!(PAIR, Value, "Base.Pair", target);
+
+        target.local_scope::<_, 3>(|mut frame| {
+            let compact = Symbol::new(&frame, "compact").as_value();
+            let enabled = Value::true_v(&frame);
+            let context = pair
+                .call2(&mut frame, compact, enabled)
+                .into_jlrs_result()?;
+            let kws = crate::named_tuple!(&mut frame, "context" => context);
+
+            let s = repr
+                .provide_keywords(kws)?
+                .call1(&mut frame, self)
+                .into_jlrs_result()?
+                .cast::<JuliaString>()?
+                .as_str()?
+                .to_owned();
+
+            Ok(s)
+        })
+    }
+
+    /// Renders this value as the MIME type `mime`, e.g. `"text/html"`, this is equivalent to
+    /// calling `Base.repr(MIME(mime), self)` in Julia.
+    ///
+    /// If `Base.showable(MIME(mime), self)` returns `false`, `None` is returned. Only textual
+    /// MIME types are supported, an error is returned if the rendered value isn't a string. If an
+    /// exception is thrown, it's caught and returned as an error. This requires four slots on the
+    /// GC stack.
+    ///
+    /// Safety: `Base.show` and `Base.showable` can be overloaded to execute arbitrary Julia code.
+    pub unsafe fn show_mime<'target, Tgt>(
+        self,
+        target: &Tgt,
+        mime: &str,
+    ) -> JlrsResult<Option<String>>
+    where
+        Tgt: Target<'target>,
+    {
+        let repr = inline_static_ref!(REPR, Value, "Base.repr", target);
+        let showable = inline_static_ref!(SHOWABLE, Value, "Base.showable", target);
+        let mime_type = inline_static_ref!(MIME_TYPE, Value, "Base.Multimedia.MIME", target);
+
+        target.local_scope::<_, 4>(|mut frame| {
+            let mime = JuliaString::new(&mut frame, mime).as_value();
+            let mime = mime_type.call1(&mut frame, mime).into_jlrs_result()?;
+
+            let is_showable = showable
+                .call2(&mut frame, mime, self)
+                .into_jlrs_result()?
+                .unbox::<bool>()?
+                .as_bool();
+
+            if !is_showable {
+                return Ok(None);
+            }
+
+            let s = repr
+                .call2(&mut frame, mime, self)
+                .into_jlrs_result()?
+                .cast::<JuliaString>()?
+                .as_str()?
+                .to_owned();
+
+            Ok(Some(s))
+        })
+    }
 }
 
 /// # Type information
@@ -566,14 +811,80 @@ impl Value<'_, '_> {
         Value::is_kind(self.datatype().as_value())
     }
 
-    /// Returns true if `self` is of type `ty`.
+    /// Returns true if `self` is a `DataType`.
+    #[inline]
+    pub fn is_datatype(self) -> bool {
+        // Safety: this method can only be called from a thread known to Julia, its lifetime is
+        // never used
+        let global = unsafe { Unrooted::new() };
+        self.datatype() == DataType::datatype_type(&global)
+    }
+
+    /// Returns true if `self` is a `UnionAll`.
+    #[inline]
+    pub fn is_unionall(self) -> bool {
+        // Safety: this method can only be called from a thread known to Julia, its lifetime is
+        // never used
+        let global = unsafe { Unrooted::new() };
+        self.datatype() == DataType::unionall_type(&global)
+    }
+
+    /// Returns true if `self` is a `Union`.
+    #[inline]
+    pub fn is_union(self) -> bool {
+        // Safety: this method can only be called from a thread known to Julia, its lifetime is
+        // never used
+        let global = unsafe { Unrooted::new() };
+        self.datatype() == DataType::uniontype_type(&global)
+    }
+
+    /// Returns true if `self` is a `TypeVar`.
+    #[inline]
+    pub fn is_typevar(self) -> bool {
+        // Safety: this method can only be called from a thread known to Julia, its lifetime is
+        // never used
+        let global = unsafe { Unrooted::new() };
+        self.datatype() == DataType::tvar_type(&global)
+    }
+
+    /// Returns true if `self` is of type `ty`, this is equivalent to `isa(self, ty)` in Julia.
+    ///
+    /// Unlike [`Value::is`], `ty` can be any type that is only known at runtime, including
+    /// abstract types, unions and `UnionAll`s. If `ty` is not a type `false` is returned.
     #[inline]
     pub fn isa(self, ty: Value) -> bool {
+        if !ty.is_type() {
+            return false;
+        }
+
         // Safety: the pointers point to valid data, the C API function
         // is called with valid arguments.
         unsafe { jl_isa(self.unwrap(Private), ty.unwrap(Private)) != 0 }
     }
 
+    /// Returns `self` if it's an instance of `ty`, this is equivalent to `self::ty` in Julia.
+    ///
+    /// Unlike [`Value::isa`], this returns an error if the assertion fails:
+    /// `TypeError::NotA` if `ty` is not a type, `TypeError::TypeMismatch` if `self` is not an
+    /// instance of `ty`.
+    pub fn typeassert(self, ty: Value) -> JlrsResult<Self> {
+        if !ty.is_type() {
+            Err(TypeError::NotA {
+                value: ty.display_string_or(CANNOT_DISPLAY_VALUE),
+                field_type: "Type".into(),
+            })?
+        }
+
+        if !self.isa(ty) {
+            Err(TypeError::TypeMismatch {
+                expected: ty.display_string_or(CANNOT_DISPLAY_TYPE),
+                found: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
+            })?
+        }
+
+        Ok(self)
+    }
+
     /// Returns `true` if `self` depends on the type parameter `tvar`.
     pub fn has_typevar(self, tvar: TypeVar) -> bool {
         unsafe { jl_has_typevar(self.unwrap(Private), tvar.unwrap(Private)) != 0 }
@@ -761,6 +1072,48 @@ impl<'scope, 'data> Value<'scope, 'data> {
     pub unsafe fn assume_owned(self) -> Value<'scope, 'static> {
         Value::wrap_non_null(self.unwrap_non_null(Private), Private)
     }
+
+    /// Create a deep copy of this value by calling `Base.deepcopy(self)`.
+    ///
+    /// The copy is a fresh allocation that doesn't share any mutable data with `self`, including
+    /// data borrowed from Rust, so its second lifetime is `'static`. It can be used to store a
+    /// value past the lifetime of borrowed data, or to send it to another thread with
+    /// [`Value::prepare_send`]. If `deepcopy` throws an exception, e.g. because the value
+    /// contains a task or an IO object, it's caught and returned as an error. This requires one
+    /// slot on the GC stack.
+    ///
+    /// Safety: `Base.deepcopy_internal` can be overloaded to execute arbitrary Julia code. Such a
+    /// method must not return data that is shared with `self` if `self` borrows data from Rust.
+    pub unsafe fn deep_copy<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ValueData<'target, 'static, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let deepcopy = inline_static_ref!(DEEPCOPY, Value, "Base.deepcopy", &frame);
+
+            // Safety: deepcopy allocates new data for everything that isn't immutable.
+            let copy = deepcopy.call1(&mut frame, self).into_jlrs_result()?;
+            let copy = copy.assume_owned();
+
+            Ok(copy.root(target))
+        })
+    }
+}
+
+impl Value<'_, 'static> {
+    /// Prepare this value to be sent to another thread.
+    ///
+    /// The value remains rooted until [`SendValue::receive`] is called, independently of the
+    /// scope this value belongs to. See the [`send`] module for more information.
+    ///
+    /// [`send`]: crate::data::managed::value::send
+    #[inline]
+    pub fn prepare_send(self) -> SendValue {
+        SendValue::new(self)
+    }
 }
 
 /// # Conversions
@@ -800,7 +1153,8 @@ impl<'scope, 'data> Value<'scope, 'data> {
     }
 
     /// Unbox the contents of the value as the output type associated with `T`. Returns an error
-    /// if the layout of `T::Output` is incompatible with the layout of the type in Julia.
+    /// if the layout of `T::Output` is incompatible with the layout of the type in Julia, or if
+    /// the contents are invalid, e.g. if `T` is an enum and the value isn't one of its variants.
     #[inline]
     pub fn unbox<T: Unbox + Typecheck>(self) -> JlrsResult<T::Output> {
         if !self.is::<T>() {
@@ -810,7 +1164,7 @@ impl<'scope, 'data> Value<'scope, 'data> {
         }
 
         // Safety: self.is::<T>() returning true guarantees this is safe
-        unsafe { Ok(T::unbox(self)) }
+        unsafe { T::try_unbox(self) }
     }
 
     /// Unbox the contents of the value as the output type associated with `T` without checking
@@ -822,7 +1176,148 @@ impl<'scope, 'data> Value<'scope, 'data> {
         T::unbox(self)
     }
 
+    /// Unbox a Julia integer as the Rust integer type `T`. Returns an error if the value is not
+    /// an instance of one of the primitive integer types, or if it's out of range for `T`.
+    pub fn unbox_checked<T: BoundedInteger>(self) -> JlrsResult<T> {
+        // Safety: the layout of the unboxed type is checked before unboxing.
+        let n = unsafe {
+            if self.is::<i8>() {
+                self.unbox_unchecked::<i8>() as i128
+            } else if self.is::<i16>() {
+                self.unbox_unchecked::<i16>() as i128
+            } else if self.is::<i32>() {
+                self.unbox_unchecked::<i32>() as i128
+            } else if self.is::<i64>() {
+                self.unbox_unchecked::<i64>() as i128
+            } else if self.is::<u8>() {
+                self.unbox_unchecked::<u8>() as i128
+            } else if self.is::<u16>() {
+                self.unbox_unchecked::<u16>() as i128
+            } else if self.is::<u32>() {
+                self.unbox_unchecked::<u32>() as i128
+            } else if self.is::<u64>() {
+                self.unbox_unchecked::<u64>() as i128
+            } else {
+                Err(AccessError::InvalidLayout {
+                    value_type: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
+                })?
+            }
+        };
+
+        match T::try_from(n) {
+            Ok(n) => Ok(n),
+            Err(_) => Err(TypeError::IntegerOverflow {
+                value: n.to_string(),
+                target_type: type_name::<T>().into(),
+                min: T::MIN.to_string(),
+                max: T::MAX.to_string(),
+            })?,
+        }
+    }
+
+    /// Convert this value to `ty` by calling `Base.convert(ty, self)`.
+    ///
+    /// If Julia throws an exception, e.g. a `MethodError` because no conversion exists or an
+    /// `InexactError` because the value can't be represented exactly, it's caught and returned
+    /// as an error. This requires one slot on the GC stack.
+    ///
+    /// Safety: a `Base.convert` method can execute arbitrary Julia code.
+    pub unsafe fn convert_to<'target, Tgt>(
+        self,
+        target: Tgt,
+        ty: DataType,
+    ) -> JlrsResult<ValueData<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let convert = inline_static_ref!(CONVERT, Value, "Base.convert", &frame);
+
+            let converted = convert
+                .call2(&mut frame, ty.as_value(), self)
+                .into_jlrs_result()?;
+
+            Ok(converted.root(target))
+        })
+    }
+
+    /// Convert this value to the type constructed from `T` by calling `Base.convert` and unbox
+    /// the result.
+    ///
+    /// If Julia throws an exception it's caught and returned as an error, see
+    /// [`Value::convert_to`]. This requires two slots on the GC stack.
+    ///
+    /// Safety: a `Base.convert` method can execute arbitrary Julia code.
+    pub unsafe fn convert_to_typed<'target, T, Tgt>(self, target: &Tgt) -> JlrsResult<T::Output>
+    where
+        T: ConstructType + Unbox + Typecheck,
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 2>(|_, mut frame| {
+            let convert = inline_static_ref!(CONVERT, Value, "Base.convert", &frame);
+            let ty = T::construct_type(&mut frame);
+
+            let converted = convert.call2(&mut frame, ty, self).into_jlrs_result()?;
+
+            converted.unbox::<T>()
+        })
+    }
+
+    /// Collect the elements of this value into a new array by calling `Base.collect(self)`.
+    ///
+    /// This can be used to materialize any iterable, e.g. a generator or a lazy view, into a
+    /// dense `Array`. If Julia throws an exception it's caught and returned as an error,
+    /// `AccessError::InvalidLayout` is returned if the result is not an `Array`. This requires
+    /// one slot on the GC stack.
+    ///
+    /// Safety: `Base.collect` calls `Base.iterate`, which can be overloaded to execute arbitrary
+    /// Julia code.
+    pub unsafe fn collect<'target, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<ArrayData<'target, 'data, Tgt>>
+    where
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let collect = inline_static_ref!(COLLECT, Value, "Base.collect", &frame);
+
+            let collected = collect
+                .call1(&mut frame, self)
+                .into_jlrs_result()?
+                .cast::<Array>()?;
+
+            Ok(collected.root(target))
+        })
+    }
+
+    /// Collect the elements of this value into a new array by calling `Base.collect(self)` and
+    /// check if its element type is the type constructed from `T`.
+    ///
+    /// In addition to the errors returned by [`Value::collect`], `TypeError::IncompatibleType` is
+    /// returned if the element type of the collected array is not the constructed type. This
+    /// requires one slot on the GC stack.
+    ///
+    /// Safety: `Base.collect` calls `Base.iterate`, which can be overloaded to execute arbitrary
+    /// Julia code.
+    pub unsafe fn collect_typed<'target, T, Tgt>(
+        self,
+        target: Tgt,
+    ) -> JlrsResult<TypedArrayData<'target, 'data, Tgt, T>>
+    where
+        T: ConstructType,
+        Tgt: Target<'target>,
+    {
+        target.with_local_scope::<_, _, 1>(|target, mut frame| {
+            let collected = self.collect(&mut frame)?.set_type::<T>()?;
+            Ok(collected.root(target))
+        })
+    }
+
     /// Convert this value to a typed value if this value is an instance of the constructed type.
+    ///
+    /// If it isn't, `TypeError::TypeMismatch` is returned which contains both the constructed
+    /// type and the type of `self`.
     pub fn as_typed<'target, T: ConstructType, Tgt: Target<'target>>(
         self,
         target: &Tgt,
@@ -832,9 +1327,9 @@ impl<'scope, 'data> Value<'scope, 'data> {
             if self.isa(ty) {
                 unsafe { Ok(TypedValue::<T>::from_value_unchecked(self)) }
             } else {
-                Err(TypeError::NotA {
-                    value: self.display_string_or("<Cannot display value>"),
-                    field_type: ty.display_string_or("<Cannot display type>"),
+                Err(TypeError::TypeMismatch {
+                    expected: ty.display_string_or(CANNOT_DISPLAY_TYPE),
+                    found: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
                 })?
             }
         })
@@ -935,6 +1430,29 @@ impl<'scope, 'data> Value<'scope, 'data> {
         }
     }
 
+    /// Roots the elements of this tuple in `frame` and returns them.
+    ///
+    /// Elements that are stored inline are boxed, elements stored as pointers are rooted
+    /// directly. If this value is not a tuple `TypeError::NotATuple` is returned.
+    pub fn tuple_elements<'target>(
+        self,
+        frame: &mut GcFrame<'target>,
+    ) -> JlrsResult<Vec<Value<'target, 'data>>> {
+        if !self.is::<Tuple>() {
+            Err(TypeError::NotATuple {
+                ty: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
+            })?
+        }
+
+        let n_fields = self.n_fields();
+        let mut elements = Vec::with_capacity(n_fields);
+        for idx in 0..n_fields {
+            elements.push(self.get_nth_field(&mut *frame, idx)?);
+        }
+
+        Ok(elements)
+    }
+
     /// Returns the field at index `idx` if it's a pointer field.
     ///
     /// If the field doesn't exist or if the field can't be referenced because its data is stored
@@ -1017,6 +1535,26 @@ impl<'scope, 'data> Value<'scope, 'data> {
         }
     }
 
+    /// Unboxes the field with the name `field_name` as `T`.
+    ///
+    /// If there's no field with that name, `AccessError::NoSuchField` is returned. If the field
+    /// is undefined, `AccessError::UndefRef` is returned, if its contents are incompatible with
+    /// `T`, `AccessError::InvalidLayout` is returned. Fields that are stored inline, including
+    /// bits-union fields, are boxed before they're unboxed. This requires one slot on the GC
+    /// stack.
+    pub fn get_field_by_name<'target, T, N, Tgt>(
+        self,
+        target: &Tgt,
+        field_name: N,
+    ) -> JlrsResult<T::Output>
+    where
+        T: Unbox + Typecheck,
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        target.local_scope::<_, 1>(|mut frame| self.get_field(&mut frame, field_name)?.unbox::<T>())
+    }
+
     /// Returns the field with the name `field_name` if it's a pointer field.
     ///
     /// If the field doesn't exist or if the field can't be referenced because its data is stored
@@ -1219,6 +1757,261 @@ impl<'scope, 'data> Value<'scope, 'data> {
             value.unwrap(Private),
         ))
     }
+
+    /// Read the field with the name `field_name` with the memory ordering `order`. This is
+    /// equivalent to calling `getfield(self, field_name, order)` in Julia.
+    ///
+    /// The ordering must be compatible with the declaration of the field: fields declared
+    /// `@atomic` can't be read with `MemoryOrder::NotAtomic`, other fields can only be read with
+    /// `MemoryOrder::NotAtomic`. If Julia throws an exception, e.g. because the ordering is
+    /// invalid, it's caught and returned. If there's no field with the given name an error is
+    /// returned.
+    ///
+    /// This method is only available if the `unstable` feature is enabled.
+    #[cfg(feature = "unstable")]
+    pub fn get_field_atomic<'target, N, Tgt>(
+        self,
+        target: Tgt,
+        field_name: N,
+        order: MemoryOrder,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        if self.is::<Module>() {
+            Err(AccessError::ModuleField)?
+        }
+
+        // Safety: the pointers point to valid data. Base.getfield is a builtin function that
+        // only reads the field, if it throws an exception it's caught.
+        unsafe {
+            let symbol = field_name.to_symbol_priv(Private);
+            let idx = jl_field_index(self.datatype().unwrap(Private), symbol.unwrap(Private), 0);
+
+            if idx < 0 {
+                Err(AccessError::NoSuchField {
+                    type_name: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
+                    field_name: symbol.as_str().unwrap_or("<Non-UTF8 symbol>").into(),
+                })?
+            }
+
+            let func = inline_static_ref!(GETFIELD, Value, "Base.getfield", &target);
+            let order = order.as_symbol(&target).as_value();
+            Ok(func.call(target, [self, symbol.as_value(), order]))
+        }
+    }
+
+    /// Atomically apply the binary operation `op` to the field with the name `field_name` and
+    /// `value`, and store the result in that field. This is equivalent to calling
+    /// `modifyfield!(self, field_name, op, value, order)` in Julia.
+    ///
+    /// If successful, a `Pair` of the old and the new value of the field is returned. If Julia
+    /// throws an exception, e.g. because the field is not declared `@atomic`, it's caught and
+    /// returned. If there's no field with the given name an error is returned.
+    ///
+    /// This method is only available if the `unstable` feature is enabled.
+    ///
+    /// Safety: `op` can be an arbitrary Julia function. Mutating things that should absolutely
+    /// not be mutated, like the fields of a `DataType`, is not prevented.
+    #[cfg(feature = "unstable")]
+    pub unsafe fn modify_field<'target, N, Tgt>(
+        self,
+        target: Tgt,
+        field_name: N,
+        op: Value<'_, 'data>,
+        value: Value<'_, 'data>,
+        order: MemoryOrder,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        if self.is::<Module>() {
+            Err(AccessError::ModuleField)?
+        }
+
+        let symbol = field_name.to_symbol_priv(Private);
+        let idx = jl_field_index(self.datatype().unwrap(Private), symbol.unwrap(Private), 0);
+
+        if idx < 0 {
+            Err(AccessError::NoSuchField {
+                type_name: self.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
+                field_name: symbol.as_str().unwrap_or("<Non-UTF8 symbol>").into(),
+            })?
+        }
+
+        let func = inline_static_ref!(MODIFYFIELD, Value, "Base.modifyfield!", &target);
+        let order = order.as_symbol(&target).as_value();
+        Ok(func.call(target, [self, symbol.as_value(), op, value, order]))
+    }
+
+    /// Returns the property `name` of this value. This is equivalent to `self.name` in Julia,
+    /// i.e. `Base.getproperty(self, name)` is called.
+    ///
+    /// Unlike [`Value::get_field`], this respects overloaded `getproperty` methods which can
+    /// provide computed properties. If Julia throws an exception it's caught and returned.
+    ///
+    /// Safety: `getproperty` can be overloaded to execute arbitrary Julia code.
+    pub unsafe fn get_property<'target, N, Tgt>(
+        self,
+        target: Tgt,
+        name: N,
+    ) -> ValueResult<'target, 'data, Tgt>
+    where
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        let symbol = name.to_symbol_priv(Private);
+        let func = inline_static_ref!(GETPROPERTY, Value, "Base.getproperty", &target);
+        func.call2(target, self, symbol.as_value())
+    }
+
+    /// Set the property `name` of this value to `value`. This is equivalent to
+    /// `self.name = value` in Julia, i.e. `Base.setproperty!(self, name, value)` is called.
+    ///
+    /// Unlike [`Value::set_field`], this respects overloaded `setproperty!` methods. The default
+    /// method converts `value` to the type of the field. If Julia throws an exception it's caught
+    /// and returned.
+    ///
+    /// Safety: `setproperty!` can be overloaded to execute arbitrary Julia code. Mutating things
+    /// that should absolutely not be mutated, like the fields of a `DataType`, is not prevented.
+    pub unsafe fn set_property<'target, N, Tgt>(
+        self,
+        target: Tgt,
+        name: N,
+        value: Value<'_, 'data>,
+    ) -> TargetException<'target, 'data, (), Tgt>
+    where
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        let symbol = name.to_symbol_priv(Private);
+        let func = inline_static_ref!(SETPROPERTY, Value, "Base.setproperty!", &target);
+        let res = match func.call3(&target, self, symbol.as_value(), value) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.ptr()),
+        };
+
+        target.exception_from_ptr(res, Private)
+    }
+
+    /// Create a copy of this immutable value with the field `name` set to `value`.
+    ///
+    /// This is the functional update of immutable data: all fields are read, the field `name` is
+    /// replaced with `value`, and the constructor of the value's type is called with the new
+    /// fields. This means inner constructors are respected, if the constructor rejects the new
+    /// combination of fields and throws an exception it's caught and returned. If there's no
+    /// field with the given name, if the value is mutable, or if one of the fields is undefined
+    /// an error is returned. This requires as many slots on the GC stack as the value has fields.
+    ///
+    /// Safety: the constructor can execute arbitrary Julia code.
+    pub unsafe fn set_property_copy<'target, N, Tgt>(
+        self,
+        target: Tgt,
+        name: N,
+        value: Value<'_, 'data>,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        N: ToSymbol,
+        Tgt: Target<'target>,
+    {
+        let dt = self.datatype();
+        if dt.mutable() {
+            Err(TypeError::Mutable {
+                value_type: dt.display_string_or(CANNOT_DISPLAY_TYPE),
+            })?
+        }
+
+        let symbol = name.to_symbol_priv(Private);
+        let idx = jl_field_index(dt.unwrap(Private), symbol.unwrap(Private), 0);
+
+        if idx < 0 {
+            Err(AccessError::NoSuchField {
+                type_name: dt.display_string_or(CANNOT_DISPLAY_TYPE),
+                field_name: symbol.as_str().unwrap_or("<Non-UTF8 symbol>").into(),
+            })?
+        }
+
+        let n_fields = self.n_fields();
+        let res = target.unsized_local_scope(n_fields, |mut frame| -> JlrsResult<_> {
+            let mut fields = Vec::with_capacity(n_fields);
+            for i in 0..n_fields {
+                if i == idx as usize {
+                    fields.push(value);
+                    continue;
+                }
+
+                // Fields that are stored inline are boxed, so every field must be rooted.
+                let fld_ptr = jl_get_nth_field(self.unwrap(Private), i);
+                if fld_ptr.is_null() {
+                    Err(AccessError::UndefRef)?;
+                }
+
+                let fld = Value::wrap_non_null(NonNull::new_unchecked(fld_ptr), Private);
+                fields.push(fld.root(&mut frame));
+            }
+
+            // The result is returned as a pointer and rooted in `target` after this scope has
+            // been popped, nothing is allocated in between.
+            let res = match dt.as_value().call(&frame, fields.as_slice()) {
+                Ok(v) => Ok(v.ptr()),
+                Err(e) => Err(e.ptr()),
+            };
+
+            Ok(res)
+        })?;
+
+        Ok(target.result_from_ptr(res, Private))
+    }
+}
+
+/// # Prepare arguments for `ccall`
+///
+/// When Julia calls a foreign function with `ccall`, each argument is first converted with
+/// `Base.cconvert` and the result is converted with `Base.unsafe_convert`. The result of
+/// `cconvert` is kept rooted until the call returns, because the result of `unsafe_convert`,
+/// e.g. a pointer to the data of an array, is only valid while it's rooted.
+impl<'data> Value<'_, 'data> {
+    /// Convert this value to an object that can be converted to `ty` with
+    /// [`Value::unsafe_convert`], this is equivalent to `Base.cconvert(ty, self)` in Julia.
+    ///
+    /// The result must remain rooted while the result of `unsafe_convert` is used, i.e. until
+    /// the foreign function returns. If Julia throws an exception it's caught and returned.
+    ///
+    /// Safety: `cconvert` can be overloaded to execute arbitrary Julia code.
+    pub unsafe fn cconvert<'target, Tgt>(
+        self,
+        target: Tgt,
+        ty: DataType,
+    ) -> ValueResult<'target, 'data, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let func = inline_static_ref!(CCONVERT, Value, "Base.cconvert", &target);
+        func.call2(target, ty.as_value(), self)
+    }
+
+    /// Convert this value to `ty`, this is equivalent to `Base.unsafe_convert(ty, self)` in
+    /// Julia. This value should be the result of [`Value::cconvert`].
+    ///
+    /// The result can contain a pointer to the data of this value, e.g. if `ty` is `Ptr{UInt8}`,
+    /// which is only valid while this value is rooted. If Julia throws an exception it's caught
+    /// and returned.
+    ///
+    /// Safety: `unsafe_convert` can be overloaded to execute arbitrary Julia code. The result
+    /// must not be used after this value is no longer rooted.
+    pub unsafe fn unsafe_convert<'target, Tgt>(
+        self,
+        target: Tgt,
+        ty: DataType,
+    ) -> ValueResult<'target, 'data, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        let func = inline_static_ref!(UNSAFE_CONVERT, Value, "Base.unsafe_convert", &target);
+        func.call2(target, ty.as_value(), self)
+    }
 }
 
 /// # Evaluate Julia code
@@ -1321,6 +2114,10 @@ impl Value<'_, '_> {
     }
 
     /// Returns true if `self` and `other` are equal.
+    ///
+    /// This is the identity comparison `self === other`, not the value comparison `==`. Mutable
+    /// objects are egal only if they're the same object, immutable objects are compared by their
+    /// contents. No Julia function is called, so this is much cheaper than calling `Base.:(===)`.
     #[inline]
     pub fn egal(self, other: Value) -> bool {
         // Safety: the pointer points to valid data, the C API
@@ -1602,6 +2399,56 @@ impl<'scope> Value<'scope, 'static> {
     }
 }
 
+// World age
+impl<'data> Value<'_, 'data> {
+    /// Call this value as a function with `args` in the world `world`.
+    ///
+    /// Methods that have been defined after `world` are invisible to the call, which makes
+    /// dispatch deterministic when methods are defined dynamically. This is the opposite of
+    /// `invokelatest`, which calls a function in the most recent world. The current world can be
+    /// obtained by calling [`Info::current_world`].
+    ///
+    /// Returns `RuntimeError::FutureWorldAge` if `world` is newer than the current world. If an
+    /// exception is thrown it's caught and returned.
+    ///
+    /// Safety: this method lets you call arbitrary Julia functions which can't be checked for
+    /// correctness. More information can be found in the [`safety`] module.
+    ///
+    /// [`safety`]: crate::safety
+    pub unsafe fn call_in_world<'target, 'value, V, Tgt, const N: usize>(
+        self,
+        target: Tgt,
+        args: V,
+        world: usize,
+    ) -> JlrsResult<ValueResult<'target, 'data, Tgt>>
+    where
+        V: Values<'value, 'data, N>,
+        Tgt: Target<'target>,
+    {
+        let current = Info::current_world();
+        if world > current {
+            Err(RuntimeError::FutureWorldAge { world, current })?;
+        }
+
+        let args = args.as_pointers(Private);
+        let callback = || {
+            jlrs_call_in_world(
+                self.unwrap(Private),
+                args.as_ptr() as *mut _,
+                args.len() as _,
+                world,
+            )
+        };
+
+        let res = match catch_exceptions(callback, unwrap_exc) {
+            Ok(ptr) => Ok(NonNull::new_unchecked(ptr)),
+            Err(e) => Err(e),
+        };
+
+        Ok(target.result_from_ptr(res, Private))
+    }
+}
+
 impl<'data> Call<'data> for Value<'_, 'data> {
     #[inline]
     unsafe fn call0<'target, Tgt>(self, target: Tgt) -> ValueResult<'target, 'data, Tgt>
@@ -0,0 +1,96 @@
+//! Send Julia data to another thread.
+//!
+//! A `Value` can't be sent to another thread because it's only guaranteed to be rooted while the
+//! scope it belongs to is active. When Julia data has to be passed between threads, e.g. from one
+//! `MtHandle::with` invocation to another one on a different thread, it must remain rooted until
+//! it has been rooted again on the receiving thread.
+//!
+//! [`Value::prepare_send`] converts a `Value` to a [`SendValue`], which can be sent to another
+//! thread. The value is preserved by calling `Base.preserve_handle`, which keeps it reachable
+//! independently of any scope. The receiving thread calls [`SendValue::receive`] to root the
+//! value in a new target, after which the value is no longer preserved.
+//!
+//! ```
+//! use jlrs::prelude::*;
+//!
+//! # fn main() {
+//! # let mut julia = Builder::new().start_local().unwrap();
+//! let send_value = julia.local_scope::<_, 1>(|mut frame| {
+//!     let value = Value::new(&mut frame, 1usize);
+//!     value.prepare_send()
+//! });
+//!
+//! julia.local_scope::<_, 1>(|mut frame| {
+//!     let value = send_value.receive(&mut frame);
+//!     assert_eq!(value.unbox::<usize>().unwrap(), 1);
+//! });
+//! # }
+//! ```
+
+use std::ptr::NonNull;
+
+use jl_sys::jl_value_t;
+
+use crate::{
+    call::Call,
+    data::managed::{private::ManagedPriv, value::ValueData},
+    inline_static_ref,
+    memory::target::{unrooted::Unrooted, Target},
+    prelude::Value,
+    private::Private,
+};
+
+/// A value that can be sent to another thread.
+///
+/// The value remains rooted until [`SendValue::receive`] is called. If a `SendValue` is dropped
+/// without being received, the value is never freed.
+#[must_use]
+#[derive(Debug)]
+pub struct SendValue {
+    ptr: NonNull<jl_value_t>,
+}
+
+// Safety: the value is preserved until it's received, it can only be accessed again after it has
+// been received on a thread that can call into Julia.
+unsafe impl Send for SendValue {}
+unsafe impl Sync for SendValue {}
+
+impl SendValue {
+    pub(crate) fn new(value: Value<'_, 'static>) -> Self {
+        // Safety: a value exists, so this thread can call into Julia. Base.preserve_handle only
+        // throws if it can't allocate.
+        unsafe {
+            let unrooted = Unrooted::new();
+            let preserve =
+                inline_static_ref!(PRESERVE_HANDLE, Value, "Base.preserve_handle", &unrooted);
+            preserve.call_unchecked(&unrooted, [value]);
+        }
+
+        SendValue {
+            ptr: value.unwrap_non_null(Private),
+        }
+    }
+
+    /// Root the value in `target` and stop preserving it.
+    ///
+    /// If `target` is not rooting, the value is no longer rooted after this method returns.
+    pub fn receive<'target, Tgt>(self, target: Tgt) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        // Safety: the value is still preserved, it's rooted in target before it's unpreserved.
+        unsafe {
+            let unrooted = Unrooted::new();
+            let unpreserve = inline_static_ref!(
+                UNPRESERVE_HANDLE,
+                Value,
+                "Base.unpreserve_handle",
+                &unrooted
+            );
+            let value = target.data_from_ptr::<Value>(self.ptr, Private);
+            let v = Value::wrap_non_null(self.ptr, Private);
+            unpreserve.call_unchecked(&unrooted, [v]);
+            value
+        }
+    }
+}
@@ -43,7 +43,7 @@ use crate::{
             typecheck::Typecheck,
         },
     },
-    error::{JlrsResult, TypeError},
+    error::{JlrsResult, TypeError, CANNOT_DISPLAY_TYPE},
     memory::{
         scope::LocalScope,
         target::{Target, TargetResult},
@@ -120,9 +120,9 @@ impl<'scope, 'data, U: ConstructType> TypedValue<'scope, 'data, U> {
                         Private,
                     ))
                 } else {
-                    Err(TypeError::NotA {
-                        value: value.display_string_or("<Cannot display value>"),
-                        field_type: ty.display_string_or("<Cannot display type>"),
+                    Err(TypeError::TypeMismatch {
+                        expected: ty.display_string_or(CANNOT_DISPLAY_TYPE),
+                        found: value.datatype().display_string_or(CANNOT_DISPLAY_TYPE),
                     })?
                 }
             })
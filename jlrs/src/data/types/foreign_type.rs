@@ -668,6 +668,24 @@ pub unsafe trait ParametricVariant: ParametricBase {
 /// that all managed types provide, lifetimes can be erased by calling either [`Ref::leak`] or
 /// [`Managed::leak`].
 ///
+/// For example, a type that caches a Julia value must mark that value:
+///
+/// ```no_run
+/// # use jlrs::{data::types::foreign_type::ForeignType, memory::{gc::mark_queue_obj, PTls}};
+/// # use jlrs::prelude::*;
+/// struct CachedResult {
+///     value: ValueRef<'static, 'static>,
+/// }
+///
+/// unsafe impl Send for CachedResult {}
+///
+/// unsafe impl ForeignType for CachedResult {
+///     fn mark(ptls: PTls, data: &Self) -> usize {
+///         unsafe { mark_queue_obj(ptls, data.value) as usize }
+///     }
+/// }
+/// ```
+///
 /// Safety:
 ///
 /// All implementations of this trait automatically implement `OpaqueType`, and like direct
@@ -4,4 +4,5 @@ pub mod abstract_type;
 pub mod construct_type;
 pub mod foreign_type;
 pub mod primitive_type;
+pub mod rust_iter;
 pub mod typecheck;
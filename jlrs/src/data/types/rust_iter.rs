@@ -0,0 +1,129 @@
+//! Expose Rust iterators to Julia.
+//!
+//! A [`RustIter`] wraps an arbitrary Rust iterator and can be exported as an opaque type with the
+//! `julia_module` macro. By exporting its `iterate` methods as `Base.iterate`, Julia code can
+//! consume the iterator lazily in a `for`-loop, items are only produced when Julia asks for them.
+//!
+//! ```ignore
+//! use jlrs::{
+//!     data::{managed::value::typed::TypedValueRet, types::rust_iter::RustIter},
+//!     prelude::*,
+//! };
+//!
+//! fn squares(n: i64) -> TypedValueRet<RustIter<i64>> {
+//!     RustIter::new_ret((0..n).map(|i| i * i))
+//! }
+//!
+//! julia_module! {
+//!     become squares_module_init_fn;
+//!
+//!     for T in [i64, f64] {
+//!         struct RustIter<T>;
+//!         in RustIter<T> fn iterate(&mut self) -> ValueRet as Base.iterate;
+//!         in RustIter<T> fn iterate_with_state(&mut self, state: Value) -> ValueRet as Base.iterate;
+//!     };
+//!
+//!     fn squares(n: i64) -> TypedValueRet<RustIter<i64>>;
+//! }
+//! ```
+//!
+//! After loading this module, `for x in squares(4) ... end` iterates over `0, 1, 4, 9`. Because
+//! the state of the iteration lives in the Rust iterator, a `RustIter` can only be iterated over
+//! once. The length of the iterator is unknown to Julia, functions like `collect` require
+//! `Base.IteratorSize(::Type{<:RustIter}) = Base.SizeUnknown()` to be defined.
+
+use crate::{
+    convert::into_julia::IntoJulia,
+    data::{
+        layout::tuple::Tuple,
+        managed::{
+            value::{
+                typed::{TypedValue, TypedValueRet},
+                Value, ValueRet,
+            },
+            Managed,
+        },
+        types::{
+            construct_type::ConstructType,
+            foreign_type::{ParametricBase, ParametricVariant},
+        },
+    },
+    impl_type_parameters, impl_variant_parameters,
+    memory::scope::LocalScope,
+    weak_handle_unchecked,
+};
+
+/// An opaque wrapper around a Rust iterator that yields items of type `T`.
+///
+/// See the [module-level docs] for more information.
+///
+/// [module-level docs]: self
+pub struct RustIter<T> {
+    iter: Box<dyn Iterator<Item = T> + Send>,
+}
+
+impl<T> RustIter<T>
+where
+    T: 'static + Send + ConstructType + IntoJulia,
+{
+    /// Wrap `iter`.
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+    {
+        RustIter {
+            iter: Box::new(iter.into_iter()),
+        }
+    }
+
+    /// Wrap `iter` and move it to Julia.
+    ///
+    /// This function must only be called from a thread known to Julia, which is the case for
+    /// functions exported with the `julia_module` macro.
+    pub fn new_ret<I>(iter: I) -> TypedValueRet<Self>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+    {
+        let weak_handle = unsafe { weak_handle_unchecked!() };
+        TypedValue::new(weak_handle, Self::new(iter)).leak()
+    }
+
+    /// Advance the iterator, returns `nothing` if the iterator is exhausted and `(item, nothing)`
+    /// otherwise.
+    ///
+    /// This method should be exported as the single-argument `Base.iterate` method.
+    pub fn iterate(&mut self) -> ValueRet {
+        let weak_handle = unsafe { weak_handle_unchecked!() };
+
+        match self.iter.next() {
+            None => Value::nothing(&weak_handle).as_ref().leak(),
+            Some(item) => weak_handle.local_scope::<_, 1>(|mut frame| {
+                let item = Value::new(&mut frame, item);
+                let nothing = Value::nothing(&frame);
+                // Safety: creating a tuple of two values can't throw an exception.
+                unsafe { Tuple::new_unchecked(&weak_handle, [item, nothing]).leak() }
+            }),
+        }
+    }
+
+    /// Advance the iterator, the state is ignored because it's tracked by the iterator itself.
+    ///
+    /// This method should be exported as the two-argument `Base.iterate` method.
+    pub fn iterate_with_state(&mut self, _state: Value) -> ValueRet {
+        self.iterate()
+    }
+}
+
+unsafe impl<T> ParametricBase for RustIter<T>
+where
+    T: 'static + Send + ConstructType,
+{
+    type Key = RustIter<()>;
+    impl_type_parameters!('T');
+}
+
+unsafe impl<T: 'static + Send + ConstructType> ParametricVariant for RustIter<T> {
+    impl_variant_parameters!(T);
+}
@@ -1,12 +1,14 @@
 //! Everything related to errors.
 
-use std::{error::Error as StdErr, pin::Pin, sync::Arc};
+use std::{error::Error as StdErr, fmt::Display, pin::Pin, sync::Arc};
 
 use thiserror::Error;
 
 use crate::{
     data::managed::{
         array::dimensions::Dimensions,
+        datatype::DataType,
+        string::JuliaString,
         value::{Value, ValueRef, ValueRet},
         RefRet, Ret,
     },
@@ -191,6 +193,86 @@ impl<'scope, V> JuliaResultRefExt<'scope, V> for JuliaResultRef<'scope, 'static,
     }
 }
 
+/// Convert a Rust error to a Julia exception.
+///
+/// Functions exported with `julia_module!` that are annotated with `#[throws]` can return
+/// `Result<T, E>` where `E` implements this trait. If such a function returns an error, it's
+/// converted to a Julia exception with this trait and thrown. This trait is implemented for
+/// strings, [`JlrsError`], boxed errors, several error types from the standard library and
+/// `anyhow::Error` if the `anyhow` feature is enabled. These errors are converted to an
+/// `ErrorException` with the formatted error as its message. Custom error types
+/// can implement this trait to throw an exception of a more specific type, [`error_exception`]
+/// can be used to convert an error that implements `Display` to an `ErrorException`.
+pub trait IntoJuliaError {
+    /// Convert this error to a Julia exception.
+    ///
+    /// Safety: implementations must not throw a Julia exception, this method is called from
+    /// `ccall`ed functions where an exception can't unwind through the Rust frames on the stack.
+    unsafe fn into_julia_error<'target, Tgt>(self, target: Tgt) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>;
+}
+
+/// Create an `ErrorException` with the formatted `error` as its message.
+pub fn error_exception<'target, E, Tgt>(error: E, target: Tgt) -> ValueData<'target, 'static, Tgt>
+where
+    E: Display,
+    Tgt: Target<'target>,
+{
+    target.with_local_scope::<_, _, 1>(|target, mut frame| unsafe {
+        let msg = JuliaString::new(&mut frame, error.to_string()).as_value();
+        DataType::errorexception_type(&frame).instantiate_unchecked(target, [msg])
+    })
+}
+
+macro_rules! impl_into_julia_error {
+    ($($ty:ty),+) => {
+        $(
+            impl IntoJuliaError for $ty {
+                #[inline]
+                unsafe fn into_julia_error<'target, Tgt>(
+                    self,
+                    target: Tgt,
+                ) -> ValueData<'target, 'static, Tgt>
+                where
+                    Tgt: Target<'target>,
+                {
+                    error_exception(self, target)
+                }
+            }
+        )+
+    };
+}
+
+impl_into_julia_error!(
+    String,
+    &str,
+    JlrsError,
+    Box<JlrsError>,
+    std::fmt::Error,
+    std::io::Error,
+    std::num::ParseIntError,
+    std::num::ParseFloatError,
+    std::num::TryFromIntError,
+    std::str::ParseBoolError,
+    std::str::Utf8Error,
+    std::string::FromUtf8Error,
+    Box<dyn StdErr>,
+    Box<dyn StdErr + Send + Sync>
+);
+
+#[cfg(feature = "anyhow")]
+impl IntoJuliaError for anyhow::Error {
+    // The alternate format includes the causes of the error.
+    #[inline]
+    unsafe fn into_julia_error<'target, Tgt>(self, target: Tgt) -> ValueData<'target, 'static, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        error_exception(format_args!("{self:#}"), target)
+    }
+}
+
 /// Runtime errors.
 #[derive(Debug, Error, Clone)]
 pub enum RuntimeError {
@@ -208,6 +290,24 @@ pub enum RuntimeError {
     InvalidThread,
     #[error("the current state does not allow creating new handles")]
     IncorrectState,
+    #[error("JlrsCore cannot be loaded in project {project}")]
+    JlrsCoreUnavailable { project: String },
+    #[error("juliaup channel {channel} is not installed")]
+    JuliaupChannelNotFound { channel: String },
+    #[error("world {world} is newer than the current world {current}")]
+    FutureWorldAge { world: usize, current: usize },
+    #[error("invalid GC tuning: {msg}")]
+    InvalidGcTuning { msg: String },
+    #[error("trace path contains a null character: {path}")]
+    InvalidTracePath { path: String },
+    #[error("CPU target contains a null character: {target}")]
+    InvalidCpuTarget { target: String },
+    #[error("thread name prefix contains a null character: {prefix}")]
+    InvalidThreadNamePrefix { prefix: String },
+    #[error("cannot activate project {project}")]
+    InvalidProject { project: String },
+    #[error("cannot set the depot path to {depot}")]
+    InvalidDepot { depot: String },
 }
 
 /// IO errors.
@@ -224,6 +324,8 @@ pub enum TypeError {
     NotAFunction { name: String, ty: String },
     #[error("expected a NamedTuple, got a {ty}")]
     NotANamedTuple { ty: String },
+    #[error("expected a Tuple, got a {ty}")]
+    NotATuple { ty: String },
     #[error("expected a Module, {name} is a {ty}")]
     NotAModule { name: String, ty: String },
     #[error("{element_type} is not a {value_type}")]
@@ -238,18 +340,34 @@ pub enum TypeError {
     },
     #[error("{value} is not a {field_type}")]
     NotA { value: String, field_type: String },
+    #[error("expected an instance of {expected}, got an instance of {found}")]
+    TypeMismatch { expected: String, found: String },
+    #[error("{discriminant} is not a known variant of {enum_type}")]
+    UnknownEnumVariant {
+        discriminant: String,
+        enum_type: String,
+    },
     #[error("{value} is not a concrete datatype")]
     NotConcrete { value: String },
     #[error("layout is invalid for {value_type}")]
     InvalidLayout { value_type: String },
     #[error("{value_type} is immutable")]
     Immutable { value_type: String },
+    #[error("{value_type} is mutable")]
+    Mutable { value_type: String },
     #[error("No base type is available")]
     NoBaseType,
     #[error("Layout of {ty} is None")]
     LayoutNone { ty: String },
     #[error("The layout of this type is incompatible with {base_type}")]
     IncompatibleBaseType { base_type: String },
+    #[error("{value} is out of range for {target_type} ({min}..={max})")]
+    IntegerOverflow {
+        value: String,
+        target_type: String,
+        min: String,
+        max: String,
+    },
 }
 
 /// Array layout errors.
@@ -261,6 +379,8 @@ pub enum ArrayLayoutError {
     NotInline { element_type: String },
     #[error("element type is {element_type}, which has pointer fields")]
     NotBits { element_type: String },
+    #[error("element type is {element_type}, which contains padding")]
+    HasPadding { element_type: String },
     #[error("element type is {element_type}, which is stored inline")]
     NotPointer { element_type: String },
     #[error("element type is {element_type}, not {name}")]
@@ -304,8 +424,16 @@ pub enum AccessError {
     },
     #[error("index {idx} is out-of-bounds for SimpleVector of length {len}")]
     OutOfBoundsSVec { idx: usize, len: usize },
+    #[error("cannot copy {src_len} elements to an array of length {dst_len}")]
+    ArrayLengthMismatch { dst_len: usize, src_len: usize },
+    #[error("index {idx} is out-of-bounds for array of length {len}")]
+    OutOfBoundsArrayIndex { idx: usize, len: usize },
+    #[error("the data of this array is owned by another object")]
+    ArrayDataNotOwned,
     #[error("index {idx} is invalid for array with shape {sz}")]
     InvalidIndex { idx: Dimensions, sz: Dimensions },
+    #[error("array with shape {arg} cannot be broadcast into array with shape {dest}")]
+    BroadcastShapeMismatch { arg: Dimensions, dest: Dimensions },
     #[error("arrays can only be accessed with n-dimensional indices")]
     ArrayNeedsNumericalIndex,
     #[error("fields cannot be accessed with n-dimensional indices")]
@@ -314,6 +442,8 @@ pub enum AccessError {
     UndefRef,
     #[error("type {value_type} has no fields")]
     NoFields { value_type: String },
+    #[error("invalid atomic ordering :{order}")]
+    InvalidMemoryOrder { order: &'static str },
 }
 
 /// Data instantiation errors.
@@ -323,10 +453,14 @@ pub enum InstantiationError {
     ArrayNotSupported,
     #[error("NamedTuples must have an equal number of keys and values, got {n_names} keys and {n_values} values")]
     NamedTupleSizeMismatch { n_names: usize, n_values: usize },
+    #[error("NamedTuples must have unique keys, {name} occurs more than once")]
+    DuplicateNamedTupleKey { name: String },
     #[error("expected a shape for {vec_size} elements, got a shape for {dim_size} elements")]
     ArraySizeMismatch { dim_size: usize, vec_size: usize },
     #[error("expected dimensions of rank {expected}, got {found}")]
     ArrayRankMismatch { expected: usize, found: usize },
+    #[error("cannot reshape an array to zero dimensions")]
+    ZeroDimensionalReshape,
 }
 
 /// Julia exception converted to a string.
@@ -344,6 +478,13 @@ impl Exception {
 }
 
 /// All different errors.
+///
+/// A `JlrsError` owns all its data, Julia exceptions are rendered to a string when they're
+/// converted with [`IntoJlrsResult`]. As a result, `JlrsError` and `Box<JlrsError>` are
+/// `Send + Sync` and can be converted to `Box<dyn Error + Send + Sync>` or `anyhow::Error` with
+/// `?`.
+///
+/// [`IntoJlrsResult`]: crate::convert::into_jlrs_result::IntoJlrsResult
 #[derive(Debug, Error, Clone)]
 pub enum JlrsError {
     #[error("Other: {0}")]
@@ -362,8 +503,17 @@ pub enum JlrsError {
     InstantiationError(InstantiationError),
     #[error("Array layout error: {0}")]
     ArrayLayoutError(ArrayLayoutError),
+    #[error("task has been cancelled")]
+    Cancelled,
 }
 
+// Errors must remain convertible to `Box<dyn Error + Send + Sync>`.
+const _: () = {
+    const fn assert_send_sync_error<E: StdErr + Send + Sync + 'static>() {}
+    assert_send_sync_error::<JlrsError>();
+    assert_send_sync_error::<Box<JlrsError>>();
+};
+
 impl JlrsError {
     /// Convert an arbitrary error to `JlrsError::Other`.
     #[inline]
@@ -1,14 +1,22 @@
 //! System and Julia version information.
 
-use std::{ffi::CStr, ptr::NonNull};
+use std::{ffi::CStr, ptr::NonNull, sync::atomic::Ordering};
 
 use jl_sys::{
-    jl_cpu_threads, jl_get_UNAME, jl_is_debugbuild, jl_n_threads, jl_ver_is_release, jl_ver_major,
-    jl_ver_minor, jl_ver_patch, jl_ver_string,
+    jl_cpu_threads, jl_get_UNAME, jl_get_world_counter, jl_is_debugbuild, jl_n_threads,
+    jl_ver_is_release, jl_ver_major, jl_ver_minor, jl_ver_patch, jl_ver_string,
+    jlrs_get_nsweepthreads,
 };
 
 use crate::{
-    data::managed::{private::ManagedPriv, symbol::Symbol},
+    call::Call,
+    convert::into_jlrs_result::IntoJlrsResult,
+    data::managed::{
+        array::TypedVector, module::Module, private::ManagedPriv, symbol::Symbol, value::Value,
+    },
+    error::JlrsResult,
+    inline_static_ref,
+    memory::{scope::LocalScope, target::Target},
     private::Private,
 };
 
@@ -43,6 +51,24 @@ impl Info {
         unsafe { jl_sys::jl_n_gcthreads as usize }
     }
 
+    /// The number of GC mark and sweep threads, in that order.
+    ///
+    /// The total is equal to [`Info::n_gc_threads`]. The number of sweep threads is read from
+    /// the options Julia has been initialized with, i.e. `--gcthreads=M,N`.
+    pub fn gc_threads() -> (usize, usize) {
+        let n_gc_threads = Self::n_gc_threads();
+        let n_sweep_threads = unsafe { jlrs_get_nsweepthreads() }.max(0) as usize;
+        let n_sweep_threads = n_sweep_threads.min(n_gc_threads);
+        (n_gc_threads - n_sweep_threads, n_sweep_threads)
+    }
+
+    /// Returns `true` if the GC sweeps concurrently, i.e. if at least one sweep thread is
+    /// available.
+    #[inline]
+    pub fn gc_is_concurrent() -> bool {
+        Self::gc_threads().1 != 0
+    }
+
     /// Returns `true` if a debug build of Julia is used.
     #[inline]
     pub fn is_debugbuild() -> bool {
@@ -97,6 +123,17 @@ impl Info {
         unsafe { jl_ver_patch() as isize }
     }
 
+    /// The current world age.
+    ///
+    /// The world age is incremented every time a method is defined, it can be used with
+    /// [`Value::call_in_world`] to call a function in a fixed world.
+    ///
+    /// [`Value::call_in_world`]: crate::data::managed::value::Value::call_in_world
+    #[inline]
+    pub fn current_world() -> usize {
+        unsafe { jl_get_world_counter() }
+    }
+
     /// Returns true if a release version of Julia is used.
     #[inline]
     pub fn is_release() -> bool {
@@ -108,6 +145,44 @@ impl Info {
     pub fn version_string() -> &'static str {
         unsafe { CStr::from_ptr(jl_ver_string()).to_str().unwrap() }
     }
+
+    /// Returns the name and top-level module of every loaded package in the order they have
+    /// been loaded.
+    ///
+    /// The modules are read from `Base.loaded_modules_array()`, they include the standard
+    /// libraries and packages that have been loaded in this session. This requires one slot on
+    /// the GC stack.
+    pub fn loaded_modules<'target, Tgt>(target: &Tgt) -> JlrsResult<Vec<(String, Module<'target>)>>
+    where
+        Tgt: Target<'target>,
+    {
+        let loaded_modules_array = inline_static_ref!(
+            LOADED_MODULES_ARRAY,
+            Value,
+            "Base.loaded_modules_array",
+            target
+        );
+
+        target.local_scope::<_, 1>(|mut frame| unsafe {
+            let modules = loaded_modules_array
+                .call0(&mut frame)
+                .into_jlrs_result()?
+                .cast::<TypedVector<Module>>()?;
+
+            modules
+                .managed_data()
+                .as_slice()
+                .iter()
+                .filter_map(|module| module.load(Ordering::Relaxed))
+                .map(|module| module.as_managed())
+                .map(|module| {
+                    // Loaded modules are never freed, so they can outlive the frame.
+                    let module = Module::wrap_non_null(module.unwrap_non_null(Private), Private);
+                    Ok((module.name().as_string()?, module))
+                })
+                .collect()
+        })
+    }
 }
 
 /// Alias for a result that contains either a valid UTF8-encoded string slice, or the raw byte
@@ -123,4 +198,11 @@ mod test {
         assert_eq!(Info::major_version(), 1);
         assert_eq!(Info::n_threads(), 0);
     }
+
+    #[test]
+    fn gc_threads() {
+        let (n_mark, n_sweep) = Info::gc_threads();
+        assert_eq!(n_mark + n_sweep, Info::n_gc_threads());
+        assert_eq!(Info::gc_is_concurrent(), n_sweep != 0);
+    }
 }
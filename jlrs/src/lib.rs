@@ -166,6 +166,10 @@
 //!
 //!   Adds support for working with Julia's `Complex` type from Rust using num's `Complex` type.
 //!
+//! - `bigint`
+//!
+//!   Adds support for converting between Julia's `BigInt` type and num's `BigInt` type.
+//!
 //! - `ccall`
 //!
 //!   Julia's `ccall` interface can be used to call functions written in Rust from Julia. No
@@ -191,11 +195,40 @@
 //!   you must set at least the following flags:
 //!   `RUSTFLAGS="-Clinker-plugin-lto -Clinker=clang-XX -Clink-arg=-fuse-ld=lld -Clink-args=-rdynamic"`.
 //!
+//! - `tracing`
+//!
+//!   Adds `Builder::julia_output_to_tracing`, which redirects Julia's stdout and stderr to
+//!   `tracing` events. This feature is only supported on Unix.
+//!
+//! - `juliaup`
+//!
+//!   Adds `Builder::from_juliaup_channel`, which initializes Julia with the installation of a
+//!   juliaup channel.
+//!
+//! - `serde`
+//!
+//!   Adds the `convert::serde` module, which serializes and deserializes Julia data with Julia's
+//!   `Serialization` standard library.
+//!
+//! - `smallvec`
+//!
+//!   Adds `ArrayBase::to_smallvec`, which copies the `isbits` data of an array to a `SmallVec`.
+//!
+//! - `anyhow`
+//!
+//!   Implements `IntoJuliaError` for `anyhow::Error`, so functions exported with `julia_module!`
+//!   that are annotated with `#[throws]` can return `anyhow::Result`.
+//!
 //! - `diagnostics`
 //!
 //!   Enable custom diagnostics for several traits because the default lint is unhelpful. This feature
 //!   requires Rust 1.78.
 //!
+//! - `unstable`
+//!
+//!   Enable experimental APIs that are easy to misuse, like atomic field operations. These APIs
+//!   are subject to change and don't follow the usual semver guarantees.
+//!
 //! - `i686`
 //!
 //!   Link with a 32-bit build of Julia on Linux, only used for cross-compilation.
@@ -216,9 +249,9 @@
 //!
 //!   Flag that must be enabled when compiling with BinaryBuilder.
 //!
-//! You can enable all features except `debug`, `i686`, `windows`, `no-link`, `lto` and
-//! `yggdrasil` by enabling the `full` feature. If you don't want to enable any runtimes either,
-//! you can use `full-no-rt`.
+//! You can enable all features except `debug`, `i686`, `windows`, `no-link`, `lto`, `unstable`,
+//! `tracing`, `juliaup` and `yggdrasil` by enabling the `full` feature. If you don't want to
+//! enable any runtimes either, you can use `full-no-rt`.
 //!
 //!
 //! # Using jlrs
@@ -2,9 +2,10 @@
 
 pub use jl_sys::GcCollection;
 use jl_sys::{
-    jl_gc_collect, jl_gc_collection_t, jl_gc_enable, jl_gc_is_enabled, jl_gc_mark_queue_obj,
-    jl_gc_mark_queue_objarray, jl_gc_safepoint, jlrs_gc_safe_enter, jlrs_gc_safe_leave,
-    jlrs_gc_unsafe_enter, jlrs_gc_unsafe_leave, jlrs_gc_wb, jlrs_ppgcstack,
+    jl_gc_collect, jl_gc_collection_t, jl_gc_enable, jl_gc_get_total_bytes, jl_gc_is_enabled,
+    jl_gc_live_bytes, jl_gc_mark_queue_obj, jl_gc_mark_queue_objarray, jl_gc_safepoint,
+    jlrs_gc_safe_enter, jlrs_gc_safe_leave, jlrs_gc_unsafe_enter, jlrs_gc_unsafe_leave, jlrs_gc_wb,
+    jlrs_ppgcstack,
 };
 
 use super::{
@@ -16,19 +17,23 @@ use super::{
 use crate::runtime::sync_rt::Julia;
 use crate::{
     call::Call,
+    convert::into_jlrs_result::IntoJlrsResult,
     data::managed::{
         module::Module,
         private::ManagedPriv,
         value::{Value, ValueRef},
     },
+    error::JlrsResult,
+    inline_static_ref,
+    memory::scope::LocalScope,
     private::Private,
 };
 
 /// Manage the GC.
 ///
 /// This trait provides several methods that can be used to enable or disable the GC, force a
-/// collection, insert a safepoint, and to enable and disable GC logging. It's implemented for
-/// [`Julia`] and all [`Target`]s.
+/// collection, insert a safepoint, to enable and disable GC logging, and to read some GC
+/// statistics. It's implemented for [`Julia`] and all [`Target`]s.
 pub trait Gc: private::GcPriv {
     /// Enable or disable the GC.
     #[inline]
@@ -96,6 +101,51 @@ pub trait Gc: private::GcPriv {
         }
     }
 
+    /// Returns the total number of bytes that have been allocated since Julia was started,
+    /// including memory that has already been freed. This is equivalent to `Base.gc_bytes()`.
+    ///
+    /// The value is approximate, allocations by other threads might not have been accounted for
+    /// yet.
+    #[inline]
+    fn total_allocated_bytes(&self) -> i64 {
+        let mut bytes = 0;
+        // Safety: this function can only be called while Julia is active from a thread known to
+        // Julia.
+        unsafe { jl_gc_get_total_bytes(&mut bytes) };
+        bytes
+    }
+
+    /// Returns the number of bytes that are in use by live objects. This is equivalent to
+    /// `Base.gc_live_bytes()`.
+    ///
+    /// The value is approximate, it's updated after each collection and adjusted for the
+    /// allocations that have been made since then.
+    #[inline]
+    fn live_bytes(&self) -> i64 {
+        // Safety: this function can only be called while Julia is active from a thread known to
+        // Julia.
+        unsafe { jl_gc_live_bytes() }
+    }
+
+    /// Returns the number of collections since Julia was started. This is equivalent to
+    /// `Base.gc_num().pause`.
+    ///
+    /// The value is approximate, a collection might be running while it's read. An error is
+    /// returned if `Base.gc_num` throws or its result has no `pause` field.
+    fn collections_count(&self) -> JlrsResult<usize> {
+        // Safety: this function can only be called while Julia is active from a thread known to
+        // Julia. Base.gc_num returns the GC counters as an isbits struct, exceptions are caught.
+        unsafe {
+            let unrooted = Unrooted::new();
+            unrooted.local_scope::<_, 1>(|mut frame| {
+                let gc_num = inline_static_ref!(GC_NUM, Value, "Base.gc_num", &frame);
+                let counters = gc_num.call0(&mut frame).into_jlrs_result()?;
+                let pause = counters.field_accessor().field("pause")?.access::<i32>()?;
+                Ok(pause as usize)
+            })
+        }
+    }
+
     /// Insert a safepoint, a point where the garbage collector may run.
     #[inline]
     fn gc_safepoint(&self) {
@@ -0,0 +1,458 @@
+//! Dynamically and statically-sized frames.
+//!
+//! Every scope has its own frame which can hold some number of roots. When the scope ends these
+//! roots are removed from the set of roots, so all data rooted in a frame can safely be used
+//! until its scope ends. This hold true even if the frame is dropped before its scope ends.
+//!
+//! For more information see the documentation in the [`memory`] and [`target`] modules.
+//!
+//! [`memory`]: crate::memory
+//! [`target`]: crate::memory::target
+
+#[cfg(feature = "async")]
+pub mod async_frame;
+
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::{marker::PhantomData, pin::Pin, ptr::NonNull};
+
+use jl_sys::{pop_frame, RawGcFrame, UnsizedGcFrame};
+
+#[cfg(feature = "async")]
+pub use self::async_frame::*;
+use super::{
+    output::{LocalOutput, Output},
+    reusable_slot::{LocalReusableSlot, ReusableSlot},
+    unrooted::Unrooted,
+    ExtendedTarget, Target,
+};
+use crate::{
+    data::managed::{private::ManagedPriv, value::Value, Managed},
+    error::JlrsResult,
+    memory::context::stack::Stack,
+    prelude::Scope,
+    private::Private,
+};
+
+/// A dynamically-sized frame that can hold an arbitrary number of roots.
+pub struct GcFrame<'scope> {
+    stack: &'scope Stack,
+    offset: usize,
+    _marker: PhantomData<&'scope mut &'scope ()>,
+}
+
+impl<'scope> GcFrame<'scope> {
+    /// Returns a mutable reference to this frame.
+    #[inline]
+    pub fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Reserve capacity for at least `additional` roots.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.stack.reserve(additional)
+    }
+
+    /// Borrow the current frame.
+    #[inline]
+    pub fn borrow<'borrow>(&'borrow mut self) -> BorrowedFrame<'borrow, 'scope, Self> {
+        BorrowedFrame(self, PhantomData)
+    }
+
+    /// Borrow this frame as an `ExtendedTarget` with the provided `target`.
+    #[inline]
+    pub fn extended_target<'target, 'borrow, Tgt>(
+        &'borrow mut self,
+        target: Tgt,
+    ) -> ExtendedTarget<'target, 'scope, 'borrow, Tgt>
+    where
+        Tgt: Target<'target>,
+    {
+        ExtendedTarget {
+            target,
+            frame: self,
+            _target_marker: PhantomData,
+        }
+    }
+
+    /// Borrow this frame as an `ExtendedTarget` with an `Output` that targets this frame.
+    #[inline]
+    pub fn as_extended_target<'borrow>(
+        &'borrow mut self,
+    ) -> ExtendedTarget<'scope, 'scope, 'borrow, Output<'scope>> {
+        let target = self.output();
+        ExtendedTarget {
+            target,
+            frame: self,
+            _target_marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of values rooted in this frame.
+    #[inline]
+    pub fn n_roots(&self) -> usize {
+        self.stack_size() - self.offset
+    }
+
+    /// Returns the number of values rooted in this frame.
+    #[inline]
+    pub fn stack_size(&self) -> usize {
+        self.stack.size()
+    }
+
+    /// Returns an `Output` that targets the current frame.
+    #[inline]
+    pub fn output(&mut self) -> Output<'scope> {
+        unsafe {
+            let offset = self.stack.reserve_slot();
+            Output {
+                stack: self.stack,
+                offset,
+            }
+        }
+    }
+
+    /// Returns a `ReusableSlot` that targets the current frame.
+    #[inline]
+    pub fn reusable_slot(&mut self) -> ReusableSlot<'scope> {
+        unsafe {
+            let offset = self.stack.reserve_slot();
+            ReusableSlot {
+                stack: self.stack,
+                offset,
+            }
+        }
+    }
+
+    /// Root all values in `values` in this frame, and return an iterator over the rooted values.
+    ///
+    /// Capacity for all roots is reserved before the first value is rooted, and all values are
+    /// rooted before this method returns. Rooting a value doesn't allocate managed data, so the
+    /// GC can't run until all values have been rooted.
+    pub fn root_slice<'values, 'data: 'values>(
+        &mut self,
+        values: &'values [Value<'_, 'data>],
+    ) -> impl ExactSizeIterator<Item = Value<'scope, 'data>> + 'values
+    where
+        'scope: 'values,
+    {
+        self.reserve(values.len());
+
+        for value in values {
+            // Safety: the value points to valid data.
+            unsafe { self.root::<Value>(value.unwrap_non_null(Private)) };
+        }
+
+        // Safety: all values have been rooted in this frame.
+        values
+            .iter()
+            .map(|value| unsafe { Value::wrap_non_null(value.unwrap_non_null(Private), Private) })
+    }
+
+    /// Returns an `Unrooted` that targets the current frame.
+    #[inline]
+    pub const fn unrooted(&self) -> Unrooted<'scope> {
+        unsafe { Unrooted::new() }
+    }
+
+    // Safety: ptr must be a valid pointer to T
+    #[inline]
+    pub(crate) unsafe fn root<'data, T: Managed<'scope, 'data>>(
+        &self,
+        ptr: NonNull<T::Wraps>,
+    ) -> T {
+        self.stack.push_root(ptr.cast());
+        T::wrap_non_null(ptr, Private)
+    }
+
+    #[inline]
+    pub(crate) fn stack(&self) -> &Stack {
+        self.stack
+    }
+
+    #[inline]
+    pub(crate) unsafe fn nest<'nested>(&'nested mut self) -> (usize, GcFrame<'nested>) {
+        let frame = GcFrame {
+            stack: self.stack(),
+            offset: self.stack.size(),
+            _marker: PhantomData,
+        };
+        (self.stack.size(), frame)
+    }
+
+    // Safety: only one base frame can exist per `Stack`
+    #[inline]
+    pub(crate) unsafe fn base(stack: &'scope Stack) -> GcFrame<'scope> {
+        debug_assert_eq!(stack.size(), 0);
+        GcFrame {
+            stack,
+            offset: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn returning<T>(&mut self) -> &mut impl Scope<'scope, T> {
+        self
+    }
+
+    // pub fn stack_addr(&self) -> *const c_void {
+    //     self.stack as *const _ as *const _
+    // }
+}
+
+impl<'ctx, T> Scope<'ctx, T> for GcFrame<'ctx> {
+    #[inline]
+    fn scope<F>(&mut self, func: F) -> T
+    where
+        for<'scope> F: FnOnce(GcFrame<'scope>) -> T,
+    {
+        unsafe {
+            let (offset, nested) = self.nest();
+            let res = func(nested);
+            self.stack.pop_roots(offset);
+            res
+        }
+    }
+}
+
+/// A statically-sized frame that can hold `N` roots.
+pub struct LocalGcFrame<'scope, const N: usize> {
+    frame: &'scope PinnedLocalFrame<'scope, N>,
+    offset: usize,
+}
+
+impl<'scope, const N: usize> LocalGcFrame<'scope, N> {
+    /// Returns a mutable reference to this frame.
+    #[inline]
+    pub fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Returns the number of values rooted in this frame.
+    #[inline]
+    pub fn n_roots(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of values that can be rooted in this frame.
+    #[inline]
+    pub const fn frame_size(&self) -> usize {
+        N
+    }
+
+    /// Returns a `LocalOutput` that targets the current frame.
+    #[inline]
+    pub fn local_output(&mut self) -> LocalOutput<'scope> {
+        unsafe {
+            let slot = self.frame.frame.raw.get_root(self.offset);
+            self.offset += 1;
+            LocalOutput::new(slot)
+        }
+    }
+
+    /// Returns a `LocalReusableSlot` that targets the current frame.
+    #[inline]
+    pub fn local_reusable_slot(&mut self) -> LocalReusableSlot<'scope> {
+        unsafe {
+            let slot = self.frame.frame.raw.get_root(self.offset);
+            self.offset += 1;
+            LocalReusableSlot::new(slot)
+        }
+    }
+
+    /// Returns a `Unrooted` that targets the current frame.
+    #[inline]
+    pub const fn unrooted(&self) -> Unrooted<'scope> {
+        unsafe { Unrooted::new() }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn new(frame: &'scope PinnedLocalFrame<'scope, N>) -> Self {
+        LocalGcFrame { frame, offset: 0 }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn root<'data, T: Managed<'scope, 'data>>(
+        &mut self,
+        ptr: NonNull<T::Wraps>,
+    ) -> T {
+        self.frame
+            .frame
+            .raw
+            .set_root(self.offset, ptr.as_ptr().cast());
+        self.offset += 1;
+        T::wrap_non_null(ptr, Private)
+    }
+}
+
+pub struct UnsizedLocalGcFrame<'scope> {
+    frame: UnsizedGcFrame<'scope>,
+    offset: usize,
+}
+
+impl<'scope> UnsizedLocalGcFrame<'scope> {
+    pub(crate) fn new(frame: UnsizedGcFrame<'scope>) -> Self {
+        UnsizedLocalGcFrame { frame, offset: 0 }
+    }
+
+    /// Returns a mutable reference to this frame.
+    #[inline]
+    pub fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Returns the number of values rooted in this frame.
+    #[inline]
+    pub fn n_roots(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of values that can be rooted in this frame.
+    #[inline]
+    pub fn frame_size(&self) -> usize {
+        self.frame.size()
+    }
+
+    /// Returns a `LocalOutput` that targets the current frame.
+    #[inline]
+    pub fn local_output(&mut self) -> LocalOutput<'scope> {
+        unsafe {
+            let slot = self.frame.get_root(self.offset);
+            self.offset += 1;
+            LocalOutput::new(slot)
+        }
+    }
+
+    /// Returns a `LocalReusableSlot` that targets the current frame.
+    #[inline]
+    pub fn local_reusable_slot(&mut self) -> LocalReusableSlot<'scope> {
+        unsafe {
+            let slot = self.frame.get_root(self.offset);
+            self.offset += 1;
+            LocalReusableSlot::new(slot)
+        }
+    }
+
+    /// Returns a `Unrooted` that targets the current frame.
+    #[inline]
+    pub const fn unrooted(&self) -> Unrooted<'scope> {
+        unsafe { Unrooted::new() }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn root<'data, T: Managed<'scope, 'data>>(
+        &mut self,
+        ptr: NonNull<T::Wraps>,
+    ) -> T {
+        self.frame.get_root(self.offset).set(ptr.as_ptr().cast());
+        self.offset += 1;
+        T::wrap_non_null(ptr, Private)
+    }
+}
+
+/// A frame that has been borrowed. A new scope must be created before it can be used as a target
+/// again.
+// TODO privacy
+pub struct BorrowedFrame<'borrow, 'current, F>(
+    pub(crate) &'borrow mut F,
+    pub(crate) PhantomData<&'current ()>,
+);
+
+impl<'borrow, 'current> BorrowedFrame<'borrow, 'current, GcFrame<'current>> {
+    /// Create a temporary scope by calling [`GcFrame::scope`].
+
+    #[inline]
+    pub fn scope<T, F>(self, func: F) -> JlrsResult<T>
+    where
+        for<'inner> F: FnOnce(GcFrame<'inner>) -> JlrsResult<T>,
+    {
+        self.0.scope(func)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'borrow, 'current> BorrowedFrame<'borrow, 'current, AsyncGcFrame<'current>> {
+    /// Create a temporary scope by calling [`GcFrame::scope`].
+
+    #[inline]
+    pub fn scope<T, F>(self, func: F) -> JlrsResult<T>
+    where
+        for<'inner> F: FnOnce(GcFrame<'inner>) -> JlrsResult<T>,
+    {
+        self.0.scope(func)
+    }
+
+    /// Create a temporary scope by calling [`AsyncGcFrame::async_scope`].
+
+    #[inline]
+    pub async fn async_scope<'nested, T, F, G>(self, func: F) -> JlrsResult<T>
+    where
+        'borrow: 'nested,
+        T: 'current,
+        G: Future<Output = JlrsResult<T>>,
+        F: FnOnce(AsyncGcFrame<'nested>) -> G,
+    {
+        self.0.async_scope(func).await
+    }
+
+    /// Create a temporary scope by calling [`AsyncGcFrame::relaxed_async_scope`].
+    #[inline]
+    pub async unsafe fn relaxed_async_scope<'nested, T, F, G>(self, func: F) -> JlrsResult<T>
+    where
+        'borrow: 'nested,
+        T: 'nested,
+        G: Future<Output = JlrsResult<T>>,
+        F: FnOnce(AsyncGcFrame<'nested>) -> G,
+    {
+        self.0.relaxed_async_scope(func).await
+    }
+}
+
+#[repr(C)]
+pub(crate) struct LocalFrame<const N: usize> {
+    raw: RawGcFrame<N>,
+}
+
+impl<const N: usize> LocalFrame<N> {
+    #[inline]
+    pub const fn new() -> Self {
+        unsafe {
+            LocalFrame {
+                raw: RawGcFrame::new(),
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn pin<'scope>(&'scope mut self) -> PinnedLocalFrame<'scope, N> {
+        PinnedLocalFrame::new(self)
+    }
+}
+
+pub(crate) struct PinnedLocalFrame<'scope, const N: usize> {
+    frame: Pin<&'scope mut LocalFrame<N>>,
+    _marker: PhantomData<&'scope mut &'scope ()>,
+}
+
+impl<'scope, const N: usize> PinnedLocalFrame<'scope, N> {
+    #[inline]
+    unsafe fn new(frame: &'scope mut LocalFrame<N>) -> Self {
+        if N > 0 {
+            frame.raw.push_frame();
+        }
+
+        PinnedLocalFrame {
+            frame: Pin::new_unchecked(frame),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn pop(&self) {
+        if N > 0 {
+            pop_frame()
+        }
+    }
+}
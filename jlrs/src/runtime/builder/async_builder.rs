@@ -1,4 +1,9 @@
-use std::{path::Path, thread, thread::JoinHandle};
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+    sync::mpsc::sync_channel,
+    thread::JoinHandle,
+};
 
 use async_channel::{bounded, unbounded};
 use jl_sys::jlrs_gc_safe_enter;
@@ -6,17 +11,20 @@ use jl_sys::jlrs_gc_safe_enter;
 #[cfg(feature = "multi-rt")]
 use crate::runtime::handle::mt_handle::MtHandle;
 use crate::{
+    async_util::cancellation_token::CancellationToken,
     error::{JlrsError, RuntimeError},
-    memory::get_tls,
+    memory::{gc::gc_unsafe, get_tls, target::unrooted::Unrooted},
     prelude::{JlrsResult, StackFrame},
     runtime::{
-        builder::{init_runtime, Builder},
-        executor::Executor,
-        handle::async_handle::{
-            cancellation_token::CancellationToken, on_main_thread, AsyncHandle,
+        builder::{
+            init_runtime, run_init_hooks, run_shutdown_hooks, set_thread_name_prefix,
+            thread_builder, AllocTrackMode, Builder, CoverageMode, EnvReport, GcTuning,
         },
+        executor::Executor,
+        handle::async_handle::{on_main_thread, AsyncHandle},
         state::{can_init, set_exit},
     },
+    util::panic_message,
     InstallJlrsCore,
 };
 
@@ -70,7 +78,16 @@ impl<E: Executor<N>, const N: usize> AsyncBuilder<E, N> {
 
     /// Set the capacity of the channel used to communicate with the async runtime.
     ///
-    /// The default value is 0, i.e. unbounded.
+    /// The default value is 0, i.e. unbounded. A bounded channel applies backpressure when tasks
+    /// are dispatched faster than the runtime can handle them. How a full channel is handled is
+    /// decided when a task is dispatched: [`Dispatch::try_dispatch`] rejects the task and returns
+    /// the dispatcher, [`Dispatch::dispatch`] waits asynchronously until there's space, and
+    /// [`Dispatch::dispatch_timeout`] blocks the current thread until there's space or the
+    /// timeout expires.
+    ///
+    /// [`Dispatch::try_dispatch`]: crate::runtime::handle::async_handle::dispatch::Dispatch::try_dispatch
+    /// [`Dispatch::dispatch`]: crate::runtime::handle::async_handle::dispatch::Dispatch::dispatch
+    /// [`Dispatch::dispatch_timeout`]: crate::runtime::handle::async_handle::dispatch::Dispatch::dispatch_timeout
     #[inline]
     pub fn channel_capacity(mut self, capacity: usize) -> Self {
         self.channel_capacity = capacity;
@@ -99,6 +116,132 @@ impl<E: Executor<N>, const N: usize> AsyncBuilder<E, N> {
         self
     }
 
+    /// Set the number of threads the GC can use for marking, equivalent to Julia's
+    /// `--gcthreads` option.
+    ///
+    /// See [`Builder::n_gc_threads`] for more information.
+    #[inline]
+    pub const fn n_gc_threads(mut self, n: usize) -> Self {
+        self.builder.gc_tuning.n_gc_threads = n;
+        self
+    }
+
+    /// Configure the GC, see [`GcTuning`] for the available settings.
+    ///
+    /// See [`Builder::gc_tuning`] for more information.
+    #[inline]
+    pub const fn gc_tuning(mut self, tuning: GcTuning) -> Self {
+        self.builder.gc_tuning = tuning;
+        self
+    }
+
+    /// Set the code coverage mode, equivalent to Julia's `--code-coverage` option.
+    ///
+    /// Coverage data is written to `.cov` files when Julia exits. The default mode is
+    /// [`CoverageMode::None`].
+    #[inline]
+    pub const fn code_coverage(mut self, mode: CoverageMode) -> Self {
+        self.builder.code_coverage = mode;
+        self
+    }
+
+    /// Set the allocation tracking mode, equivalent to Julia's `--track-allocation` option.
+    ///
+    /// Allocation data is written to `.mem` files when Julia exits. The default mode is
+    /// [`AllocTrackMode::None`].
+    #[inline]
+    pub const fn track_allocation(mut self, mode: AllocTrackMode) -> Self {
+        self.builder.track_allocation = mode;
+        self
+    }
+
+    /// Set the CPU target, equivalent to Julia's `--cpu-target` option.
+    ///
+    /// See [`Builder::cpu_target`] for more information.
+    #[inline]
+    pub fn cpu_target(mut self, target: &str) -> JlrsResult<Self> {
+        self.builder = self.builder.cpu_target(target)?;
+        Ok(self)
+    }
+
+    /// Write the signatures of compiled methods to `path`.
+    ///
+    /// See [`Builder::trace_compile`] for more information.
+    #[inline]
+    pub fn trace_compile<P: AsRef<Path>>(mut self, path: P) -> JlrsResult<Self> {
+        self.builder = self.builder.trace_compile(path)?;
+        Ok(self)
+    }
+
+    /// Write the signatures of methods that are dispatched dynamically to `path`.
+    ///
+    /// See [`Builder::trace_dispatch`] for more information.
+    #[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11")))]
+    #[inline]
+    pub fn trace_dispatch<P: AsRef<Path>>(mut self, path: P) -> JlrsResult<Self> {
+        self.builder = self.builder.trace_dispatch(path)?;
+        Ok(self)
+    }
+
+    /// Set the project Julia is started in.
+    ///
+    /// See [`Builder::with_project`] for more information.
+    #[inline]
+    pub fn with_project<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.builder = self.builder.with_project(path);
+        self
+    }
+
+    /// Set the depot path.
+    ///
+    /// See [`Builder::with_depot`] for more information.
+    #[inline]
+    pub fn with_depot<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.builder = self.builder.with_depot(path);
+        self
+    }
+
+    /// Register a closure that is called after Julia has been initialized.
+    ///
+    /// See [`Builder::add_init_hook`] for more information.
+    #[inline]
+    pub fn add_init_hook<F>(mut self, hook: F) -> Self
+    where
+        F: 'static + Send + for<'scope> FnOnce(Unrooted<'scope>) -> JlrsResult<()>,
+    {
+        self.builder = self.builder.add_init_hook(hook);
+        self
+    }
+
+    /// Register a closure that is called before Julia exits.
+    ///
+    /// See [`Builder::on_shutdown`] for more information.
+    #[inline]
+    pub fn on_shutdown<F>(mut self, hook: F) -> Self
+    where
+        F: 'static + Send + for<'scope> FnOnce(Unrooted<'scope>),
+    {
+        self.builder = self.builder.on_shutdown(hook);
+        self
+    }
+
+    /// Check the environment without initializing Julia.
+    ///
+    /// See [`Builder::validate`] for more information.
+    pub fn validate(&self) -> JlrsResult<EnvReport> {
+        self.builder.validate()
+    }
+
+    /// Redirect Julia's output to `tracing` events.
+    ///
+    /// See [`Builder::julia_output_to_tracing`] for more information.
+    #[cfg(all(feature = "tracing", unix))]
+    #[inline]
+    pub const fn julia_output_to_tracing(mut self) -> Self {
+        self.builder.output_to_tracing = true;
+        self
+    }
+
     /// Use a custom system image.
     ///
     /// You must provide two arguments to use a custom system image, `julia_bindir` and
@@ -135,6 +278,15 @@ impl<E: Executor<N>, const N: usize> AsyncBuilder<E, N> {
         Ok(self)
     }
 
+    /// Name the threads spawned by jlrs with `prefix`.
+    ///
+    /// See [`Builder::thread_name_prefix`] for more information.
+    #[inline]
+    pub fn thread_name_prefix(mut self, prefix: &str) -> JlrsResult<Self> {
+        self.builder = self.builder.thread_name_prefix(prefix)?;
+        Ok(self)
+    }
+
     /// Enable or disable automatically installing JlrsCore.
     ///
     /// jlrs requires that the JlrsCore package is installed. By default, this package is
@@ -147,7 +299,7 @@ impl<E: Executor<N>, const N: usize> AsyncBuilder<E, N> {
 }
 
 pub(crate) fn spawn_main<R: Executor<N>, const N: usize>(
-    builder: Builder,
+    mut builder: Builder,
     executor_opts: R,
     channel_capacity: usize,
 ) -> JlrsResult<(AsyncHandle, JoinHandle<()>)> {
@@ -155,6 +307,8 @@ pub(crate) fn spawn_main<R: Executor<N>, const N: usize>(
         Err(RuntimeError::AlreadyInitialized)?;
     }
 
+    builder.gc_tuning.validate(&builder)?;
+
     let token = CancellationToken::new();
     let t2 = token.clone();
     let (sender, receiver) = if channel_capacity == 0 {
@@ -163,17 +317,45 @@ pub(crate) fn spawn_main<R: Executor<N>, const N: usize>(
         bounded(channel_capacity)
     };
 
-    let thread_handle = std::thread::spawn(move || unsafe {
-        init_runtime(&builder);
+    // The result of the init hooks is sent back so it can be returned before the handle is.
+    let (init_sender, init_receiver) = sync_channel(1);
 
-        let ptls = get_tls();
-        jlrs_gc_safe_enter(ptls);
+    set_thread_name_prefix(&builder);
+    let thread_handle = thread_builder("runtime")
+        .spawn(move || unsafe {
+            if let Err(e) = init_runtime(&builder) {
+                init_sender.send(Err(e)).ok();
+                return;
+            }
 
-        let mut base_frame = StackFrame::<N>::new_n();
-        executor_opts.block_on(on_main_thread::<R, N>(receiver, token, &mut base_frame));
+            // A panic is caught so its message can be returned, Julia has already been shut down
+            // by run_init_hooks.
+            let init_res = catch_unwind(AssertUnwindSafe(|| run_init_hooks(&mut builder)))
+                .unwrap_or_else(|payload| {
+                    let msg = panic_message(payload.as_ref());
+                    Err(JlrsError::exception(format!("init hook panicked: {msg}")))?
+                });
+            let init_failed = init_res.is_err();
+            init_sender.send(init_res).ok();
+            if init_failed {
+                return;
+            }
 
-        set_exit();
-    });
+            let ptls = get_tls();
+            jlrs_gc_safe_enter(ptls);
+
+            let mut base_frame = StackFrame::<N>::new_n();
+            executor_opts.block_on(on_main_thread::<R, N>(receiver, token, &mut base_frame));
+
+            gc_unsafe(|_| run_shutdown_hooks());
+            set_exit();
+        })
+        .map_err(JlrsError::other)?;
+
+    match init_receiver.recv() {
+        Ok(res) => res?,
+        Err(_) => Err(JlrsError::exception("runtime thread panicked"))?,
+    }
 
     unsafe {
         let handle = AsyncHandle::new_main(sender, t2);
@@ -182,7 +364,7 @@ pub(crate) fn spawn_main<R: Executor<N>, const N: usize>(
 }
 
 pub(crate) fn run_main<T: 'static + Send, R: Executor<N>, const N: usize>(
-    builder: Builder,
+    mut builder: Builder,
     executor_opts: R,
     channel_capacity: usize,
     func: impl 'static + Send + FnOnce(AsyncHandle) -> T,
@@ -191,8 +373,11 @@ pub(crate) fn run_main<T: 'static + Send, R: Executor<N>, const N: usize>(
         Err(RuntimeError::AlreadyInitialized)?;
     }
 
+    builder.gc_tuning.validate(&builder)?;
+
     unsafe {
-        init_runtime(&builder);
+        init_runtime(&builder)?;
+        run_init_hooks(&mut builder)?;
 
         let token = CancellationToken::new();
         let t2 = token.clone();
@@ -207,11 +392,14 @@ pub(crate) fn run_main<T: 'static + Send, R: Executor<N>, const N: usize>(
         let ptls = get_tls();
         jlrs_gc_safe_enter(ptls);
 
-        let handle = thread::spawn(move || func(handle));
+        let handle = thread_builder("main")
+            .spawn(move || func(handle))
+            .map_err(JlrsError::other)?;
 
         let mut base_frame = StackFrame::<N>::new_n();
         executor_opts.block_on(on_main_thread::<R, N>(receiver, token, &mut base_frame));
 
+        gc_unsafe(|_| run_shutdown_hooks());
         set_exit();
 
         handle
@@ -229,17 +417,16 @@ mod mt_impl {
 
     use jl_sys::jl_atexit_hook;
 
-    use super::super::{init_runtime, Builder};
+    use super::super::{init_runtime, run_init_hooks, run_shutdown_hooks, thread_builder, Builder};
     use crate::{
+        async_util::cancellation_token::CancellationToken,
         error::{JlrsError, RuntimeError},
         memory::gc::gc_safe,
         prelude::{AsyncHandle, JlrsResult, StackFrame},
         runtime::{
             executor::Executor,
             handle::{
-                async_handle::{
-                    cancellation_token::CancellationToken, channel::channel, on_main_thread,
-                },
+                async_handle::{channel::channel, on_main_thread},
                 mt_handle::{wait_loop, MtHandle, EXIT_LOCK},
                 wait,
             },
@@ -248,7 +435,7 @@ mod mt_impl {
     };
 
     pub(crate) fn run_main_mt<'env, T, E, F, const N: usize>(
-        options: Builder,
+        mut options: Builder,
         executor_opts: E,
         channel_capacity: usize,
         func: F,
@@ -262,23 +449,28 @@ mod mt_impl {
             Err(RuntimeError::AlreadyInitialized)?;
         }
 
+        options.gc_tuning.validate(&options)?;
+
         let token = CancellationToken::new();
         let t2 = token.clone();
         let (sender, receiver) = channel(channel_capacity);
 
         unsafe {
-            init_runtime(&options);
+            init_runtime(&options)?;
+            run_init_hooks(&mut options)?;
         }
 
         let async_handle = unsafe { AsyncHandle::new_main(sender, t2) };
 
         let ret = thread::scope(|scope| {
-            let handle = scope.spawn(|| unsafe {
-                thread::scope(|scope| {
-                    let handle = MtHandle::new(scope);
-                    func(handle, async_handle)
+            let handle = thread_builder("main")
+                .spawn_scoped(scope, || unsafe {
+                    thread::scope(|scope| {
+                        let handle = MtHandle::new(scope);
+                        func(handle, async_handle)
+                    })
                 })
-            });
+                .expect("failed to spawn thread");
 
             unsafe {
                 let mut base_frame = StackFrame::<N>::new_n();
@@ -297,6 +489,7 @@ mod mt_impl {
                         // Returned from wait_main, so we're about to exit Julia becuase all handles have
                         // been dropped. Next we need to wait until we've returned from `notify_main` too.
                         gc_safe(|| wait(&EXIT_LOCK));
+                        run_shutdown_hooks();
                         set_exit();
                         jl_atexit_hook(0);
                     }
@@ -0,0 +1,99 @@
+//! Configure the GC.
+
+use jl_sys::{jlrs_set_heap_size_hint, jlrs_set_nmarkthreads, jlrs_set_nsweepthreads};
+
+use super::Builder;
+use crate::error::{JlrsResult, RuntimeError};
+
+/// GC settings, set with [`Builder::gc_tuning`].
+///
+/// Julia's GC is configured with several command line options which interact with each other
+/// and with the number of threads Julia is started with. This struct groups these settings so
+/// they can be validated together when the runtime is started. The defaults leave every setting
+/// to Julia.
+///
+/// Julia's allocator can't be replaced, and the supported versions of Julia don't have an
+/// incremental GC that can be toggled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GcTuning {
+    pub(crate) heap_size_hint: u64,
+    pub(crate) n_gc_threads: usize,
+    pub(crate) concurrent_sweep: bool,
+}
+
+impl GcTuning {
+    /// Create new GC settings that leave every setting to Julia.
+    #[inline]
+    pub const fn new() -> Self {
+        GcTuning {
+            heap_size_hint: 0,
+            n_gc_threads: 0,
+            concurrent_sweep: false,
+        }
+    }
+
+    /// Set the heap size in bytes at which the GC becomes more aggressive, equivalent to Julia's
+    /// `--heap-size-hint` option.
+    ///
+    /// If it's set to 0, the default value, Julia derives the hint from the amount of available
+    /// memory.
+    #[inline]
+    pub const fn heap_size_hint(mut self, bytes: u64) -> Self {
+        self.heap_size_hint = bytes;
+        self
+    }
+
+    /// Set the number of threads the GC can use for marking, equivalent to the first value of
+    /// Julia's `--gcthreads` option.
+    ///
+    /// See [`Builder::n_gc_threads`] for more information.
+    #[inline]
+    pub const fn n_gc_threads(mut self, n: usize) -> Self {
+        self.n_gc_threads = n;
+        self
+    }
+
+    /// Enable or disable concurrent sweeping, equivalent to the second value of Julia's
+    /// `--gcthreads` option.
+    ///
+    /// If it's enabled, an additional thread is used to sweep pages concurrently. It's disabled
+    /// by default.
+    #[inline]
+    pub const fn concurrent_sweep(mut self, enable: bool) -> Self {
+        self.concurrent_sweep = enable;
+        self
+    }
+
+    // Check that these settings are consistent with the other options of `builder`.
+    pub(crate) fn validate(&self, builder: &Builder) -> JlrsResult<()> {
+        // If the number of threads is 0 it's the number of CPU cores, which is checked by Julia.
+        if builder.n_threads != 0 {
+            let n_threads = builder.n_threads + builder.n_threadsi;
+            if self.n_gc_threads > n_threads {
+                Err(RuntimeError::InvalidGcTuning {
+                    msg: format!(
+                        "{} GC threads exceeds the total number of threads ({})",
+                        self.n_gc_threads, n_threads
+                    ),
+                })?
+            }
+        }
+
+        Ok(())
+    }
+
+    // Must be called before Julia is initialized.
+    pub(crate) unsafe fn apply(&self) {
+        if self.n_gc_threads != 0 {
+            jlrs_set_nmarkthreads(self.n_gc_threads.min(i16::MAX as usize) as i16);
+        }
+
+        if self.concurrent_sweep {
+            jlrs_set_nsweepthreads(1);
+        }
+
+        if self.heap_size_hint != 0 {
+            jlrs_set_heap_size_hint(self.heap_size_hint);
+        }
+    }
+}
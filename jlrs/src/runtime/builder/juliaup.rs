@@ -0,0 +1,95 @@
+//! Resolve the installation directory of a juliaup channel.
+//!
+//! juliaup stores the installed versions and channels in `juliaup.json`, which is found in the
+//! `juliaup` directory of the first depot. Installed channels refer to an installed version,
+//! whose path is relative to the `juliaup` directory. Linked channels refer to a `julia`
+//! executable directly.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value as JsonValue;
+
+use crate::error::{IOError, JlrsError, JlrsResult, RuntimeError};
+
+#[cfg(target_os = "windows")]
+const SYSIMAGE: &str = "sys.dll";
+#[cfg(target_os = "macos")]
+const SYSIMAGE: &str = "sys.dylib";
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const SYSIMAGE: &str = "sys.so";
+
+/// Returns the bin directory and the path to the system image of the Julia version installed for
+/// `channel`.
+pub(super) fn resolve_channel(channel: &str) -> JlrsResult<(PathBuf, PathBuf)> {
+    let juliaup_dir = juliaup_dir()?;
+    let config_path = juliaup_dir.join("juliaup.json");
+    let config = fs::read_to_string(&config_path).map_err(|_| IOError::NotFound {
+        path: config_path.to_string_lossy().into(),
+    })?;
+    let config: JsonValue = serde_json::from_str(&config).map_err(JlrsError::other)?;
+
+    let channel_not_found = || RuntimeError::JuliaupChannelNotFound {
+        channel: channel.into(),
+    };
+
+    let channel_config = config
+        .get("InstalledChannels")
+        .and_then(|channels| channels.get(channel))
+        .ok_or_else(channel_not_found)?;
+
+    let bin_dir = if let Some(command) = channel_config.get("Command").and_then(JsonValue::as_str) {
+        // Linked channel, the command is the path to a julia executable.
+        Path::new(command)
+            .parent()
+            .ok_or_else(channel_not_found)?
+            .to_path_buf()
+    } else {
+        let version = channel_config
+            .get("Version")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(channel_not_found)?;
+
+        let install_path = config
+            .get("InstalledVersions")
+            .and_then(|versions| versions.get(version))
+            .and_then(|version| version.get("Path"))
+            .and_then(JsonValue::as_str)
+            .ok_or_else(channel_not_found)?;
+
+        juliaup_dir.join(install_path).join("bin")
+    };
+
+    let image_path = bin_dir.join("..").join("lib").join("julia").join(SYSIMAGE);
+    for path in [&bin_dir, &image_path] {
+        if !path.exists() {
+            Err(IOError::NotFound {
+                path: path.to_string_lossy().into(),
+            })?;
+        }
+    }
+
+    Ok((bin_dir, image_path))
+}
+
+fn juliaup_dir() -> JlrsResult<PathBuf> {
+    if let Some(depot) = env::var_os("JULIAUP_DEPOT_PATH").filter(|path| !path.is_empty()) {
+        return Ok(PathBuf::from(depot).join("juliaup"));
+    }
+
+    if let Some(depots) = env::var_os("JULIA_DEPOT_PATH") {
+        if let Some(depot) = env::split_paths(&depots).find(|path| !path.as_os_str().is_empty()) {
+            return Ok(depot.join("juliaup"));
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or_else(|| IOError::NotFound {
+            path: "~/.julia/juliaup".into(),
+        })?;
+
+    Ok(PathBuf::from(home).join(".julia").join("juliaup"))
+}
@@ -7,28 +7,81 @@
 
 #[cfg(feature = "async-rt")]
 pub mod async_builder;
+mod gc_tuning;
+#[cfg(feature = "juliaup")]
+mod juliaup;
+#[cfg(all(feature = "tracing", unix))]
+mod output;
+mod validate;
 
 use std::{
     ffi::CString,
+    fmt::Display,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
     path::{Path, PathBuf},
+    sync::OnceLock,
+    thread,
 };
 
 #[cfg(feature = "async-rt")]
 pub use async_builder::*;
 use jl_sys::{
-    jl_init, jl_init_with_image, jlrs_set_nthreadpools, jlrs_set_nthreads,
-    jlrs_set_nthreads_per_pool,
+    jl_atexit_hook, jl_init, jl_init_with_image, jlrs_set_code_coverage, jlrs_set_cpu_target,
+    jlrs_set_malloc_log, jlrs_set_nthreadpools, jlrs_set_nthreads, jlrs_set_nthreads_per_pool,
+    jlrs_set_trace_compile,
 };
+use parking_lot::Mutex;
 
-#[cfg(any(feature = "multi-rt", feature = "local-rt"))]
-use crate::error::JlrsResult;
+pub use self::{gc_tuning::GcTuning, validate::EnvReport};
 #[cfg(feature = "async-rt")]
 use crate::runtime::executor::Executor;
 #[cfg(feature = "multi-rt")]
 use crate::runtime::handle::mt_handle::MtHandle;
+use crate::runtime::state::set_exit;
 #[cfg(feature = "local-rt")]
 use crate::runtime::{handle::local_handle::LocalHandle, sync_rt::PendingJulia};
-use crate::{init_jlrs, InstallJlrsCore};
+use crate::{
+    data::managed::value::Value,
+    error::{JlrsResult, RuntimeError},
+    init_jlrs,
+    memory::target::unrooted::Unrooted,
+    InstallJlrsCore,
+};
+
+/// A closure registered with [`Builder::add_init_hook`].
+pub(crate) type InitHook = Box<dyn for<'scope> FnOnce(Unrooted<'scope>) -> JlrsResult<()> + Send>;
+
+/// A closure registered with [`Builder::on_shutdown`].
+pub(crate) type ShutdownHook = Box<dyn for<'scope> FnOnce(Unrooted<'scope>) + Send>;
+
+// The shutdown hooks of the running runtime, they're moved here by `run_init_hooks`.
+static SHUTDOWN_HOOKS: Mutex<Vec<ShutdownHook>> = Mutex::new(Vec::new());
+
+/// Code coverage mode, mirrors Julia's `--code-coverage` command line option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(i8)]
+pub enum CoverageMode {
+    /// Don't track code coverage.
+    #[default]
+    None = 0,
+    /// Track code coverage of all code except Base and the standard library.
+    User = 1,
+    /// Track code coverage of all code.
+    All = 2,
+}
+
+/// Allocation tracking mode, mirrors Julia's `--track-allocation` command line option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(i8)]
+pub enum AllocTrackMode {
+    /// Don't track allocations.
+    #[default]
+    None = 0,
+    /// Track allocations in all code except Base and the standard library.
+    User = 1,
+    /// Track allocations in all code.
+    All = 2,
+}
 
 /// Build a runtime.
 ///
@@ -41,22 +94,71 @@ pub struct Builder {
     pub(crate) install_jlrs_core: InstallJlrsCore,
     pub(crate) n_threads: usize,
     pub(crate) n_threadsi: usize,
+    pub(crate) gc_tuning: GcTuning,
+    pub(crate) code_coverage: CoverageMode,
+    pub(crate) track_allocation: AllocTrackMode,
+    #[cfg(all(feature = "tracing", unix))]
+    pub(crate) output_to_tracing: bool,
+    pub(crate) thread_name_prefix: Option<String>,
+    pub(crate) cpu_target: Option<String>,
+    pub(crate) trace_compile: Option<PathBuf>,
+    #[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11")))]
+    pub(crate) trace_dispatch: Option<PathBuf>,
+    pub(crate) project: Option<PathBuf>,
+    pub(crate) depot: Option<PathBuf>,
+    pub(crate) init_hooks: Vec<InitHook>,
+    pub(crate) shutdown_hooks: Vec<ShutdownHook>,
 }
 
 impl Builder {
     /// Create a new builder.
     ///
     /// The default options are: no custom system image, install JlrsCore if it is unavailable,
-    /// and don't start any additional threads.
+    /// don't start any additional threads, and don't track code coverage or allocations.
     pub const fn new() -> Self {
         Builder {
             image: None,
             install_jlrs_core: InstallJlrsCore::Default,
             n_threads: 0,
             n_threadsi: 0,
+            gc_tuning: GcTuning::new(),
+            code_coverage: CoverageMode::None,
+            track_allocation: AllocTrackMode::None,
+            #[cfg(all(feature = "tracing", unix))]
+            output_to_tracing: false,
+            thread_name_prefix: None,
+            cpu_target: None,
+            trace_compile: None,
+            #[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11")))]
+            trace_dispatch: None,
+            project: None,
+            depot: None,
+            init_hooks: Vec::new(),
+            shutdown_hooks: Vec::new(),
         }
     }
 
+    /// Create a new builder that uses the Julia version installed for the juliaup `channel`.
+    ///
+    /// The installation directory of the channel is resolved by reading the juliaup
+    /// configuration, which is found in the first depot of `JULIAUP_DEPOT_PATH`,
+    /// `JULIA_DEPOT_PATH` or `~/.julia`. Julia is initialized with the bin directory and default
+    /// system image of that installation, an error is returned if the channel isn't installed or
+    /// either path doesn't exist.
+    ///
+    /// The Julia library jlrs is linked with is still loaded by the dynamic linker, so the
+    /// library path must point to the `lib` directory of the same installation. You can use
+    /// [`Builder::validate`] to check the version of the loaded library.
+    ///
+    /// This method is only available if the `juliaup` feature is enabled.
+    #[cfg(feature = "juliaup")]
+    pub fn from_juliaup_channel(channel: &str) -> JlrsResult<Self> {
+        let image = juliaup::resolve_channel(channel)?;
+        let mut builder = Builder::new();
+        builder.image = Some(image);
+        Ok(builder)
+    }
+
     #[cfg(feature = "local-rt")]
     #[inline]
     #[deprecated]
@@ -70,15 +172,18 @@ impl Builder {
     #[cfg(feature = "local-rt")]
     #[inline]
     /// initialize Julia on the current thread.
-    pub fn start_local(self) -> JlrsResult<LocalHandle> {
-        use crate::{error::RuntimeError, runtime::state::can_init};
+    pub fn start_local(mut self) -> JlrsResult<LocalHandle> {
+        use crate::runtime::state::can_init;
 
         if !can_init() {
             Err(RuntimeError::AlreadyInitialized)?;
         }
 
+        self.gc_tuning.validate(&self)?;
+
         unsafe {
-            init_runtime(&self);
+            init_runtime(&self)?;
+            run_init_hooks(&mut self)?;
             Ok(LocalHandle::new())
         }
     }
@@ -92,6 +197,20 @@ impl Builder {
         mt_impl::sync_impl::start(self, func)
     }
 
+    /// Check the environment without initializing Julia.
+    ///
+    /// Julia can only be initialized once, and failing to do so can abort the process. This
+    /// method checks if the version of the loaded Julia library matches the version jlrs has been
+    /// built for, if the custom system image exists, and if the depot path and project are
+    /// accessible. These are read from `JULIA_DEPOT_PATH` and `JULIA_PROJECT` unless they have
+    /// been set with [`Builder::with_depot`] and [`Builder::with_project`]. The findings are
+    /// returned as an [`EnvReport`].
+    ///
+    /// Returns an error if Julia has already been initialized.
+    pub fn validate(&self) -> JlrsResult<EnvReport> {
+        validate::validate(self)
+    }
+
     /// Set the number of threads Julia can use.
     ///
     /// If it's set to 0, the default value, the number of threads is the number of CPU
@@ -114,6 +233,193 @@ impl Builder {
         self
     }
 
+    /// Set the number of threads the GC can use for marking, equivalent to Julia's
+    /// `--gcthreads` option.
+    ///
+    /// Like the command line option, this number includes the thread that triggers a
+    /// collection, so `n` must be at least 1 to take effect. If it's set to 0, the default
+    /// value, the number of GC threads is read from the `JULIA_NUM_GC_THREADS` environment
+    /// variable if it's set, otherwise it's half the number of threads set with
+    /// [`Builder::n_threads`].
+    ///
+    /// This overrides the number of GC threads set with [`Builder::gc_tuning`].
+    #[inline]
+    pub const fn n_gc_threads(mut self, n: usize) -> Self {
+        self.gc_tuning.n_gc_threads = n;
+        self
+    }
+
+    /// Configure the GC, see [`GcTuning`] for the available settings.
+    ///
+    /// This replaces the number of GC threads set with [`Builder::n_gc_threads`]. The settings
+    /// are validated when the runtime is started, an error is returned if they're inconsistent
+    /// with the other options, e.g. if the number of GC threads exceeds the total number of
+    /// threads set with [`Builder::n_threads`] and [`Builder::n_interactive_threads`].
+    #[inline]
+    pub const fn gc_tuning(mut self, tuning: GcTuning) -> Self {
+        self.gc_tuning = tuning;
+        self
+    }
+
+    /// Set the code coverage mode, equivalent to Julia's `--code-coverage` option.
+    ///
+    /// Coverage data is written to `.cov` files when Julia exits. The default mode is
+    /// [`CoverageMode::None`].
+    #[inline]
+    pub const fn code_coverage(mut self, mode: CoverageMode) -> Self {
+        self.code_coverage = mode;
+        self
+    }
+
+    /// Set the allocation tracking mode, equivalent to Julia's `--track-allocation` option.
+    ///
+    /// Allocation data is written to `.mem` files when Julia exits. The default mode is
+    /// [`AllocTrackMode::None`].
+    #[inline]
+    pub const fn track_allocation(mut self, mode: AllocTrackMode) -> Self {
+        self.track_allocation = mode;
+        self
+    }
+
+    /// Set the CPU target, equivalent to Julia's `--cpu-target` option.
+    ///
+    /// The target uses the same syntax as the `JULIA_CPU_TARGET` environment variable, e.g.
+    /// `"generic;haswell,clone_all"`. This should match the targets the system image has been
+    /// built for. By default, the CPU target is the native target of the host unless
+    /// `JULIA_CPU_TARGET` is set. An error is returned if `target` contains a null character.
+    #[inline]
+    pub fn cpu_target(mut self, target: &str) -> JlrsResult<Self> {
+        if target.contains('\0') {
+            Err(RuntimeError::InvalidCpuTarget {
+                target: target.to_string(),
+            })?
+        }
+
+        self.cpu_target = Some(target.to_string());
+        Ok(self)
+    }
+
+    /// Write the signatures of compiled methods to `path`, equivalent to Julia's
+    /// `--trace-compile` option.
+    ///
+    /// A `precompile` statement is written to the file for every method that is compiled during
+    /// the session. These statements can be used to create a system image with
+    /// [`PackageCompiler`] or be included to precompile the workload of an application before it
+    /// runs. If `path` is `stderr`, the statements are written to stderr instead. An error is
+    /// returned if `path` contains a null character.
+    ///
+    /// [`PackageCompiler`]: https://julialang.github.io/PackageCompiler.jl
+    #[inline]
+    pub fn trace_compile<P: AsRef<Path>>(mut self, path: P) -> JlrsResult<Self> {
+        self.trace_compile = Some(trace_path(path.as_ref())?);
+        Ok(self)
+    }
+
+    /// Write the signatures of methods that are dispatched dynamically to `path`, equivalent to
+    /// Julia's `--trace-dispatch` option.
+    ///
+    /// A `precompile` statement is written to the file for every method that is called with
+    /// dynamic dispatch during the session. If `path` is `stderr`, the statements are written to
+    /// stderr instead. An error is returned if `path` contains a null character.
+    ///
+    /// This method is only available when Julia 1.12 or later is used.
+    #[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11")))]
+    #[inline]
+    pub fn trace_dispatch<P: AsRef<Path>>(mut self, path: P) -> JlrsResult<Self> {
+        self.trace_dispatch = Some(trace_path(path.as_ref())?);
+        Ok(self)
+    }
+
+    /// Set the project Julia is started in, equivalent to Julia's `--project` option.
+    ///
+    /// The path can be the path to a project directory or a `Project.toml` file, or a special
+    /// value like `@.`, which searches the current directory and its parents for a project, and
+    /// named environments like `@v1.11`. The project is activated right after Julia has been
+    /// initialized and overrides `JULIA_PROJECT`, the environment variable itself is not
+    /// changed. If JlrsCore is installed automatically, it's installed in this project if it
+    /// can't be loaded. If the project can't be activated, Julia is shut down and starting the
+    /// runtime fails with `RuntimeError::InvalidProject`.
+    #[inline]
+    pub fn with_project<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.project = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the depot path, equivalent to setting the `JULIA_DEPOT_PATH` environment variable.
+    ///
+    /// Julia looks for installed packages, registries and precompiled code in the depots of the
+    /// depot path. Multiple depots can be provided by separating them with the platform's path
+    /// separator, i.e. `:` on Unix and `;` on Windows, an empty entry is replaced with the
+    /// default depots. The depot path is updated right after Julia has been initialized and
+    /// overrides `JULIA_DEPOT_PATH`, the environment variable itself is not changed. If the depot
+    /// path can't be updated, Julia is shut down and starting the runtime fails with
+    /// `RuntimeError::InvalidDepot`.
+    #[inline]
+    pub fn with_depot<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.depot = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Register a closure that is called after Julia has been initialized.
+    ///
+    /// This method can be called multiple times, the hooks are called in the order they have
+    /// been registered. They're called on the thread that initializes Julia after JlrsCore has
+    /// been loaded, before the handle to the runtime is returned, so they can be used to load
+    /// packages or define helper functions that are needed by the rest of the application.
+    /// The [`Unrooted`] target can be used to create a local scope.
+    ///
+    /// If a hook returns an error or panics, the remaining hooks are not called and Julia is shut
+    /// down before starting the runtime fails, see [`Builder::on_shutdown`] for the details. Julia
+    /// can't be initialized again in this case. Hooks are not called by the deprecated
+    /// [`Builder::start`].
+    #[inline]
+    pub fn add_init_hook<F>(mut self, hook: F) -> Self
+    where
+        F: 'static + Send + for<'scope> FnOnce(Unrooted<'scope>) -> JlrsResult<()>,
+    {
+        self.init_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Register a closure that is called when the runtime shuts down.
+    ///
+    /// This method can be called multiple times, the hooks are called in the order they have
+    /// been registered. They're called on the thread that initialized Julia while Julia is still
+    /// fully functional, just before `jl_atexit_hook` is called. This gives the application a
+    /// deterministic point to flush buffers or close resources owned by Julia. The [`Unrooted`]
+    /// target can be used to create a local scope.
+    ///
+    /// The local runtime calls them when the [`LocalHandle`] is dropped, the multithreaded
+    /// runtime after all handles have been dropped, and the async runtime after the executor has
+    /// stopped. If a hook registered with [`Builder::add_init_hook`] returns an error or panics,
+    /// they're called before Julia is shut down and starting the runtime fails. If starting the
+    /// runtime fails for another reason, e.g. because the options are invalid, they're not
+    /// called. They're never called by the deprecated [`Builder::start`].
+    #[inline]
+    pub fn on_shutdown<F>(mut self, hook: F) -> Self
+    where
+        F: 'static + Send + for<'scope> FnOnce(Unrooted<'scope>),
+    {
+        self.shutdown_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Redirect Julia's output to `tracing` events.
+    ///
+    /// Every line written to stdout is emitted as an `INFO` event, every line written to stderr
+    /// as a `WARN` event. Both events have the target `julia`. Only `Base.stdout` and
+    /// `Base.stderr` are redirected, the file descriptors of the process are left untouched.
+    /// Output from Rust, and output that Julia's runtime writes directly to the file
+    /// descriptors, is not redirected.
+    ///
+    /// This method is only available on Unix if the `tracing` feature is enabled.
+    #[cfg(all(feature = "tracing", unix))]
+    #[inline]
+    pub const fn julia_output_to_tracing(mut self) -> Self {
+        self.output_to_tracing = true;
+        self
+    }
+
     /// Use a custom system image.
     ///
     /// You must provide two arguments to use a custom system image, `julia_bindir` and
@@ -160,6 +466,26 @@ impl Builder {
         self
     }
 
+    /// Name the threads spawned by jlrs with `prefix`.
+    ///
+    /// The async runtime thread is named `{prefix}-runtime`, the thread that runs the closure
+    /// passed to `start_mt` is named `{prefix}-main`, and threads spawned with
+    /// [`MtHandle::spawn`] are named `{prefix}-0`, `{prefix}-1`, etc. Workers of a thread pool
+    /// that don't have a prefix of their own are named `{prefix}-worker-{pool}-{worker}`. By
+    /// default, these threads are unnamed except for the workers of a thread pool, which use the
+    /// prefix `jlrs`. An error is returned if `prefix` contains a null character.
+    #[inline]
+    pub fn thread_name_prefix(mut self, prefix: &str) -> JlrsResult<Self> {
+        if prefix.contains('\0') {
+            Err(RuntimeError::InvalidThreadNamePrefix {
+                prefix: prefix.to_string(),
+            })?
+        }
+
+        self.thread_name_prefix = Some(prefix.to_string());
+        Ok(self)
+    }
+
     /// Upgrade this builder to an [`AsyncBuilder`].
     ///
     /// You must provide an executor, jlrs supports using tokio if the `tokio-rt` feature is
@@ -200,7 +526,9 @@ mod mt_impl {
             memory::gc::gc_safe,
             prelude::JlrsResult,
             runtime::{
-                builder::{init_runtime, Builder},
+                builder::{
+                    init_runtime, run_init_hooks, run_shutdown_hooks, thread_builder, Builder,
+                },
                 handle::{
                     mt_handle::{wait_loop, MtHandle, EXIT_LOCK},
                     wait,
@@ -209,7 +537,7 @@ mod mt_impl {
             },
         };
 
-        pub(crate) fn start<'env, T, F>(options: Builder, func: F) -> JlrsResult<T>
+        pub(crate) fn start<'env, T, F>(mut options: Builder, func: F) -> JlrsResult<T>
         where
             T: Send + 'static,
             F: 'env + for<'scope> FnOnce(MtHandle<'scope, 'env>) -> T + Send,
@@ -218,17 +546,22 @@ mod mt_impl {
                 Err(RuntimeError::AlreadyInitialized)?;
             }
 
+            options.gc_tuning.validate(&options)?;
+
             unsafe {
-                init_runtime(&options);
+                init_runtime(&options)?;
+                run_init_hooks(&mut options)?;
             }
 
             let ret = thread::scope(|scope| {
-                let handle = scope.spawn(|| unsafe {
-                    thread::scope(|scope| {
-                        let handle = MtHandle::new(scope);
-                        func(handle)
+                let handle = thread_builder("main")
+                    .spawn_scoped(scope, || unsafe {
+                        thread::scope(|scope| {
+                            let handle = MtHandle::new(scope);
+                            func(handle)
+                        })
                     })
-                });
+                    .expect("failed to spawn thread");
 
                 unsafe {
                     wait_loop();
@@ -236,6 +569,7 @@ mod mt_impl {
                     // Returned from wait_main, so we're about to exit Julia becuase all handles have
                     // been dropped. Next we need to wait until we've returned from `notify_main` too.
                     gc_safe(|| wait(&EXIT_LOCK));
+                    run_shutdown_hooks();
                     set_exit();
                     jl_atexit_hook(0);
 
@@ -251,10 +585,158 @@ mod mt_impl {
     }
 }
 
-unsafe fn init_runtime(options: &Builder) {
+static THREAD_NAME_PREFIX: OnceLock<String> = OnceLock::new();
+
+/// Returns the thread name prefix set with [`Builder::thread_name_prefix`].
+#[cfg_attr(not(all(feature = "async", feature = "multi-rt")), allow(dead_code))]
+pub(crate) fn thread_name_prefix() -> Option<&'static str> {
+    THREAD_NAME_PREFIX.get().map(String::as_str)
+}
+
+/// Returns a thread builder that names the thread `{prefix}-{suffix}` if a thread name prefix
+/// has been set.
+#[cfg_attr(not(any(feature = "async-rt", feature = "multi-rt")), allow(dead_code))]
+pub(crate) fn thread_builder(suffix: impl Display) -> thread::Builder {
+    let builder = thread::Builder::new();
+    match thread_name_prefix() {
+        Some(prefix) => builder.name(format!("{prefix}-{suffix}")),
+        None => builder,
+    }
+}
+
+fn set_thread_name_prefix(options: &Builder) {
+    if let Some(prefix) = options.thread_name_prefix.as_ref() {
+        let _ = THREAD_NAME_PREFIX.set(prefix.clone());
+    }
+}
+
+unsafe fn init_runtime(options: &Builder) -> JlrsResult<()> {
+    set_thread_name_prefix(options);
     set_n_threads(options);
+    options.gc_tuning.apply();
+    set_profiling_options(options);
+    set_cpu_target(options);
+    set_trace_options(options);
     init_julia(options);
+    if let Err(e) = set_environment(options) {
+        // No shutdown hooks have been registered yet.
+        set_exit();
+        jl_atexit_hook(1);
+        return Err(e);
+    }
     init_jlrs(&options.install_jlrs_core);
+    #[cfg(all(feature = "tracing", unix))]
+    if options.output_to_tracing {
+        output::redirect_output_to_tracing();
+    }
+
+    Ok(())
+}
+
+// Calls the hooks registered with `Builder::add_init_hook` in registration order, must be called
+// after `init_runtime` on the same thread. The shutdown hooks are stored until
+// `run_shutdown_hooks` is called.
+//
+// If a hook returns an error or panics, the runtime is shut down before the error is returned or
+// the panic is resumed: the shutdown hooks are called and Julia exits. Julia can't be initialized
+// again afterwards.
+pub(crate) unsafe fn run_init_hooks(options: &mut Builder) -> JlrsResult<()> {
+    SHUTDOWN_HOOKS.lock().append(&mut options.shutdown_hooks);
+
+    let hooks = std::mem::take(&mut options.init_hooks);
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        for hook in hooks {
+            hook(Unrooted::new())?;
+        }
+
+        JlrsResult::Ok(())
+    }));
+
+    match res {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => {
+            exit_after_failed_init();
+            Err(e)
+        }
+        Err(payload) => {
+            exit_after_failed_init();
+            resume_unwind(payload)
+        }
+    }
+}
+
+unsafe fn exit_after_failed_init() {
+    run_shutdown_hooks();
+    set_exit();
+    jl_atexit_hook(1);
+}
+
+// Calls the hooks registered with `Builder::on_shutdown` in registration order, must be called
+// from the thread that initialized Julia before `jl_atexit_hook` is called.
+#[cfg_attr(
+    not(any(feature = "local-rt", feature = "async-rt", feature = "multi-rt")),
+    allow(dead_code)
+)]
+pub(crate) unsafe fn run_shutdown_hooks() {
+    let hooks = std::mem::take(&mut *SHUTDOWN_HOOKS.lock());
+    for hook in hooks {
+        hook(Unrooted::new());
+    }
+}
+
+// Updates the active project and depot path the same way Julia does when it reads
+// `JULIA_PROJECT` and `JULIA_DEPOT_PATH` during initialization. This must be called after Julia
+// has been initialized but before any package has been loaded. The environment of the process is
+// left untouched.
+unsafe fn set_environment(options: &Builder) -> JlrsResult<()> {
+    if let Some(path) = options.project.as_ref() {
+        let project = julia_string_literal(path);
+        let cmd = format!(
+            "let p = {project}
+                Base.ACTIVE_PROJECT[] = startswith(p, \"@\") ? Base.load_path_expand(p) : abspath(expanduser(p))
+            end"
+        );
+
+        if Value::eval_string(Unrooted::new(), cmd).is_err() {
+            Err(RuntimeError::InvalidProject {
+                project: path.display().to_string(),
+            })?
+        }
+    }
+
+    if let Some(path) = options.depot.as_ref() {
+        // Empty entries are replaced with the default depots.
+        let depot = julia_string_literal(path);
+        let cmd = format!(
+            "let defaults = copy(Base.DEPOT_PATH)
+                empty!(Base.DEPOT_PATH)
+                for d in split({depot}, Sys.iswindows() ? ';' : ':')
+                    isempty(d) ? append!(Base.DEPOT_PATH, defaults) : push!(Base.DEPOT_PATH, expanduser(d))
+                end
+            end"
+        );
+
+        if Value::eval_string(Unrooted::new(), cmd).is_err() {
+            Err(RuntimeError::InvalidDepot {
+                depot: path.display().to_string(),
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+// Converts a path to a Julia string literal.
+fn julia_string_literal(path: &Path) -> String {
+    let mut literal = String::from("\"");
+    for c in path.to_string_lossy().chars() {
+        if matches!(c, '"' | '\\' | '$') {
+            literal.push('\\');
+        }
+        literal.push(c);
+    }
+    literal.push('"');
+    literal
 }
 
 unsafe fn init_julia(options: &Builder) {
@@ -299,3 +781,43 @@ unsafe fn set_n_threads(options: &Builder) {
         jlrs_set_nthreads_per_pool(Box::leak(perthread) as *const _);
     }
 }
+
+unsafe fn set_profiling_options(options: &Builder) {
+    jlrs_set_code_coverage(options.code_coverage as i8);
+    jlrs_set_malloc_log(options.track_allocation as i8);
+}
+
+unsafe fn set_cpu_target(options: &Builder) {
+    if let Some(cpu_target) = options.cpu_target.as_ref() {
+        // The target has been checked for null characters by `Builder::cpu_target`, the string
+        // is leaked because Julia keeps using it.
+        let cpu_target = CString::new(cpu_target.as_str()).unwrap();
+        jlrs_set_cpu_target(cpu_target.into_raw());
+    }
+}
+
+// Returns an error if `path` can't be converted to a C string.
+fn trace_path(path: &Path) -> JlrsResult<PathBuf> {
+    if path.as_os_str().as_encoded_bytes().contains(&0) {
+        Err(RuntimeError::InvalidTracePath {
+            path: path.display().to_string(),
+        })?
+    }
+
+    Ok(path.to_path_buf())
+}
+
+unsafe fn set_trace_options(options: &Builder) {
+    // The paths have been checked for null characters by `trace_path`, the strings are leaked
+    // because Julia keeps using them.
+    if let Some(path) = options.trace_compile.as_ref() {
+        let path = CString::new(path.as_os_str().as_encoded_bytes()).unwrap();
+        jlrs_set_trace_compile(path.into_raw());
+    }
+
+    #[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11")))]
+    if let Some(path) = options.trace_dispatch.as_ref() {
+        let path = CString::new(path.as_os_str().as_encoded_bytes()).unwrap();
+        jl_sys::jlrs_set_trace_dispatch(path.into_raw());
+    }
+}
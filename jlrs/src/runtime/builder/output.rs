@@ -0,0 +1,117 @@
+//! Redirect Julia's output to `tracing` events.
+//!
+//! After Julia has been initialized, `Base.stdout` and `Base.stderr` are replaced with the write
+//! ends of two pipes. A background thread reads each pipe line by line and emits the lines as
+//! `tracing` events. The file descriptors of the process are left alone, so a subscriber that
+//! writes to stdout or stderr doesn't feed its own output back into the pipes.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    os::fd::{FromRawFd, RawFd},
+    thread,
+};
+
+use crate::{data::managed::value::Value, memory::target::unrooted::Unrooted};
+
+#[derive(Clone, Copy)]
+enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl OutputStream {
+    const fn name(self) -> &'static str {
+        match self {
+            OutputStream::Stdout => "stdout",
+            OutputStream::Stderr => "stderr",
+        }
+    }
+
+    fn emit(self, line: &str) {
+        match self {
+            OutputStream::Stdout => tracing::info!(target: "julia", "{}", line),
+            OutputStream::Stderr => tracing::warn!(target: "julia", "{}", line),
+        }
+    }
+}
+
+// Safety: must be called after Julia has been initialized from a thread that can call into
+// Julia.
+pub(super) unsafe fn redirect_output_to_tracing() {
+    for stream in [OutputStream::Stdout, OutputStream::Stderr] {
+        if let Err(e) = redirect(stream) {
+            tracing::warn!(
+                target: "julia",
+                "Cannot redirect {} to tracing: {}",
+                stream.name(),
+                e
+            );
+        }
+    }
+}
+
+unsafe fn redirect(stream: OutputStream) -> io::Result<()> {
+    let mut fds = [0 as RawFd; 2];
+    if libc::pipe(fds.as_mut_ptr()) != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let [read_fd, write_fd] = fds;
+    let reader = File::from_raw_fd(read_fd);
+
+    // The reader must be running before Julia can write to the pipe, otherwise writes block
+    // as soon as the pipe is full.
+    let spawned = thread::Builder::new()
+        .name(format!("jlrs-{}", stream.name()))
+        .spawn(move || pump(stream, reader));
+
+    if let Err(e) = spawned {
+        // The read end has been dropped together with the closure.
+        libc::close(write_fd);
+        return Err(e);
+    }
+
+    // Julia takes ownership of the write end. The reader thread stops when it's closed.
+    let cmd = format!(
+        "setglobal!(Base, :{}, Base.PipeEndpoint(Base.RawFD({})))",
+        stream.name(),
+        write_fd
+    );
+
+    match Value::eval_string(Unrooted::new(), cmd) {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            libc::close(write_fd);
+            let msg = format!(
+                "an exception was thrown while replacing Base.{}",
+                stream.name()
+            );
+            Err(io::Error::other(msg))
+        }
+    }
+}
+
+fn pump(stream: OutputStream, reader: File) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+
+        // A line can be split across several writes, read_until only returns when it has read
+        // a complete line or when the write end has been closed.
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) => break,
+            Ok(_) => {
+                if buf.last() == Some(&b'\n') {
+                    buf.pop();
+                }
+
+                stream.emit(&String::from_utf8_lossy(&buf));
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Validate the environment before Julia is initialized.
+
+use std::{
+    env,
+    ffi::CStr,
+    path::{Path, PathBuf},
+};
+
+use jl_sys::{jl_is_initialized, jl_ver_major, jl_ver_minor, jl_ver_string};
+
+use super::Builder;
+use crate::{
+    error::{JlrsResult, RuntimeError},
+    runtime::state::{current_state_is, State},
+};
+
+#[cfg(feature = "julia-1-10")]
+const EXPECTED_VERSION: (i32, i32) = (1, 10);
+#[cfg(feature = "julia-1-11")]
+const EXPECTED_VERSION: (i32, i32) = (1, 11);
+#[cfg(not(any(feature = "julia-1-10", feature = "julia-1-11")))]
+const EXPECTED_VERSION: (i32, i32) = (1, 12);
+
+/// The result of validating the environment with [`Builder::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvReport {
+    /// The version of the Julia library that has been loaded.
+    pub julia_version: String,
+    /// The major and minor version jlrs has been built for.
+    pub expected_version: (i32, i32),
+    /// `true` if the major and minor version of the loaded library match the expected version.
+    pub version_matches: bool,
+    /// The paths to the custom system image and the directory containing the Julia binary, if
+    /// they have been set, which don't exist.
+    pub missing_image_paths: Vec<PathBuf>,
+    /// The entries of the depot path set with [`Builder::with_depot`] or `JULIA_DEPOT_PATH`
+    /// which don't exist or are not directories.
+    pub inaccessible_depots: Vec<PathBuf>,
+    /// The project set with [`Builder::with_project`] or `JULIA_PROJECT` if it doesn't exist.
+    pub inaccessible_project: Option<PathBuf>,
+}
+
+impl EnvReport {
+    /// Returns `true` if no problems have been found.
+    pub fn is_ok(&self) -> bool {
+        self.version_matches
+            && self.missing_image_paths.is_empty()
+            && self.inaccessible_depots.is_empty()
+            && self.inaccessible_project.is_none()
+    }
+}
+
+pub(super) fn validate(builder: &Builder) -> JlrsResult<EnvReport> {
+    if !current_state_is(State::Uninit) || unsafe { jl_is_initialized() != 0 } {
+        Err(RuntimeError::AlreadyInitialized)?;
+    }
+
+    // Safety: these functions only read constants, they can be called before Julia has been
+    // initialized.
+    let (julia_version, version) = unsafe {
+        let version_str = CStr::from_ptr(jl_ver_string())
+            .to_string_lossy()
+            .into_owned();
+        (version_str, (jl_ver_major(), jl_ver_minor()))
+    };
+
+    let missing_image_paths = match builder.image.as_ref() {
+        Some((bin_dir, image_path)) => [bin_dir, image_path]
+            .into_iter()
+            .filter(|path| !path.exists())
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let depots = builder
+        .depot
+        .as_ref()
+        .map(|depot| depot.clone().into_os_string())
+        .or_else(|| env::var_os("JULIA_DEPOT_PATH"));
+    let inaccessible_depots = match depots {
+        Some(depots) => env::split_paths(&depots)
+            .filter(|path| !path.as_os_str().is_empty() && !path.is_dir())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    // Special values like `@.` are resolved by Julia.
+    let inaccessible_project = builder
+        .project
+        .clone()
+        .or_else(|| env::var_os("JULIA_PROJECT").map(PathBuf::from))
+        .filter(|path| !path.as_os_str().is_empty() && !is_special_project(path) && !path.exists());
+
+    Ok(EnvReport {
+        julia_version,
+        expected_version: EXPECTED_VERSION,
+        version_matches: version == EXPECTED_VERSION,
+        missing_image_paths,
+        inaccessible_depots,
+        inaccessible_project,
+    })
+}
+
+fn is_special_project(path: &Path) -> bool {
+    path.as_os_str()
+        .to_str()
+        .map(|path| path.starts_with('@'))
+        .unwrap_or(false)
+}
@@ -1,4 +1,9 @@
 //! Re-export tokio oneshot channel sender and receiver types, and async-channel's `RecvError`.
+//!
+//! The result of a task is sent to a tokio oneshot channel by default. Other channels can be
+//! used by implementing [`ResultSender`].
+
+use std::sync::mpsc;
 
 use async_channel::{bounded, unbounded, Receiver, Sender};
 
@@ -18,3 +23,50 @@ pub(crate) fn channel<T>(channel_capacity: usize) -> (Sender<T>, Receiver<T>) {
 
 ///async-channel's `RecvError`
 pub type RecvError = async_channel::RecvError;
+
+/// The sending half of a channel that the result of a task can be sent to.
+///
+/// This trait is implemented for tokio's oneshot sender, `std::sync::mpsc::Sender`, and closures
+/// that take the result. Other channels, like crossbeam's, can be used by wrapping their sender
+/// in a closure:
+///
+/// ```ignore
+/// let (sender, receiver) = crossbeam_channel::unbounded();
+/// handle
+///     .task_with_sender(task, move |res| {
+///         sender.send(res).ok();
+///     })
+///     .dispatch()
+///     .await?;
+/// ```
+///
+/// The result is sent from the thread that runs the task, sending it must not block.
+pub trait ResultSender<T>: 'static + Send {
+    /// Send the result of a task. If the receiving half has been dropped, the result is
+    /// discarded.
+    fn send_result(self, result: T);
+}
+
+impl<T: Send + 'static> ResultSender<T> for OneshotSender<T> {
+    #[inline]
+    fn send_result(self, result: T) {
+        self.send(result).ok();
+    }
+}
+
+impl<T: Send + 'static> ResultSender<T> for mpsc::Sender<T> {
+    #[inline]
+    fn send_result(self, result: T) {
+        self.send(result).ok();
+    }
+}
+
+impl<T, F> ResultSender<T> for F
+where
+    F: 'static + Send + FnOnce(T),
+{
+    #[inline]
+    fn send_result(self, result: T) {
+        self(result)
+    }
+}
@@ -1,6 +1,11 @@
 //! Dispatch a task to the async runtime.
 
-use std::fmt;
+use std::{
+    fmt,
+    marker::PhantomData,
+    thread,
+    time::{Duration, Instant},
+};
 
 use async_channel::{SendError, Sender, TrySendError};
 
@@ -11,24 +16,34 @@ use crate::{
 };
 
 /// Dispatch a task to the async runtime.
-pub struct Dispatch<'a, M, T> {
+///
+/// When the task is dispatched, `R` is returned. By default this is the receiving end of a
+/// oneshot channel, if the task has been created with a custom [`ResultSender`] it's `()`.
+///
+/// [`ResultSender`]: super::channel::ResultSender
+pub struct Dispatch<'a, M, T, R = OneshotReceiver<T>> {
     msg: M,
     sender: &'a Sender<M>,
-    receiver: OneshotReceiver<T>,
+    receiver: R,
+    _result: PhantomData<fn() -> T>,
 }
 
-impl<'a, M, T> Dispatch<'a, M, T> {
+// The maximum time `Dispatch::dispatch_timeout` parks the thread before retrying.
+const MAX_BACKOFF: Duration = Duration::from_millis(1);
+
+impl<'a, M, T, R> Dispatch<'a, M, T, R> {
     #[inline]
-    pub(crate) const fn new(msg: M, sender: &'a Sender<M>, receiver: OneshotReceiver<T>) -> Self {
+    pub(crate) const fn new(msg: M, sender: &'a Sender<M>, receiver: R) -> Self {
         Dispatch {
             msg,
             sender,
             receiver,
+            _result: PhantomData,
         }
     }
 
     /// Dispatch the task.
-    pub async fn dispatch(self) -> JlrsResult<OneshotReceiver<T>> {
+    pub async fn dispatch(self) -> JlrsResult<R> {
         match self.sender.send(self.msg).await {
             Ok(_) => Ok(self.receiver),
             Err(SendError(_)) => Err(RuntimeError::ChannelClosed)?,
@@ -38,7 +53,7 @@ impl<'a, M, T> Dispatch<'a, M, T> {
     /// Try to dispatch the task.
     ///
     /// If the channel is full, the dispatcher is returned to allow retrying.
-    pub fn try_dispatch(self) -> Result<OneshotReceiver<T>, JlrsResult<Self>> {
+    pub fn try_dispatch(self) -> Result<R, JlrsResult<Self>> {
         match self.sender.try_send(self.msg) {
             Ok(_) => Ok(self.receiver),
             Err(TrySendError::Closed(_)) => Err(Err(Box::new(JlrsError::RuntimeError(
@@ -48,12 +63,48 @@ impl<'a, M, T> Dispatch<'a, M, T> {
                 msg,
                 sender: self.sender,
                 receiver: self.receiver,
+                _result: PhantomData,
             })),
         }
     }
+
+    /// Try to dispatch the task, blocking the current thread for at most `timeout` while the
+    /// channel is full.
+    ///
+    /// If the task hasn't been dispatched when the timeout expires, the dispatcher is returned to
+    /// allow retrying. This method blocks the current thread, it must not be called from an async
+    /// context; use [`Dispatch::dispatch`] in combination with the timeout mechanism of your
+    /// async runtime instead.
+    pub fn dispatch_timeout(self, timeout: Duration) -> Result<R, JlrsResult<Self>> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut backoff = Duration::from_micros(10);
+        let mut dispatch = self;
+
+        loop {
+            dispatch = match dispatch.try_dispatch() {
+                Ok(receiver) => return Ok(receiver),
+                Err(Err(e)) => return Err(Err(e)),
+                Err(Ok(dispatch)) => dispatch,
+            };
+
+            let park_for = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Ok(dispatch));
+                    }
+                    backoff.min(deadline - now)
+                }
+                None => backoff,
+            };
+
+            thread::park_timeout(park_for);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
 }
 
-impl<'a, M, T> fmt::Debug for Dispatch<'a, M, T> {
+impl<'a, M, T, R> fmt::Debug for Dispatch<'a, M, T, R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Dispatch").finish()
     }
@@ -1,11 +1,14 @@
 use std::{future::Future, marker::PhantomData, path::PathBuf, pin::Pin};
 
 use super::{
-    channel::{channel, OneshotSender},
+    channel::{channel, OneshotSender, ResultSender},
     persistent::PersistentHandle,
 };
 use crate::{
-    async_util::task::{AsyncTask, PersistentTask, Register},
+    async_util::{
+        cancellation_token::CancellationToken,
+        task::{AsyncTask, PersistentTask, Register},
+    },
     call::Call,
     data::managed::module::{JlrsCore, Main},
     error::JlrsError,
@@ -16,6 +19,7 @@ use crate::{
 pub(crate) struct PendingTask<T, U, Kind> {
     task: Option<T>,
     sender: U,
+    token: Option<CancellationToken>,
     _kind: PhantomData<Kind>,
 }
 
@@ -28,6 +32,7 @@ where
         PendingTask {
             task: None,
             sender,
+            token: None,
             _kind: PhantomData,
         }
     }
@@ -67,32 +72,49 @@ where
     }
 }
 
-impl<A> PendingTask<A, OneshotSender<A::Output>, Task>
+impl<A, S> PendingTask<A, S, Task>
 where
     A: AsyncTask,
+    S: ResultSender<A::Output>,
 {
     #[inline]
-    pub(crate) fn new(task: A, sender: OneshotSender<A::Output>) -> Self {
+    pub(crate) fn new(task: A, sender: S, token: CancellationToken) -> Self {
         PendingTask {
             task: Some(task),
             sender,
+            token: Some(token),
             _kind: PhantomData,
         }
     }
 
     #[inline]
-    fn split(self) -> (A, OneshotSender<A::Output>) {
-        (self.task.unwrap(), self.sender)
+    fn split(self) -> (A, S, CancellationToken) {
+        (self.task.unwrap(), self.sender, self.token.unwrap())
     }
 }
 
-impl<A> PendingTaskEnvelope for PendingTask<A, OneshotSender<A::Output>, Task>
+impl<A, S> PendingTaskEnvelope for PendingTask<A, S, Task>
 where
     A: AsyncTask,
+    S: ResultSender<A::Output>,
 {
     fn call(self: Box<Self>, stack: &'static Stack) -> Pin<Box<dyn Future<Output = ()>>> {
         let f = async move {
-            let (task, sender) = self.split();
+            let (task, sender, handle_token) = self.split();
+            let task_token = task.cancellation_token();
+            let is_cancelled = || {
+                handle_token.is_cancelled()
+                    || task_token
+                        .as_ref()
+                        .is_some_and(CancellationToken::is_cancelled)
+            };
+
+            // The task is skipped if it has been cancelled before it has started, the sender is
+            // dropped without sending a result. A running task is never dropped before it has
+            // completed: Julia tasks it has started can still use its data.
+            if is_cancelled() {
+                return;
+            }
 
             // Safety: the stack slots can be reallocated because it doesn't contain any frames
             // yet. The frame is dropped at the end of the scope, the nested hierarchy of scopes is
@@ -104,7 +126,7 @@ where
                 res
             };
 
-            sender.send(res).ok();
+            sender.send_result(res);
         };
 
         Box::pin(f)
@@ -214,19 +236,25 @@ where
     }
 }
 
-pub(crate) struct BlockingTask<F, T> {
+pub(crate) struct BlockingTask<F, T, S = OneshotSender<T>> {
     func: F,
-    sender: OneshotSender<T>,
+    sender: S,
+    _result: PhantomData<fn() -> T>,
 }
 
-impl<F, T> BlockingTask<F, T>
+impl<F, T, S> BlockingTask<F, T, S>
 where
     for<'base> F: Send + FnOnce(GcFrame<'base>) -> T,
     T: Send + 'static,
+    S: ResultSender<T>,
 {
     #[inline]
-    pub(crate) fn new(func: F, sender: OneshotSender<T>) -> Self {
-        Self { func, sender }
+    pub(crate) fn new(func: F, sender: S) -> Self {
+        Self {
+            func,
+            sender,
+            _result: PhantomData,
+        }
     }
 
     #[inline]
@@ -235,7 +263,7 @@ where
         // 'scope.
         let func = self.func;
         let res = func(frame);
-        self.sender.send(res).ok();
+        self.sender.send_result(res);
     }
 }
 
@@ -243,10 +271,11 @@ pub(crate) trait BlockingTaskEnvelope: Send {
     fn call<'scope>(self: Box<Self>, stack: &'scope Stack);
 }
 
-impl<F, T> BlockingTaskEnvelope for BlockingTask<F, T>
+impl<F, T, S> BlockingTaskEnvelope for BlockingTask<F, T, S>
 where
     for<'base> F: Send + FnOnce(GcFrame<'base>) -> T,
     T: Send + 'static,
+    S: ResultSender<T>,
 {
     fn call<'scope>(self: Box<Self>, stack: &'scope Stack) {
         // Safety: the stack slots can be reallocated because it doesn't contain any frames
@@ -312,6 +341,7 @@ where
         PendingTask {
             task: Some(task),
             sender: sender,
+            token: None,
             _kind: PhantomData,
         }
     }
@@ -17,12 +17,13 @@ use std::{
 use async_channel::{Receiver, Sender, TryRecvError};
 use envelope::Task;
 use jl_sys::{jl_gcframe_t, jlrs_gc_unsafe_enter, jlrs_gc_unsafe_leave, jlrs_ppgcstack};
+use parking_lot::Mutex;
 use tokio::sync::oneshot::channel as oneshot_channel;
 
 #[cfg(feature = "multi-rt")]
 use self::task_complete::{TaskComplete, TaskCompleteState};
 use self::{
-    cancellation_token::CancellationToken,
+    channel::ResultSender,
     dispatch::Dispatch,
     envelope::{
         BlockingTask, IncludeTask, PendingTask, Persistent, RegisterTask, SetErrorColorTask,
@@ -34,6 +35,7 @@ use self::{
 use super::mt_handle::manager::{get_manager, PoolId};
 use crate::{
     async_util::{
+        cancellation_token::CancellationToken,
         future::{wake_task, GcUnsafeFuture},
         task::{sleep, AsyncTask, PersistentTask, Register},
     },
@@ -46,7 +48,6 @@ use crate::{
     weak_handle_unchecked,
 };
 
-pub(crate) mod cancellation_token;
 pub mod channel;
 pub mod dispatch;
 mod envelope;
@@ -64,6 +65,7 @@ pub struct AsyncHandle {
     sender: Sender<Message>,
     pool_or_token: PoolIdOrToken,
     n_workers: Arc<AtomicUsize>,
+    tasks_token: Arc<Mutex<CancellationToken>>,
 }
 
 impl AsyncHandle {
@@ -73,13 +75,29 @@ impl AsyncHandle {
         A: AsyncTask,
     {
         let (sender, receiver) = oneshot_channel();
-        let pending_task = PendingTask::<_, _, Task>::new(task, sender);
+        let pending_task = PendingTask::<_, _, Task>::new(task, sender, self.tasks_token());
         let boxed = Box::new(pending_task);
         let msg = MessageInner::Task(boxed).wrap();
 
         Dispatch::new(msg, &self.sender, receiver)
     }
 
+    /// Prepare to send a new async task, its result is sent to `sender`.
+    ///
+    /// Unlike [`AsyncHandle::task`], the result is sent to a channel provided by the caller
+    /// instead of a tokio oneshot channel. See [`ResultSender`] for more information.
+    pub fn task_with_sender<A, S>(&self, task: A, sender: S) -> Dispatch<Message, A::Output, ()>
+    where
+        A: AsyncTask,
+        S: ResultSender<A::Output>,
+    {
+        let pending_task = PendingTask::<_, _, Task>::new(task, sender, self.tasks_token());
+        let boxed = Box::new(pending_task);
+        let msg = MessageInner::Task(boxed).wrap();
+
+        Dispatch::new(msg, &self.sender, ())
+    }
+
     /// Prepare to register a task.
     pub fn register_task<R>(&self) -> Dispatch<Message, JlrsResult<()>>
     where
@@ -107,6 +125,23 @@ impl AsyncHandle {
         Dispatch::new(msg, &self.sender, receiver)
     }
 
+    /// Prepare to send a new blocking task, its result is sent to `sender`.
+    ///
+    /// Unlike [`AsyncHandle::blocking_task`], the result is sent to a channel provided by the
+    /// caller instead of a tokio oneshot channel. See [`ResultSender`] for more information.
+    pub fn blocking_task_with_sender<T, F, S>(&self, task: F, sender: S) -> Dispatch<Message, T, ()>
+    where
+        for<'base> F: 'static + Send + FnOnce(GcFrame<'base>) -> T,
+        T: Send + 'static,
+        S: ResultSender<T>,
+    {
+        let pending_task = BlockingTask::new(task, sender);
+        let boxed = Box::new(pending_task);
+        let msg = MessageInner::BlockingTask(boxed).wrap();
+
+        Dispatch::new(msg, &self.sender, ())
+    }
+
     /// Prepare to send a new persistent task.
     pub fn persistent<P>(&self, task: P) -> Dispatch<Message, JlrsResult<PersistentHandle<P>>>
     where
@@ -174,6 +209,20 @@ impl AsyncHandle {
         Dispatch::new(msg, &self.sender, receiver)
     }
 
+    /// Cancel all async tasks that have been sent with this handle or one of its clones.
+    ///
+    /// Tasks that haven't started yet are skipped and no result is sent for them. Running tasks
+    /// are not interrupted, they run to completion. Tasks that are sent after this method has
+    /// been called are not affected, neither are blocking and persistent tasks. See the
+    /// [`cancellation_token`] module for more information.
+    ///
+    /// [`cancellation_token`]: crate::async_util::cancellation_token
+    pub fn cancel_all(&self) {
+        let mut token = self.tasks_token.lock();
+        token.cancel();
+        *token = CancellationToken::new();
+    }
+
     /// The current number of workers in the thread pool.
     pub fn n_workers(&self) -> usize {
         self.n_workers.load(Ordering::Relaxed)
@@ -204,6 +253,7 @@ impl AsyncHandle {
             sender,
             pool_or_token: PoolIdOrToken::Token(token),
             n_workers: Arc::new(AtomicUsize::new(1)),
+            tasks_token: Arc::new(Mutex::new(CancellationToken::new())),
         }
     }
 }
@@ -255,10 +305,17 @@ impl AsyncHandle {
             sender,
             pool_or_token: PoolIdOrToken::PoolId(pool_id),
             n_workers,
+            tasks_token: Arc::new(Mutex::new(CancellationToken::new())),
         }
     }
 }
 
+impl AsyncHandle {
+    fn tasks_token(&self) -> CancellationToken {
+        self.tasks_token.lock().clone()
+    }
+}
+
 impl RequireSendSync for AsyncHandle {}
 
 #[derive(Clone)]
@@ -2,17 +2,33 @@
 //!
 //! This module is only available if the `ccall` feature is enabled.
 
+use std::{
+    cell::Cell,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
 use jl_sys::{jl_throw, unsized_local_scope};
 
 use crate::{
+    call::Call,
     convert::ccall_types::CCallReturn,
     data::{
-        managed::{module::JlrsCore, private::ManagedPriv, value::ValueRet},
+        managed::{
+            module::{JlrsCore, Module},
+            private::ManagedPriv,
+            simple_vector::SimpleVector,
+            string::JuliaString,
+            symbol::Symbol,
+            type_var::TypeVar,
+            value::{Value, ValueRet},
+            Managed,
+        },
         types::construct_type::ConstructType,
     },
     error::JlrsResult,
-    init_jlrs,
+    init_jlrs, inline_static_ref,
     memory::{
+        scope::Scope,
         stack_frame::{PinnedFrame, StackFrame},
         target::{
             frame::{GcFrame, LocalFrame, LocalGcFrame, UnsizedLocalGcFrame},
@@ -21,9 +37,15 @@ use crate::{
     },
     private::Private,
     runtime::state::set_started_from_julia,
+    util::panic_message,
     InstallJlrsCore,
 };
 
+thread_local! {
+    // The item that is being initialized by the init function generated by `julia_module!`.
+    static INIT_CONTEXT: Cell<&'static str> = const { Cell::new("module") };
+}
+
 /// Interact with Julia from a Rust function called through `ccall`.
 ///
 /// When you call Rust from Julia through `ccall`, Julia has already been initialized and trying to
@@ -188,6 +210,71 @@ impl<'context> CCall<'context> {
         func(Unrooted::new())
     }
 
+    /// Set the item that is being initialized. This is used by the init function generated by
+    /// `julia_module!` to name the item that failed to initialize if a panic is caught by
+    /// [`CCall::catch_init`].
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_init_context(item: &'static str) {
+        INIT_CONTEXT.with(|ctx| ctx.set(item))
+    }
+
+    /// Call `func` and catch any panic. If a panic is caught, an error message that names the
+    /// item that was being initialized is returned. This is used by the init function generated
+    /// by `julia_module!` so a module that fails to initialize throws an exception instead of
+    /// aborting the process. Reinitializing a module that failed to initialize is not supported,
+    /// items might have been partially defined before the panic.
+    #[doc(hidden)]
+    pub fn catch_init<T, F>(func: F) -> Result<T, String>
+    where
+        F: FnOnce() -> T,
+    {
+        Self::set_init_context("module");
+        catch_unwind(AssertUnwindSafe(func)).map_err(|payload| {
+            let item = INIT_CONTEXT.with(|ctx| ctx.get());
+            let msg = panic_message(payload.as_ref());
+            format!("failed to initialize {item}: {msg}")
+        })
+    }
+
+    /// Define the methods that provide the default arguments of an exported function. This is
+    /// used by the init function generated by `julia_module!`.
+    ///
+    /// `defaults` contains the defaults of the trailing arguments as Julia source code, the first
+    /// argument with a default is `first = arg_names.len() - defaults.len()`. For every `n` from
+    /// `first` to `arg_names.len() - 1` the method
+    /// `name(arg_1::T_1, ..., arg_n::T_n) = name(arg_1, ..., arg_n, default)` is defined in
+    /// `module`. The methods are constructed as expressions, the type variables in `env` are
+    /// declared in a single `where` clause and each default is parsed separately with
+    /// `Meta.parse`. If an exception is thrown it's returned.
+    ///
+    /// Safety: must only be called from the init function generated by `julia_module!` while
+    /// `module` is being precompiled.
+    #[doc(hidden)]
+    pub unsafe fn define_default_methods(
+        frame: &mut GcFrame,
+        module: Module,
+        name: &str,
+        env: SimpleVector,
+        arg_names: &[&str],
+        arg_types: &[Value<'_, 'static>],
+        defaults: &[&str],
+    ) -> Result<(), ValueRet> {
+        frame.scope(|mut frame| {
+            let args = DefaultMethods {
+                module,
+                name,
+                env,
+                first_default: arg_names.len() - defaults.len(),
+                arg_names,
+                arg_types,
+                defaults,
+            };
+
+            define_default_methods(&mut frame, args).map_err(|e| e.leak())
+        })
+    }
+
     /// This function must be called before jlrs can be used. When the `julia_module` macro is
     /// used this function is called automatically.
     ///
@@ -199,3 +286,140 @@ impl<'context> CCall<'context> {
         init_jlrs(install_jlrs_core);
     }
 }
+
+struct DefaultMethods<'a, 'b, 'scope> {
+    module: Module<'a>,
+    name: &'b str,
+    env: SimpleVector<'a>,
+    first_default: usize,
+    arg_names: &'b [&'b str],
+    arg_types: &'b [Value<'scope, 'static>],
+    defaults: &'b [&'b str],
+}
+
+unsafe fn define_default_methods<'target>(
+    frame: &mut GcFrame<'target>,
+    args: DefaultMethods,
+) -> Result<(), Value<'target, 'static>> {
+    let expr = inline_static_ref!(EXPR, Value, "Core.Expr", &frame);
+    let eval = inline_static_ref!(EVAL, Value, "Core.eval", &frame);
+    let parse = inline_static_ref!(PARSE, Value, "Base.Meta.parse", &frame);
+
+    let call_head = Symbol::new(&frame, "call").as_value();
+    let typed_head = Symbol::new(&frame, "::").as_value();
+    let assign_head = Symbol::new(&frame, "=").as_value();
+    let where_head = Symbol::new(&frame, "where").as_value();
+    let comparison_head = Symbol::new(&frame, "comparison").as_value();
+    let subtype = Symbol::new(&frame, "<:").as_value();
+    let name = Symbol::new(&frame, args.name).as_value();
+    let arg_names = args
+        .arg_names
+        .iter()
+        .map(|arg_name| Symbol::new(&frame, arg_name).as_value())
+        .collect::<Vec<_>>();
+
+    // The first type variable is the outermost one.
+    let env = args.env.data();
+    let tvars = (0..env.len())
+        .map(|idx| env.get(&mut *frame, idx).unwrap().as_value())
+        .collect::<Vec<_>>();
+    let tvar_names = tvars
+        .iter()
+        .map(|tvar| tvar.cast_unchecked::<TypeVar>().name().as_value())
+        .collect::<Vec<_>>();
+
+    let mut typed_args = Vec::with_capacity(arg_names.len());
+    for (arg_name, ty) in arg_names.iter().zip(args.arg_types) {
+        let mut ty = ty.as_value();
+        if !tvars.is_empty() {
+            ty = rebind_type_vars(frame, ty, &tvars, &tvar_names)?;
+        }
+
+        typed_args.push(expr.call3(&mut *frame, typed_head, *arg_name, ty)?);
+    }
+
+    // All type variables are declared in a single `where` clause so constraints shared by
+    // multiple arguments are preserved. The bounds of a type variable can depend on the type
+    // variables that precede it.
+    let mut where_params = Vec::with_capacity(tvars.len());
+    for (idx, tvar) in tvars.iter().enumerate() {
+        let tvar = tvar.cast_unchecked::<TypeVar>();
+        let mut lb = tvar.lower_bound(&mut *frame);
+        let mut ub = tvar.upper_bound(&mut *frame);
+        if idx != 0 {
+            // `Tuple{Union{}}` is normalized to `Union{}`, so the default lower bound is used as
+            // is.
+            if lb != Value::bottom_type(&frame) {
+                lb = rebind_type_vars(frame, lb, &tvars[..idx], &tvar_names[..idx])?;
+            }
+
+            ub = rebind_type_vars(frame, ub, &tvars[..idx], &tvar_names[..idx])?;
+        }
+
+        let param = expr.call(
+            &mut *frame,
+            [comparison_head, lb, subtype, tvar_names[idx], subtype, ub],
+        )?;
+        where_params.push(param);
+    }
+
+    for (idx, default) in args.defaults.iter().enumerate() {
+        let n = args.first_default + idx;
+        let default = JuliaString::new(&mut *frame, default).as_value();
+        let default = parse.call1(&mut *frame, default)?;
+
+        let mut signature = vec![call_head, name];
+        signature.extend_from_slice(&typed_args[..n]);
+        let mut signature = expr.call(&mut *frame, signature.as_slice())?;
+
+        if !where_params.is_empty() {
+            let mut where_clause = vec![where_head, signature];
+            where_clause.extend_from_slice(&where_params);
+            signature = expr.call(&mut *frame, where_clause.as_slice())?;
+        }
+
+        let mut body = vec![call_head, name];
+        body.extend_from_slice(&arg_names[..n]);
+        body.push(default);
+        let body = expr.call(&mut *frame, body.as_slice())?;
+
+        let method = expr.call3(&mut *frame, assign_head, signature, body)?;
+        eval.call2(&mut *frame, args.module.as_value(), method)?;
+    }
+
+    Ok(())
+}
+
+// Returns an expression that evaluates to `ty` with the type variables in `tvars` replaced by the
+// type variables named `names` in the enclosing `where` clause. The type is wrapped in a
+// `UnionAll` that is applied to these names, the type variables are added to the wrapped tuple
+// type so they are never simplified away.
+unsafe fn rebind_type_vars<'target>(
+    frame: &mut GcFrame<'target>,
+    ty: Value<'_, 'static>,
+    tvars: &[Value<'_, 'static>],
+    names: &[Value<'_, 'static>],
+) -> Result<Value<'target, 'static>, Value<'target, 'static>> {
+    let expr = inline_static_ref!(EXPR, Value, "Core.Expr", &frame);
+    let union_all = inline_static_ref!(UNION_ALL, Value, "Core.UnionAll", &frame);
+    let apply_type = inline_static_ref!(APPLY_TYPE, Value, "Core.apply_type", &frame);
+    let tuple = inline_static_ref!(TUPLE, Value, "Core.Tuple", &frame);
+    let fieldtype = inline_static_ref!(FIELDTYPE, Value, "Core.fieldtype", &frame);
+
+    let call_head = Symbol::new(&frame, "call").as_value();
+    let curly_head = Symbol::new(&frame, "curly").as_value();
+
+    let mut params = vec![tuple, ty];
+    params.extend_from_slice(tvars);
+    let mut wrapped = apply_type.call(&mut *frame, params.as_slice())?;
+    for tvar in tvars.iter().rev() {
+        wrapped = union_all.call2(&mut *frame, *tvar, wrapped)?;
+    }
+
+    let mut applied = vec![curly_head, wrapped];
+    applied.extend_from_slice(names);
+    let applied = expr.call(&mut *frame, applied.as_slice())?;
+
+    let first = Value::new(&mut *frame, 1isize);
+    expr.call(&mut *frame, [call_head, fieldtype, applied, first])
+}
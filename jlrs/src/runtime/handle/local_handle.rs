@@ -1,18 +1,31 @@
 //! A handle that lets you call into Julia from the current thread.
 
-use std::{fmt, marker::PhantomData, path::Path};
+use std::{cell::RefCell, fmt, marker::PhantomData, path::Path};
 
-use jl_sys::jl_atexit_hook;
+use jl_sys::{jl_atexit_hook, jl_process_events};
 
 use super::IsActive;
 use crate::{
-    call::Call,
+    call::{Call, ProvideKeywords},
     convert::into_jlrs_result::IntoJlrsResult,
-    data::managed::module::Main,
-    error::{IOError, JlrsResult},
-    memory::scope::{LocalReturning, LocalScope},
+    data::{
+        layout::nothing::Nothing,
+        managed::{
+            module::{Main, Module},
+            private::ManagedPriv,
+            value::ValueUnbound,
+        },
+    },
+    error::{IOError, JlrsResult, RuntimeError},
+    inline_static_ref,
+    memory::{
+        scope::{LocalReturning, LocalScope},
+        target::unrooted::Unrooted,
+    },
+    named_tuple,
     prelude::{JuliaString, Managed, Value},
-    runtime::state::set_exit,
+    private::Private,
+    runtime::{builder::run_shutdown_hooks, state::set_exit},
 };
 
 /// A handle that lets you call into Julia from the current thread.
@@ -22,6 +35,7 @@ use crate::{
 ///
 /// [`Builder::start_local`]: crate::runtime::builder::Builder::start_local
 pub struct LocalHandle {
+    roots: RefCell<Vec<ValueUnbound>>,
     _marker: PhantomData<*mut ()>,
 }
 
@@ -70,8 +84,143 @@ impl LocalHandle {
         });
     }
 
+    /// Activate the project at `path` by calling `Pkg.activate`.
+    ///
+    /// If `path` starts with `@`, e.g. `@v1.11`, the named environment is activated in the depot
+    /// with `Pkg.activate(name; shared=true)`. Otherwise, `IOError::NotFound` is returned if
+    /// `path` doesn't exist.
+    ///
+    /// After activating the project, this method checks if JlrsCore can still be found with
+    /// `Base.find_package`. JlrsCore remains loaded when another project is activated, but it
+    /// must be loadable for jlrs to keep working correctly, e.g. when new worker processes are
+    /// started. If JlrsCore can't be found, the previously active project is restored and
+    /// `RuntimeError::JlrsCoreUnavailable` is returned.
+    ///
+    /// Safety: activating a project can change which code is loaded by subsequent calls to
+    /// `using` and `import`.
+    pub unsafe fn activate_project<P: AsRef<Path>>(&mut self, path: P) -> JlrsResult<()> {
+        let path = path.as_ref();
+        // Named environments don't exist on the filesystem until a package has been added.
+        let shared_name = path.to_str().and_then(|path| path.strip_prefix('@'));
+        if shared_name.is_none() && !path.exists() {
+            Err(IOError::NotFound {
+                path: path.to_string_lossy().into(),
+            })?
+        }
+
+        self.local_scope::<_, 8>(|mut frame| {
+            let active_project =
+                inline_static_ref!(ACTIVE_PROJECT, Value, "Base.ACTIVE_PROJECT", &frame);
+            let getindex = inline_static_ref!(GETINDEX, Value, "Base.getindex", &frame);
+            let setindex = inline_static_ref!(SETINDEX, Value, "Base.setindex!", &frame);
+            let find_package = inline_static_ref!(FIND_PACKAGE, Value, "Base.find_package", &frame);
+
+            let previous = getindex
+                .call1(&mut frame, active_project)
+                .into_jlrs_result()?;
+
+            Module::main(&frame)
+                .require(&mut frame, "Pkg")
+                .into_jlrs_result()?;
+            let activate = inline_static_ref!(ACTIVATE, Value, "Pkg.activate", &frame);
+
+            match shared_name {
+                Some(name) => {
+                    let name = JuliaString::new(&mut frame, name);
+                    let shared = Value::true_v(&frame);
+                    let kws = named_tuple!(&mut frame, "shared" => shared);
+                    activate
+                        .provide_keywords(kws)?
+                        .call1(&mut frame, name.as_value())
+                        .into_jlrs_result()?;
+                }
+                None => {
+                    let path_jl_str = JuliaString::new(&mut frame, path.to_string_lossy());
+                    activate
+                        .call1(&mut frame, path_jl_str.as_value())
+                        .into_jlrs_result()?;
+                }
+            }
+
+            let jlrs_core = JuliaString::new(&mut frame, "JlrsCore");
+            let found = find_package
+                .call1(&mut frame, jlrs_core.as_value())
+                .into_jlrs_result()?;
+
+            if found.is::<Nothing>() {
+                setindex
+                    .call2(&mut frame, active_project, previous)
+                    .into_jlrs_result()?;
+
+                Err(RuntimeError::JlrsCoreUnavailable {
+                    project: path.to_string_lossy().into(),
+                })?
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Let Julia process pending events once.
+    ///
+    /// This calls `jl_process_events`, which runs a single non-blocking iteration of Julia's
+    /// event loop so ready timers and I/O callbacks are handled. Unlike the async runtime, the
+    /// local runtime never does this automatically. Applications that keep a `LocalHandle`
+    /// around in a long-running loop should call this method regularly to avoid starving
+    /// Julia's internal event handling.
+    #[inline]
+    pub fn process_events(&mut self) {
+        unsafe {
+            jl_process_events();
+        }
+    }
+
+    /// Root `value` until this handle is dropped and return it with the lifetime of the borrow of
+    /// this handle.
+    ///
+    /// Data rooted in a scope can't be returned from that scope. This method roots `value` by
+    /// calling `Base.preserve_handle`, which adds it to a global root set, so it can be returned
+    /// from the scope and used until the handle is dropped. The returned value borrows the
+    /// handle, so it can't be used after Julia has exited. When the handle is dropped, all data
+    /// rooted with this method is removed from the root set with `Base.unpreserve_handle`. An
+    /// error is returned if `Base.preserve_handle` throws an exception.
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// # use jlrs::prelude::*;
+    /// # fn main() {
+    /// let julia = Builder::new().start_local().unwrap();
+    /// let value = julia.local_scope::<_, 1>(|mut frame| {
+    ///     let value = Value::new(&mut frame, 1usize);
+    ///     julia.root_global(value)
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn root_global<'a, 'scope, M>(&'a self, value: M) -> JlrsResult<M::InScope<'a>>
+    where
+        M: Managed<'scope, 'static>,
+    {
+        self.local_scope::<_, 1>(|mut frame| unsafe {
+            let preserve =
+                inline_static_ref!(PRESERVE_HANDLE, Value, "Base.preserve_handle", &frame);
+
+            // Safety: Base.preserve_handle only stores the value in a global IdDict, exceptions
+            // are caught.
+            preserve
+                .call1(&mut frame, value.as_value())
+                .into_jlrs_result()?;
+            let ptr = value.unwrap_non_null(Private);
+            self.roots
+                .borrow_mut()
+                .push(value.as_ref().leak().as_value());
+            Ok(M::InScope::<'a>::wrap_non_null(ptr.cast(), Private))
+        })
+    }
+
     pub(crate) unsafe fn new() -> Self {
         LocalHandle {
+            roots: RefCell::new(Vec::new()),
             _marker: PhantomData,
         }
     }
@@ -80,6 +229,23 @@ impl LocalHandle {
 impl Drop for LocalHandle {
     fn drop(&mut self) {
         unsafe {
+            run_shutdown_hooks();
+
+            let roots = self.roots.get_mut();
+            if !roots.is_empty() {
+                let unrooted = Unrooted::new();
+                let unpreserve = inline_static_ref!(
+                    UNPRESERVE_HANDLE,
+                    Value,
+                    "Base.unpreserve_handle",
+                    &unrooted
+                );
+
+                for root in roots.drain(..) {
+                    unpreserve.call1(unrooted, root).ok();
+                }
+            }
+
             jl_atexit_hook(0);
             set_exit();
         }
@@ -14,15 +14,14 @@ use jl_sys::{jl_adopt_thread, jlrs_clear_gc_stack, jlrs_gc_safe_enter, jlrs_ptls
 use once_cell::sync::OnceCell;
 
 use crate::{
+    async_util::cancellation_token::CancellationToken,
     memory::gc::gc_unsafe_with,
     prelude::StackFrame,
     runtime::{
+        builder::{thread_builder, thread_name_prefix},
         executor::Executor,
         handle::{
-            async_handle::{
-                cancellation_token::CancellationToken, channel::channel, message::Message,
-                on_adopted_thread, AsyncHandle,
-            },
+            async_handle::{channel::channel, message::Message, on_adopted_thread, AsyncHandle},
             mt_handle::drop_handle,
         },
     },
@@ -344,29 +343,31 @@ pub(crate) fn get_manager() -> &'static Manager {
     MANAGER.get_or_init(|| {
         let (sender, receiver) = mpsc_channel();
 
-        let _ = thread::spawn(move || {
-            let mut pools = Pools::new();
-            loop {
-                match receiver.recv() {
-                    Ok(ManagerMsg::SpawnPool {
-                        pool_id,
-                        n_workers,
-                        receiver,
-                        spawner,
-                    }) => pools.spawn_pool(pool_id, n_workers, receiver, spawner),
-                    Ok(ManagerMsg::DropPool { pool_id }) => pools.cancel_all_workers(pool_id),
-                    Ok(ManagerMsg::AddWorker { pool_id }) => pools.add_worker(pool_id),
-                    Ok(ManagerMsg::RemoveWorker { pool_id }) => pools.cancel_worker(pool_id),
-                    Ok(ManagerMsg::DropWorker { pool_id, worker_id }) => {
-                        pools.drop_worker(pool_id, worker_id)
-                    }
-                    Ok(ManagerMsg::RestartWorker { pool_id, worker_id }) => {
-                        pools.restart_worker(pool_id, worker_id)
+        thread_builder("manager")
+            .spawn(move || {
+                let mut pools = Pools::new();
+                loop {
+                    match receiver.recv() {
+                        Ok(ManagerMsg::SpawnPool {
+                            pool_id,
+                            n_workers,
+                            receiver,
+                            spawner,
+                        }) => pools.spawn_pool(pool_id, n_workers, receiver, spawner),
+                        Ok(ManagerMsg::DropPool { pool_id }) => pools.cancel_all_workers(pool_id),
+                        Ok(ManagerMsg::AddWorker { pool_id }) => pools.add_worker(pool_id),
+                        Ok(ManagerMsg::RemoveWorker { pool_id }) => pools.cancel_worker(pool_id),
+                        Ok(ManagerMsg::DropWorker { pool_id, worker_id }) => {
+                            pools.drop_worker(pool_id, worker_id)
+                        }
+                        Ok(ManagerMsg::RestartWorker { pool_id, worker_id }) => {
+                            pools.restart_worker(pool_id, worker_id)
+                        }
+                        Err(_) => break,
                     }
-                    Err(_) => break,
                 }
-            }
-        });
+            })
+            .expect("failed to spawn thread");
 
         Manager { sender }
     })
@@ -380,7 +381,10 @@ pub(super) fn spawn_worker<R: Executor<N>, const N: usize>(
     worker_id: WorkerId,
     receiver: Receiver<Message>,
 ) -> JoinHandle<()> {
-    let prefix = prefix.unwrap_or_else(|| "jlrs".into()).replace('\0', "");
+    let prefix = prefix
+        .or_else(|| thread_name_prefix().map(|prefix| format!("{prefix}-worker")))
+        .unwrap_or_else(|| "jlrs".into())
+        .replace('\0', "");
 
     let name = format!("{}-{}-{}", prefix, pool_id.inner(), worker_id.inner());
     thread::Builder::new()
@@ -29,7 +29,10 @@ use crate::{
     error::{IOError, CANNOT_DISPLAY_VALUE},
     memory::{gc::gc_unsafe, get_tls, scope::LocalReturning},
     prelude::{JlrsResult, JuliaString, LocalScope, Managed, Value},
-    runtime::state::{set_exit, set_pending_exit},
+    runtime::{
+        builder::thread_builder,
+        state::{set_exit, set_pending_exit},
+    },
     weak_handle_unchecked,
 };
 
@@ -41,6 +44,7 @@ thread_local! {
 }
 
 pub(super) static N_HANDLES: AtomicUsize = AtomicUsize::new(0);
+static N_SPAWNED: AtomicUsize = AtomicUsize::new(0);
 pub(crate) static EXIT_LOCK: (Mutex<bool>, Condvar) = (Mutex::new(false), Condvar::new());
 
 /// A handle that lets you call into Julia from arbitrary threads.
@@ -56,6 +60,15 @@ pub struct MtHandle<'scope, 'env> {
 
 impl<'scope, 'env> MtHandle<'scope, 'env> {
     /// Prepares the environment to enable calling into Julia and calls `func`.
+    ///
+    /// The first time this method is called on a thread that hasn't been created by Julia, the
+    /// thread is adopted by Julia. This allocates the thread's local state, task and GC stack,
+    /// and is the only expensive part of the first call. It can't be done in advance because a
+    /// thread can only adopt itself. No jlrs stack is allocated, the scopes created by `func` use
+    /// the GC frames of the adopted task, and a dynamically-sized stack is only allocated when
+    /// [`WithStack::with_stack`] is called.
+    ///
+    /// [`WithStack::with_stack`]: crate::runtime::handle::with_stack::WithStack::with_stack
     pub fn with<T, F>(&mut self, func: F) -> T
     where
         for<'ctx> F: FnOnce(ActiveHandle<'ctx>) -> T,
@@ -72,13 +85,21 @@ impl<'scope, 'env> MtHandle<'scope, 'env> {
         }
     }
 
+    /// Spawn a new scoped thread that can call into Julia and call `f` with a new handle.
+    ///
+    /// The thread is adopted by Julia the first time [`MtHandle::with`] is called on it. To move
+    /// this cost out of a latency-sensitive path, call `with` with an empty closure right after
+    /// the thread has started.
     pub fn spawn<F, T>(&self, f: F) -> ScopedJoinHandle<'scope, T>
     where
         F: FnOnce(Self) -> T + Send + 'scope,
         T: Send + 'scope,
     {
         let s = self.clone();
-        self.scope.spawn(|| f(s))
+        let index = N_SPAWNED.fetch_add(1, Ordering::Relaxed);
+        thread_builder(index)
+            .spawn_scoped(self.scope, || f(s))
+            .expect("failed to spawn thread")
     }
 
     pub(crate) unsafe fn new(scope: &'scope Scope<'scope, 'env>) -> Self {
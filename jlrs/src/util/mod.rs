@@ -1,4 +1,7 @@
-use std::ffi::{c_int, c_void};
+use std::{
+    any::Any,
+    ffi::{c_int, c_void},
+};
 #[cfg(not(any(windows, target_os = "windows", feature = "windows")))]
 use std::{ffi::CStr, process::abort, ptr::null_mut, sync::atomic::AtomicPtr};
 
@@ -15,6 +18,16 @@ pub trait RequireSendSync: 'static + Send + Sync {}
 
 pub trait RequireSend: Send {}
 
+// Returns the message of a panic payload.
+#[cfg_attr(not(any(feature = "async-rt", feature = "ccall")), allow(dead_code))]
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown error")
+}
+
 pub(crate) type UvAsyncSendFn = unsafe extern "C" fn(handle: *mut c_void) -> c_int;
 
 #[cfg(any(windows, target_os = "windows", feature = "windows"))]
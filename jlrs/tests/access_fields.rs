@@ -830,6 +830,126 @@ mod tests {
         })
     }
 
+    fn access_overloaded_properties() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let ty = Value::eval_string(
+                        &mut frame,
+                        "mutable struct WithComputedProperty
+                            x::Int
+                        end
+                        Base.getproperty(w::WithComputedProperty, s::Symbol) =
+                            s === :double ? 2 * getfield(w, :x) : getfield(w, s)
+                        function Base.setproperty!(w::WithComputedProperty, s::Symbol, v)
+                            s === :double ? setfield!(w, :x, v ÷ 2) : setfield!(w, s, v)
+                        end
+                        WithComputedProperty",
+                    )
+                    .into_jlrs_result()?;
+
+                    let x = Value::new(&mut frame, 3isize);
+                    let value = ty.call1(&mut frame, x).into_jlrs_result()?;
+
+                    let double = value
+                        .get_property(&mut frame, "double")
+                        .into_jlrs_result()?;
+                    assert_eq!(double.unbox::<isize>()?, 6);
+                    assert!(value.get_field(&mut frame, "double").is_err());
+
+                    let new_double = Value::new(&mut frame, 10isize);
+                    let res = value.set_property(&frame, "double", new_double);
+                    assert!(res.is_ok());
+
+                    let x = value.get_property(&mut frame, "x").into_jlrs_result()?;
+                    assert_eq!(x.unbox::<isize>()?, 5);
+
+                    let res = value.get_property(&frame, "nonexistent");
+                    assert!(res.is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn access_field_by_name() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value = Value::eval_string(
+                        &mut frame,
+                        "struct WithBitsUnionField
+                            a::Int
+                            b::Union{Int8, Float32}
+                        end
+                        WithBitsUnionField(3, 2.0f0)",
+                    )
+                    .into_jlrs_result()?;
+
+                    let names = value.field_names();
+                    assert_eq!(names.len(), 2);
+                    assert_eq!(names[0].as_str()?, "a");
+                    assert_eq!(names[1].as_str()?, "b");
+
+                    assert_eq!(value.get_field_by_name::<isize, _, _>(&frame, "a")?, 3);
+                    assert_eq!(value.get_field_by_name::<f32, _, _>(&frame, "b")?, 2.0);
+                    assert!(value.get_field_by_name::<i8, _, _>(&frame, "b").is_err());
+                    assert!(value.get_field_by_name::<isize, _, _>(&frame, "c").is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn set_property_copy() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value = Value::eval_string(
+                        &mut frame,
+                        "struct OrderedPair
+                            lo::Int
+                            hi::Int
+                            OrderedPair(lo, hi) = lo <= hi ? new(lo, hi) : error(\"unordered\")
+                        end
+                        OrderedPair(1, 3)",
+                    )
+                    .into_jlrs_result()?;
+
+                    let hi = Value::new(&mut frame, 5isize);
+                    let copy = value
+                        .set_property_copy(&mut frame, "hi", hi)?
+                        .into_jlrs_result()?;
+                    assert_eq!(copy.get_field_by_name::<isize, _, _>(&frame, "lo")?, 1);
+                    assert_eq!(copy.get_field_by_name::<isize, _, _>(&frame, "hi")?, 5);
+                    assert_eq!(value.get_field_by_name::<isize, _, _>(&frame, "hi")?, 3);
+
+                    // The inner constructor rejects this combination of fields.
+                    let lo = Value::new(&mut frame, 4isize);
+                    assert!(value.set_property_copy(&mut frame, "lo", lo)?.is_err());
+
+                    assert!(value.set_property_copy(&mut frame, "mid", lo).is_err());
+
+                    let mutable = Value::eval_string(&mut frame, "Ref(1)").into_jlrs_result()?;
+                    assert!(mutable.set_property_copy(&mut frame, "x", lo).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     #[test]
     fn access_field_tests() {
         empty_union_field();
@@ -843,5 +963,8 @@ mod tests {
         access_bounds_error_fields_output();
         access_bounds_error_fields_output_oob();
         access_nested_field();
+        access_overloaded_properties();
+        access_field_by_name();
+        set_property_copy();
     }
 }
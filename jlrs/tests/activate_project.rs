@@ -0,0 +1,51 @@
+#[cfg(feature = "local-rt")]
+mod activate_project {
+    use std::fs;
+
+    use jlrs::{
+        error::{IOError, JlrsError},
+        prelude::*,
+    };
+
+    #[test]
+    fn activate_project() {
+        let mut julia = Builder::new().start_local().unwrap();
+
+        let res = unsafe { julia.activate_project("/this/project/does/not/exist") };
+        assert!(matches!(
+            res.unwrap_err().as_ref(),
+            JlrsError::IOError(IOError::NotFound { .. })
+        ));
+
+        let dir = std::env::temp_dir().join("jlrs_activate_project");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("Project.toml"), "").unwrap();
+
+        // JlrsCore can still be found through the default environment in the load path.
+        unsafe { julia.activate_project(&dir) }.unwrap();
+
+        julia.local_scope::<_, 1>(|mut frame| {
+            let project = unsafe { Value::eval_string(&mut frame, "Base.active_project()") }
+                .unwrap()
+                .unbox::<String>()
+                .unwrap()
+                .unwrap();
+
+            assert!(project.starts_with(dir.to_str().unwrap()));
+        });
+
+        // Named environments are resolved in the depot and don't have to exist yet.
+        unsafe { julia.activate_project("@jlrs_activate_project") }.unwrap();
+
+        julia.local_scope::<_, 1>(|mut frame| {
+            let project = unsafe { Value::eval_string(&mut frame, "Base.active_project()") }
+                .unwrap()
+                .unbox::<String>()
+                .unwrap()
+                .unwrap();
+
+            assert!(project.contains("jlrs_activate_project"));
+            assert!(!project.starts_with(dir.to_str().unwrap()));
+        });
+    }
+}
@@ -4,10 +4,13 @@ use array_tests_impl::*;
 
 #[test]
 fn array_tests() {
+    #[cfg(feature = "unstable")]
+    array_atomic_tests();
     array_bits_data_mut_tests();
     array_bits_data_tests();
     array_constructor_tests();
     array_conversion_tests();
+    array_copy_tests();
     array_fields_and_flags_tests();
     array_grow_del_tests();
     array_inline_data_mut_tests();
@@ -15,6 +18,9 @@ fn array_tests() {
     array_layouts_tests();
     array_managed_data_mut_tests();
     array_managed_data_tests();
+    array_reshape_tests();
+    array_sort_tests();
+    array_structured_tests();
     array_type_constructor_tests();
     array_union_data_mut_tests();
     array_union_data_tests();
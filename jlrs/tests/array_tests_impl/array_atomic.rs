@@ -0,0 +1,66 @@
+#[cfg(all(feature = "local-rt", feature = "unstable"))]
+pub(crate) mod tests {
+    use jlrs::{data::managed::value::memory_order::MemoryOrder, prelude::*};
+
+    use crate::util::JULIA;
+
+    fn atomic_get_and_set() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let arr = TypedVector::<u64>::from_vec(&mut frame, vec![1, 2, 3], 3)?
+                        .into_jlrs_result()?;
+
+                    assert_eq!(arr.atomic_get(1, MemoryOrder::Acquire)?, 2);
+                    arr.atomic_set(1, 5, MemoryOrder::Release)?;
+                    assert_eq!(arr.atomic_get(1, MemoryOrder::SequentiallyConsistent)?, 5);
+                    assert_eq!(arr.bits_data().as_slice(), &[1, 5, 3]);
+
+                    assert!(arr.atomic_get(3, MemoryOrder::Monotonic).is_err());
+                    assert!(arr.atomic_get(0, MemoryOrder::Release).is_err());
+                    assert!(arr.atomic_set(0, 1, MemoryOrder::Acquire).is_err());
+                    assert!(arr.atomic_set(0, 1, MemoryOrder::NotAtomic).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn atomic_cas() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let arr = TypedArray::<i32>::from_vec(&mut frame, vec![1, 2, 3, 4], (2, 2))?
+                        .into_jlrs_result()?;
+
+                    let res = arr.atomic_cas((1, 1), 4, 8, MemoryOrder::AcquireRelease)?;
+                    assert_eq!(res, Ok(4));
+
+                    let res = arr.atomic_cas((1, 1), 4, 16, MemoryOrder::SequentiallyConsistent)?;
+                    assert_eq!(res, Err(8));
+
+                    assert_eq!(arr.atomic_get((1, 1), MemoryOrder::Monotonic)?, 8);
+                    assert!(arr
+                        .atomic_cas((0, 0), 1, 2, MemoryOrder::NotAtomic)
+                        .is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    pub(crate) fn array_atomic_tests() {
+        atomic_get_and_set();
+        atomic_cas();
+    }
+}
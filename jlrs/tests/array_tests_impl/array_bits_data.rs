@@ -1,7 +1,11 @@
 #[cfg(feature = "local-rt")]
 pub(crate) mod tests {
     use jlrs::{
-        data::{managed::array::data::accessor::Accessor, types::construct_type::ConstantBool},
+        convert::into_jlrs_result::IntoJlrsResult,
+        data::{
+            layout::tuple::Tuple2, managed::array::data::accessor::Accessor,
+            types::construct_type::ConstantBool,
+        },
         prelude::*,
     };
 
@@ -288,6 +292,190 @@ pub(crate) mod tests {
         });
     }
 
+    fn bits_data_as_raw_bytes() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let data = vec![1u16, 2, 3, 4];
+                    let arr = TypedArray::<u16>::from_vec(&mut frame, data, (2, 2))
+                        .unwrap()
+                        .unwrap();
+
+                    let bytes = arr.as_raw_bytes()?;
+                    assert_eq!(bytes.len(), 4 * std::mem::size_of::<u16>());
+                    let expected = [1u16, 2, 3, 4]
+                        .iter()
+                        .flat_map(|x| x.to_ne_bytes())
+                        .collect::<Vec<_>>();
+                    assert_eq!(&*bytes, expected.as_slice());
+
+                    assert!(arr.track_exclusive().is_err());
+                    std::mem::drop(bytes);
+                    assert!(arr.track_exclusive().is_ok());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_as_raw_bytes_padding() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let arr = TypedArray::<Tuple2<i8, i16>>::new(&mut frame, (2, 2))
+                        .into_jlrs_result()?;
+                    assert!(arr.as_raw_bytes().is_err());
+                    assert!(arr.track_exclusive().is_ok());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_tracked() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let data = vec![1u16, 2, 3, 4];
+                    let arr = TypedArray::<u16>::from_vec(&mut frame, data, (2, 2))
+                        .unwrap()
+                        .unwrap();
+
+                    let slice = arr.bits_data_tracked()?;
+                    assert_eq!(&*slice, &[1, 2, 3, 4]);
+
+                    assert!(arr.track_exclusive().is_err());
+                    std::mem::drop(slice);
+                    assert!(arr.track_exclusive().is_ok());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_tracked_chunks() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let data = vec![1u16, 2, 3, 4, 5];
+                    let arr = TypedVector::<u16>::from_vec(&mut frame, data, 5)
+                        .unwrap()
+                        .unwrap();
+
+                    let slice = arr.bits_data_tracked()?;
+                    let chunks = slice.chunks(2).collect::<Vec<_>>();
+                    assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+
+                    let sums = slice
+                        .windows(2)
+                        .map(|w| w.iter().sum::<u16>())
+                        .collect::<Vec<_>>();
+                    assert_eq!(sums, [3, 5, 7, 9]);
+
+                    assert!(arr.track_exclusive().is_err());
+                    std::mem::drop(slice);
+                    assert!(arr.track_exclusive().is_ok());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_typed_iter() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let empty = TypedVector::<f32>::new(&mut frame, 0).into_jlrs_result()?;
+                    assert_eq!(empty.typed_iter().count(), 0);
+
+                    let single = TypedVector::<f32>::from_vec(&mut frame, vec![1.0], 1)
+                        .unwrap()
+                        .into_jlrs_result()?;
+                    assert_eq!(single.typed_iter().collect::<Vec<_>>(), [1.0]);
+
+                    let data = vec![1u16, 2, 3, 4];
+                    let arr = TypedArray::<u16>::from_vec(&mut frame, data, (2, 2))
+                        .unwrap()
+                        .into_jlrs_result()?;
+                    assert_eq!(arr.typed_iter().sum::<u16>(), 10);
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_typed_iter_derived() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        #[derive(
+                            ValidField,
+                            ValidLayout,
+                            IsBits,
+                            ConstructType,
+                            Debug,
+                            Clone,
+                            Copy,
+                            Typecheck,
+                            Unbox,
+                            PartialEq,
+                        )]
+                        #[jlrs(julia_type = "Main.TypedIterPoint")]
+                        #[repr(C)]
+                        struct TypedIterPoint {
+                            pub(crate) x: i32,
+                            pub(crate) y: i32,
+                        }
+
+                        Value::eval_string(&frame, "struct TypedIterPoint x::Int32; y::Int32 end")
+                            .unwrap();
+                        let data =
+                            vec![TypedIterPoint { x: 1, y: 2 }, TypedIterPoint { x: 3, y: 4 }];
+                        let arr = TypedVector::<TypedIterPoint>::from_vec(&mut frame, data, 2)?
+                            .into_jlrs_result()?;
+                        let points = arr.typed_iter().collect::<Vec<_>>();
+                        assert_eq!(
+                            points,
+                            [TypedIterPoint { x: 1, y: 2 }, TypedIterPoint { x: 3, y: 4 }]
+                        );
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     pub(crate) fn array_bits_data_tests() {
         bits_data();
         bits_data_get();
@@ -300,5 +488,11 @@ pub(crate) mod tests {
         try_bits_data();
         try_bits_data_err();
         bits_data_unchecked();
+        bits_data_as_raw_bytes();
+        bits_data_as_raw_bytes_padding();
+        bits_data_tracked();
+        bits_data_tracked_chunks();
+        bits_data_typed_iter();
+        bits_data_typed_iter_derived();
     }
 }
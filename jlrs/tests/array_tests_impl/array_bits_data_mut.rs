@@ -1,6 +1,7 @@
 #[cfg(feature = "local-rt")]
 pub(crate) mod tests {
     use jlrs::{
+        convert::into_jlrs_result::IntoJlrsResult,
         data::{
             managed::array::{
                 data::accessor::{Accessor, AccessorMut},
@@ -369,6 +370,154 @@ pub(crate) mod tests {
         });
     }
 
+    fn bits_data_mut_tracked() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        let data = vec![1.0, 2.0];
+                        let arr = TypedArray::<f32>::from_vec_unchecked(&mut frame, data, (1, 2));
+                        let mut slice = arr.bits_data_mut_tracked()?;
+                        slice[0] = 2.0;
+                        slice[1] = 3.0;
+                        assert_eq!(&*slice, &[2.0, 3.0]);
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_mut_tracked_already_tracked() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        let data = vec![1.0, 2.0];
+                        let arr = TypedArray::<f32>::from_vec_unchecked(&mut frame, data, (1, 2));
+                        let slice = arr.bits_data_mut_tracked()?;
+                        assert!(arr.bits_data_mut_tracked().is_err());
+                        assert!(arr.track_shared().is_err());
+                        std::mem::drop(slice);
+                        assert!(arr.bits_data_mut_tracked().is_ok());
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn typed_array_swap() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        let data = vec![1.0, 2.0, 3.0];
+                        let mut arr = TypedArray::<f32>::from_vec_unchecked(&mut frame, data, 3);
+                        arr.swap(0, 2)?;
+                        assert_eq!(arr.bits_data().as_slice(), &[3.0, 2.0, 1.0]);
+                        assert!(arr.swap(0, 3).is_err());
+
+                        let slice = arr.bits_data_mut_tracked()?;
+                        assert!(arr.swap(0, 1).is_err());
+                        std::mem::drop(slice);
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn typed_array_fill() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        let data = vec![1.0, 2.0, 3.0, 4.0];
+                        let mut arr =
+                            TypedArray::<f32>::from_vec_unchecked(&mut frame, data, (2, 2));
+                        arr.fill(5.0)?;
+                        assert_eq!(arr.bits_data().as_slice(), &[5.0; 4]);
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn typed_array_fill_new() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        let mut arr =
+                            TypedArray::<u64>::new(&mut frame, (256, 256)).into_jlrs_result()?;
+
+                        arr.fill(0)?;
+                        assert!(arr.bits_data().as_slice().iter().all(|&x| x == 0));
+
+                        arr.fill(7)?;
+                        assert!(arr.bits_data().as_slice().iter().all(|&x| x == 7));
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn bits_data_typed_iter_mut() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let mut empty = TypedVector::<f32>::new(&mut frame, 0).into_jlrs_result()?;
+                    assert_eq!(empty.typed_iter_mut().count(), 0);
+
+                    let data = vec![1u16, 2, 3, 4];
+                    let mut arr = TypedArray::<u16>::from_vec(&mut frame, data, (2, 2))
+                        .unwrap()
+                        .into_jlrs_result()?;
+
+                    for elem in arr.typed_iter_mut() {
+                        *elem *= 2;
+                    }
+
+                    assert_eq!(arr.bits_data().as_slice(), &[2, 4, 6, 8]);
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     pub(crate) fn array_bits_data_mut_tests() {
         bits_data_mut();
         bits_data_mut_rank0();
@@ -382,5 +531,11 @@ pub(crate) mod tests {
         try_bits_data_mut();
         try_bits_data_mut_err();
         bits_data_mut_unchecked();
+        bits_data_mut_tracked();
+        bits_data_mut_tracked_already_tracked();
+        typed_array_swap();
+        typed_array_fill();
+        typed_array_fill_new();
+        bits_data_typed_iter_mut();
     }
 }
@@ -0,0 +1,160 @@
+#[cfg(feature = "local-rt")]
+pub(crate) mod tests {
+    use jlrs::{
+        data::managed::array::{
+            data::accessor::{Accessor, AccessorMut},
+            TypedVector, VectorAny,
+        },
+        error::{AccessError, JlrsError},
+        prelude::*,
+    };
+
+    use crate::util::JULIA;
+
+    fn typed_vector_copyto_bits() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let src = TypedVector::<f32>::from_slice_copied(&mut frame, [1.0, 2.0, 3.0], 3)
+                    .unwrap()
+                    .unwrap();
+                let mut dst = TypedVector::<f32>::new(&mut frame, 3).unwrap();
+
+                let res = dst.copyto(&frame, src).unwrap();
+                assert!(res.is_ok());
+
+                let accessor = dst.bits_data();
+                assert_eq!(accessor.as_slice(), &[1.0, 2.0, 3.0]);
+            });
+        });
+    }
+
+    fn typed_vector_copyto_length_mismatch() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let src = TypedVector::<f32>::new(&mut frame, 3).unwrap();
+                let mut dst = TypedVector::<f32>::new(&mut frame, 2).unwrap();
+
+                let err = dst.copyto(&frame, src).unwrap_err();
+                match *err {
+                    JlrsError::AccessError(AccessError::ArrayLengthMismatch {
+                        dst_len,
+                        src_len,
+                    }) => {
+                        assert_eq!(dst_len, 2);
+                        assert_eq!(src_len, 3);
+                    }
+                    _ => panic!("unexpected error"),
+                }
+            });
+        });
+    }
+
+    fn typed_vector_copy_inline_data_into() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let src = TypedVector::<f32>::from_slice_copied(&mut frame, [1.0, 2.0, 3.0], 3)
+                    .unwrap()
+                    .unwrap();
+
+                let mut dst = [0.0f32; 3];
+                src.copy_inline_data_into(&mut dst).unwrap();
+                assert_eq!(dst, [1.0, 2.0, 3.0]);
+
+                let mut too_short = [0.0f32; 2];
+                let err = src.copy_inline_data_into(&mut too_short).unwrap_err();
+                assert!(matches!(
+                    *err,
+                    JlrsError::AccessError(AccessError::ArrayLengthMismatch {
+                        dst_len: 2,
+                        src_len: 3
+                    })
+                ));
+
+                let mut wrong_layout = [0u64; 3];
+                assert!(src.copy_inline_data_into(&mut wrong_layout).is_err());
+            });
+        });
+    }
+
+    fn vector_any_copy_inline_data_into_err() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let src = VectorAny::new_any(&mut frame, 2).unwrap();
+                let mut dst = [0usize; 2];
+                assert!(src.copy_inline_data_into(&mut dst).is_err());
+            });
+        });
+    }
+
+    fn vector_any_copyto() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let mut src = VectorAny::new_any(&mut frame, 2).unwrap();
+                let v1 = Value::new(&mut frame, 1usize);
+                let v2 = Value::new(&mut frame, 2.0f64);
+                {
+                    let mut accessor = src.value_data_mut();
+                    accessor.set_value(&frame, 0, v1).unwrap().unwrap();
+                    accessor.set_value(&frame, 1, v2).unwrap().unwrap();
+                }
+
+                let mut dst = VectorAny::new_any(&mut frame, 2).unwrap();
+                let res = dst.copyto(&frame, src).unwrap();
+                assert!(res.is_ok());
+
+                let accessor = dst.value_data();
+                let e1 = accessor.get_value(&mut frame, 0).unwrap().unwrap();
+                let e2 = accessor.get_value(&mut frame, 1).unwrap().unwrap();
+                assert_eq!(e1.unbox::<usize>().unwrap(), 1);
+                assert_eq!(e2.unbox::<f64>().unwrap(), 2.0);
+            });
+        });
+    }
+
+    #[cfg(feature = "smallvec")]
+    fn typed_vector_to_smallvec() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let arr = TypedVector::<f32>::from_slice_copied(&mut frame, [1.0, 2.0, 3.0], 3)
+                    .unwrap()
+                    .unwrap();
+
+                let inline = arr.to_smallvec::<4>();
+                assert!(!inline.spilled());
+                assert_eq!(inline.as_slice(), &[1.0, 2.0, 3.0]);
+
+                let spilled = arr.to_smallvec::<2>();
+                assert!(spilled.spilled());
+                assert_eq!(spilled.as_slice(), &[1.0, 2.0, 3.0]);
+            });
+        });
+    }
+
+    pub(crate) fn array_copy_tests() {
+        typed_vector_copyto_bits();
+        typed_vector_copyto_length_mismatch();
+        typed_vector_copy_inline_data_into();
+        vector_any_copy_inline_data_into_err();
+        vector_any_copyto();
+        #[cfg(feature = "smallvec")]
+        typed_vector_to_smallvec();
+    }
+}
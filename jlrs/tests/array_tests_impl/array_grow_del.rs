@@ -106,6 +106,68 @@ pub(crate) mod tests {
         });
     }
 
+    #[cfg(debug_assertions)]
+    fn typed_vector_tracked_slice_invalidated() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let data = [1.0f32, 2.0];
+                let mut arr = TypedVector::<f32>::from_slice_cloned(&mut frame, data.as_ref(), 2)
+                    .unwrap()
+                    .unwrap();
+
+                let slice = arr.bits_data_tracked().unwrap();
+                assert_eq!(&*slice, &[1.0, 2.0]);
+
+                let success = arr.bits_data_mut().grow_end(&frame, 100);
+                assert!(success.is_ok());
+
+                let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| slice.len()));
+                assert!(res.is_err());
+            });
+        });
+    }
+
+    fn typed_array_grow_delete_end() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let data = [1.0f32, 2.0];
+                let arr = TypedVector::<f32>::from_slice_cloned(&mut frame, data.as_ref(), 2)
+                    .unwrap()
+                    .unwrap();
+
+                let success = arr.grow_end(&frame, 3).unwrap();
+                assert!(success.is_ok());
+                assert_eq!(arr.length(), 5);
+
+                let success = arr.delete_end(&frame, 4).unwrap();
+                assert!(success.is_ok());
+                assert_eq!(arr.length(), 1);
+                assert_eq!(arr.bits_data()[0], 1.0);
+
+                let success = arr.delete_end(&frame, 2).unwrap();
+                assert!(success.is_err());
+                assert_eq!(arr.length(), 1);
+
+                let arr = TypedArray::<f32>::new(&mut frame, (2, 2)).unwrap();
+                assert!(arr.grow_end(&frame, 1).is_err());
+                assert!(arr.delete_end(&frame, 1).is_err());
+                assert_eq!(arr.length(), 4);
+
+                let arr = TypedVector::<f32>::new(&mut frame, 2).unwrap();
+                let tracked = arr.track_shared().unwrap();
+                assert!(arr.grow_end(&frame, 1).is_err());
+                std::mem::drop(tracked);
+                assert!(arr.grow_end(&frame, 1).is_ok());
+            });
+        });
+    }
+
     pub(crate) fn array_grow_del_tests() {
         typed_vector_grow_end();
         #[cfg(any(feature = "julia-1-10"))]
@@ -115,6 +177,11 @@ pub(crate) mod tests {
         #[cfg(any(feature = "julia-1-10"))]
         typed_vector_del_end_err();
 
+        typed_array_grow_delete_end();
+
+        #[cfg(debug_assertions)]
+        typed_vector_tracked_slice_invalidated();
+
         unsafe {
             let handle = weak_handle_unchecked!();
             handle.gc_collect(jl_sys::jl_gc_collection_t::Full);
@@ -0,0 +1,104 @@
+#[cfg(feature = "local-rt")]
+pub(crate) mod tests {
+    use jlrs::{
+        data::managed::array::{dimensions::Dims, TypedMatrix, TypedRankedArray, TypedVector},
+        error::{InstantiationError, JlrsError},
+        prelude::*,
+    };
+
+    use crate::util::JULIA;
+
+    fn typed_matrix_try_reshape() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let mut arr = TypedMatrix::<f64>::from_slice_copied(
+                    &mut frame,
+                    [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                    [2, 3],
+                )
+                .unwrap()
+                .unwrap();
+
+                let reshaped = arr.try_reshape(&mut frame, [3, 2]).unwrap();
+                assert_eq!(reshaped.n_dims(), 2);
+                assert_eq!(reshaped.dimensions().n_elements(0), Some(3));
+                assert_eq!(reshaped.dimensions().n_elements(1), Some(2));
+                assert_eq!(reshaped.element_type(), arr.element_type());
+                assert_eq!(reshaped.data_ptr(), arr.data_ptr());
+
+                arr.bits_data_mut()[[0, 0]] = 7.0;
+                assert_eq!(reshaped.bits_data().as_slice()[0], 7.0);
+
+                let vec = arr.try_reshape(&mut frame, [6]).unwrap();
+                assert_eq!(vec.n_dims(), 1);
+            });
+        });
+    }
+
+    fn typed_matrix_try_reshape_err() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| {
+                let arr =
+                    TypedMatrix::<f64>::from_slice_copied(&mut frame, [1.0, 2.0, 3.0, 4.0], [2, 2])
+                        .unwrap()
+                        .unwrap();
+
+                let err = arr.try_reshape(&mut frame, [3, 2]).unwrap_err();
+                assert!(matches!(
+                    err.as_ref(),
+                    JlrsError::InstantiationError(InstantiationError::ArraySizeMismatch { .. })
+                ));
+
+                let err = arr.try_reshape(&mut frame, []).unwrap_err();
+                assert!(matches!(
+                    err.as_ref(),
+                    JlrsError::InstantiationError(InstantiationError::ZeroDimensionalReshape)
+                ));
+            });
+        });
+    }
+
+    fn typed_matrix_reshape_ranked() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let arr = TypedMatrix::<f64>::from_slice_copied(
+                    &mut frame,
+                    [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                    [2, 3],
+                )
+                .unwrap()
+                .unwrap();
+
+                let reshaped: TypedRankedArray<f64, 3> =
+                    arr.reshape(&mut frame, [1, 3, 2]).unwrap();
+                assert_eq!(reshaped.n_dims(), 3);
+                assert_eq!(reshaped.dimensions().n_elements(2), Some(2));
+                assert_eq!(reshaped.data_ptr(), arr.data_ptr());
+
+                let vec: TypedVector<f64> = arr.reshape(&mut frame, [6]).unwrap();
+                assert_eq!(vec.bits_data().as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+                let err = arr.reshape::<_, 1, 1>(&mut frame, [5]).unwrap_err();
+                assert!(matches!(
+                    err.as_ref(),
+                    JlrsError::InstantiationError(InstantiationError::ArraySizeMismatch { .. })
+                ));
+            });
+        });
+    }
+
+    pub(crate) fn array_reshape_tests() {
+        typed_matrix_try_reshape();
+        typed_matrix_try_reshape_err();
+        typed_matrix_reshape_ranked();
+    }
+}
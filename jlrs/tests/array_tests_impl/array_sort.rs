@@ -0,0 +1,52 @@
+#[cfg(feature = "local-rt")]
+pub(crate) mod tests {
+    use jlrs::{data::managed::array::TypedVector, prelude::*};
+
+    use crate::util::JULIA;
+
+    fn typed_vector_sort() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let mut arr = TypedVector::<f64>::from_slice_copied(&mut frame, [3.0, 1.0, 2.0], 3)
+                    .unwrap()
+                    .unwrap();
+
+                let perm = arr.sortperm(&mut frame).unwrap();
+                assert_eq!(perm.bits_data().as_slice(), &[2, 3, 1]);
+
+                arr.sort(&frame).unwrap();
+                assert_eq!(arr.bits_data().as_slice(), &[1.0, 2.0, 3.0]);
+
+                let gt = Value::eval_string(&mut frame, ">").unwrap();
+                arr.sort_by(&frame, gt).unwrap();
+                assert_eq!(arr.bits_data().as_slice(), &[3.0, 2.0, 1.0]);
+            });
+        });
+    }
+
+    fn typed_vector_sort_tracked_err() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                let mut arr = TypedVector::<f64>::from_slice_copied(&mut frame, [3.0, 1.0, 2.0], 3)
+                    .unwrap()
+                    .unwrap();
+
+                let tracked = arr.track_shared().unwrap();
+                assert!(arr.sort(&frame).is_err());
+                std::mem::drop(tracked);
+                assert!(arr.sort(&frame).is_ok());
+            });
+        });
+    }
+
+    pub(crate) fn array_sort_tests() {
+        typed_vector_sort();
+        typed_vector_sort_tracked_err();
+    }
+}
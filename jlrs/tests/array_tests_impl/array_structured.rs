@@ -0,0 +1,76 @@
+#[cfg(feature = "local-rt")]
+pub(crate) mod tests {
+    use jlrs::{data::managed::array::TypedMatrix, prelude::*};
+
+    use crate::util::JULIA;
+
+    fn typed_matrix_diagonal() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                Value::eval_string(&frame, "using LinearAlgebra").unwrap();
+
+                let arr =
+                    TypedMatrix::<f64>::from_slice_copied(&mut frame, [1.0, 2.0, 3.0, 4.0], [2, 2])
+                        .unwrap()
+                        .unwrap();
+
+                let diag = arr
+                    .diagonal(&mut frame)
+                    .unwrap()
+                    .unwrap()
+                    .cast::<TypedVector<f64>>()
+                    .unwrap();
+                assert_eq!(diag.bits_data().as_slice(), &[1.0, 4.0]);
+
+                let diag_matrix = arr.diagonal_matrix(&mut frame).unwrap().unwrap();
+                assert_eq!(diag_matrix.datatype_name(), "Diagonal");
+            });
+        });
+    }
+
+    fn typed_matrix_structured_views() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame).scope(|mut frame| unsafe {
+                Value::eval_string(&frame, "using LinearAlgebra").unwrap();
+                let sum = Module::base(&frame).global(&mut frame, "sum").unwrap();
+
+                let arr =
+                    TypedMatrix::<f64>::from_slice_copied(&mut frame, [1.0, 2.0, 3.0, 4.0], [2, 2])
+                        .unwrap()
+                        .unwrap();
+
+                let upper = arr.upper_triangular(&mut frame).unwrap().unwrap();
+                assert_eq!(upper.datatype_name(), "UpperTriangular");
+                let upper_sum = sum.call1(&mut frame, upper).unwrap();
+                assert_eq!(upper_sum.unbox::<f64>().unwrap(), 8.0);
+
+                let lower = arr.lower_triangular(&mut frame).unwrap().unwrap();
+                assert_eq!(lower.datatype_name(), "LowerTriangular");
+                let lower_sum = sum.call1(&mut frame, lower).unwrap();
+                assert_eq!(lower_sum.unbox::<f64>().unwrap(), 7.0);
+
+                let symmetric = arr.symmetric(&mut frame).unwrap().unwrap();
+                assert_eq!(symmetric.datatype_name(), "Symmetric");
+                let symmetric_sum = sum.call1(&mut frame, symmetric).unwrap();
+                assert_eq!(symmetric_sum.unbox::<f64>().unwrap(), 11.0);
+
+                let non_square =
+                    TypedMatrix::<f64>::from_slice_copied(&mut frame, [1.0, 2.0], [1, 2])
+                        .unwrap()
+                        .unwrap();
+                assert!(non_square.symmetric(&mut frame).unwrap().is_err());
+            });
+        });
+    }
+
+    pub(crate) fn array_structured_tests() {
+        typed_matrix_diagonal();
+        typed_matrix_structured_views();
+    }
+}
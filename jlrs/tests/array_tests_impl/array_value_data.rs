@@ -6,6 +6,7 @@ pub(crate) mod tests {
         data::{
             managed::array::data::accessor::Accessor, types::construct_type::UnionTypeConstructor,
         },
+        error::{AccessError, JlrsError},
         prelude::*,
     };
 
@@ -205,6 +206,79 @@ pub(crate) mod tests {
         });
     }
 
+    fn value_data_get_value_checked() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        let arr = Value::eval_string(&mut frame, "Any[:foo :bar]")
+                            .unwrap()
+                            .cast::<TypedArray<Value>>()
+                            .unwrap();
+
+                        let accessor = arr.value_data();
+                        let s = accessor
+                            .get_value_checked(&mut frame, [0, 1])
+                            .unwrap()
+                            .cast::<Symbol>()
+                            .unwrap()
+                            .as_str()
+                            .unwrap();
+                        assert_eq!(s, "bar");
+
+                        let err = accessor.get_value_checked(&mut frame, [0, 2]).unwrap_err();
+                        assert!(matches!(
+                            err.as_ref(),
+                            JlrsError::AccessError(AccessError::InvalidIndex { .. })
+                        ));
+
+                        let undef = Value::eval_string(&mut frame, "Vector{Any}(undef, 1)")
+                            .unwrap()
+                            .cast::<TypedVector<Value>>()
+                            .unwrap();
+                        let err = undef
+                            .value_data()
+                            .get_value_checked(&mut frame, 0)
+                            .unwrap_err();
+                        assert!(matches!(
+                            err.as_ref(),
+                            JlrsError::AccessError(AccessError::UndefRef)
+                        ));
+
+                        let inline_undef =
+                            Value::eval_string(&mut frame, "Vector{Tuple{Symbol, Int}}(undef, 1)")
+                                .unwrap()
+                                .cast::<Vector>()
+                                .unwrap();
+                        let err = inline_undef
+                            .indeterminate_data()
+                            .get_value_checked(&mut frame, 0)
+                            .unwrap_err();
+                        assert!(matches!(
+                            err.as_ref(),
+                            JlrsError::AccessError(AccessError::UndefRef)
+                        ));
+
+                        let bits = TypedVector::<f64>::from_slice_copied(&mut frame, [1.0, 2.0], 2)
+                            .unwrap()
+                            .unwrap();
+                        let v = bits
+                            .indeterminate_data()
+                            .get_value_checked(&mut frame, 1)
+                            .unwrap();
+                        assert_eq!(v.unbox::<f64>().unwrap(), 2.0);
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     fn value_data_as_slice() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -417,6 +491,7 @@ pub(crate) mod tests {
         value_data_get_unchecked();
         value_data_get_value();
         value_data_get_value_unchecked();
+        value_data_get_value_checked();
         value_data_as_slice();
         value_data_into_slice();
         try_value_data();
@@ -1,3 +1,6 @@
+pub(crate) mod array_atomic;
+#[cfg(feature = "unstable")]
+pub(crate) use array_atomic::tests::*;
 pub(crate) mod array_bits_data_mut;
 pub(crate) use array_bits_data_mut::tests::*;
 pub(crate) mod array_conversions;
@@ -22,6 +25,14 @@ pub(crate) mod array_union_data_mut;
 pub(crate) use array_union_data_mut::tests::*;
 pub(crate) mod array_value_data;
 pub(crate) use array_value_data::tests::*;
+pub(crate) mod array_copy;
+pub(crate) use array_copy::tests::*;
+pub(crate) mod array_reshape;
+pub(crate) use array_reshape::tests::*;
+pub(crate) mod array_sort;
+pub(crate) use array_sort::tests::*;
+pub(crate) mod array_structured;
+pub(crate) use array_structured::tests::*;
 pub(crate) mod array_constructors;
 pub(crate) use array_constructors::tests::*;
 pub(crate) mod array_grow_del;
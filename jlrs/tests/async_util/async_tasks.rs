@@ -607,6 +607,74 @@ impl AsyncTask for MainSchedulingTask {
     }
 }
 
+pub struct InteractiveTask {
+    pub dims: isize,
+    pub iters: isize,
+}
+
+impl AsyncTask for InteractiveTask {
+    type Output = JlrsResult<f32>;
+
+    async fn run<'base>(self, mut frame: AsyncGcFrame<'base>) -> Self::Output {
+        let dims = Value::new(&mut frame, self.dims);
+        let iters = Value::new(&mut frame, self.iters);
+
+        let v = unsafe {
+            Module::main(&frame)
+                .submodule(&frame, "AsyncTests")?
+                .as_managed()
+                .function(&frame, "complexfunc")?
+                .as_managed()
+                .call_async_interactive(&mut frame, [dims, iters])
+                .await
+                .unwrap()
+                .unbox::<f64>()? as f32
+        };
+
+        frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+        frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+        frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+
+        Ok(v)
+    }
+}
+
+pub struct InteractiveSchedulingTask {
+    pub dims: isize,
+    pub iters: isize,
+}
+
+impl AsyncTask for InteractiveSchedulingTask {
+    type Output = JlrsResult<f32>;
+
+    async fn run<'base>(self, mut frame: AsyncGcFrame<'base>) -> Self::Output {
+        let dims = Value::new(&mut frame, self.dims);
+        let iters = Value::new(&mut frame, self.iters);
+
+        let v = unsafe {
+            let task = Module::main(&frame)
+                .submodule(&frame, "AsyncTests")?
+                .as_managed()
+                .function(&frame, "complexfunc")?
+                .as_managed()
+                .schedule_async_interactive(&mut frame, [dims, iters])
+                .unwrap();
+
+            frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+            frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+            frame.gc_collect(jlrs::memory::gc::GcCollection::Full);
+
+            Module::base(&frame)
+                .function(&mut frame, "fetch")?
+                .call1(&mut frame, task.as_value())
+                .into_jlrs_result()?
+                .unbox::<f64>()? as f32
+        };
+
+        Ok(v)
+    }
+}
+
 pub struct SchedulingTask {
     pub dims: isize,
     pub iters: isize,
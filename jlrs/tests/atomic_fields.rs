@@ -1,6 +1,8 @@
 mod util;
 #[cfg(feature = "local-rt")]
 mod tests {
+    #[cfg(feature = "unstable")]
+    use jlrs::data::managed::value::memory_order::MemoryOrder;
     use jlrs::prelude::*;
 
     use super::util::JULIA;
@@ -35,6 +37,97 @@ mod tests {
         })
     }
 
+    #[cfg(feature = "unstable")]
+    fn modify_atomic_field() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let ty = unsafe {
+                        Module::main(&frame)
+                            .submodule(&frame, "JlrsStableTests")?
+                            .as_managed()
+                            .global(&frame, "WithAtomic")?
+                            .as_value()
+                    };
+
+                    let arg1 = Value::new(&mut frame, 3u32);
+                    let instance = ty
+                        .cast::<DataType>()?
+                        .instantiate(&mut frame, [arg1])?
+                        .into_jlrs_result()?;
+
+                    let add = Module::base(&frame).global(&mut frame, "+")?;
+                    let two = Value::new(&mut frame, 2u32);
+                    let pair = unsafe {
+                        instance
+                            .modify_field(
+                                &mut frame,
+                                "a",
+                                add,
+                                two,
+                                MemoryOrder::SequentiallyConsistent,
+                            )?
+                            .into_jlrs_result()?
+                    };
+
+                    let old = pair.get_nth_field(&mut frame, 0)?.unbox::<u32>()?;
+                    let new = pair.get_nth_field(&mut frame, 1)?.unbox::<u32>()?;
+                    assert_eq!(old, 3);
+                    assert_eq!(new, 5);
+
+                    let a = instance.field_accessor().field("a")?.access::<u32>()?;
+                    assert_eq!(a, 5);
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    #[cfg(feature = "unstable")]
+    fn get_atomic_field() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let ty = unsafe {
+                        Module::main(&frame)
+                            .submodule(&frame, "JlrsStableTests")?
+                            .as_managed()
+                            .global(&frame, "WithAtomic")?
+                            .as_value()
+                    };
+
+                    let arg1 = Value::new(&mut frame, 3u32);
+                    let instance = ty
+                        .cast::<DataType>()?
+                        .instantiate(&mut frame, [arg1])?
+                        .into_jlrs_result()?;
+
+                    let a = instance
+                        .get_field_atomic(&mut frame, "a", MemoryOrder::SequentiallyConsistent)?
+                        .into_jlrs_result()?
+                        .unbox::<u32>()?;
+                    assert_eq!(a, 3);
+
+                    let res = instance.get_field_atomic(&mut frame, "a", MemoryOrder::NotAtomic)?;
+                    assert!(res.is_err());
+
+                    assert!(instance
+                        .get_field_atomic(&mut frame, "b", MemoryOrder::Acquire)
+                        .is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     fn read_large_atomic_field() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -131,6 +224,10 @@ mod tests {
     #[test]
     fn atomic_field_tests() {
         read_atomic_field();
+        #[cfg(feature = "unstable")]
+        modify_atomic_field();
+        #[cfg(feature = "unstable")]
+        get_atomic_field();
         read_large_atomic_field();
         read_oddly_sized_atomic_field();
         atomic_union_is_pointer_field();
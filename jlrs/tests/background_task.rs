@@ -1,8 +1,11 @@
 use jlrs::{
     data::{
         layout::valid_layout::ValidLayout,
-        managed::background_task::{spawn_background_task, BackgroundTaskLayout},
+        managed::background_task::{
+            spawn_background_future, spawn_background_task, BackgroundTaskLayout,
+        },
     },
+    error::JlrsError,
     prelude::*,
 };
 
@@ -10,7 +13,7 @@ use jlrs::{
 fn run_background_task() {
     let rt = Builder::new().start_local().unwrap();
 
-    rt.local_scope::<_, 2>(|mut frame| {
+    rt.local_scope::<_, 8>(|mut frame| {
         let bg_task = spawn_background_task::<usize, _, _>(&mut frame, || Ok(7usize));
 
         assert!(BackgroundTaskLayout::<usize>::valid_layout(
@@ -30,6 +33,51 @@ fn run_background_task() {
         };
 
         assert_eq!(v, 7);
+
+        let bg_task = spawn_background_future::<usize, _, _>(&mut frame, async { Ok(8usize) });
+
+        let v = unsafe {
+            Module::base(&frame)
+                .global(&frame, "fetch")
+                .unwrap()
+                .as_value()
+                .call1(&mut frame, bg_task.as_value())
+                .into_jlrs_result()
+                .unwrap()
+                .unbox::<usize>()
+                .unwrap()
+        };
+
+        assert_eq!(v, 8);
+
+        // Fetching a failed task must throw an exception rather than wait forever.
+        let bg_task = spawn_background_task::<usize, _, _>(&mut frame, || {
+            Err(JlrsError::exception("background task failed"))?
+        });
+
+        let res = unsafe {
+            Module::base(&frame)
+                .global(&frame, "fetch")
+                .unwrap()
+                .as_value()
+                .call1(&mut frame, bg_task.as_value())
+        };
+
+        assert!(res.is_err());
+
+        let bg_task = spawn_background_future::<usize, _, _>(&mut frame, async {
+            Err(JlrsError::exception("background future failed"))?
+        });
+
+        let res = unsafe {
+            Module::base(&frame)
+                .global(&frame, "fetch")
+                .unwrap()
+                .as_value()
+                .call1(&mut frame, bg_task.as_value())
+        };
+
+        assert!(res.is_err());
     });
 
     std::mem::drop(rt);
@@ -0,0 +1,106 @@
+mod util;
+
+#[cfg(test)]
+#[cfg(all(feature = "local-rt", feature = "bigint"))]
+mod tests {
+    use jlrs::prelude::*;
+    use num_bigint::BigInt;
+
+    use super::util::JULIA;
+
+    fn unbox_bigint() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    for (expr, expected) in [
+                        ("big(0)", "0"),
+                        ("big(-42)", "-42"),
+                        (
+                            "big(2)^200 + 12345",
+                            "1606938044258990275541962092341162602522202993782792835313721",
+                        ),
+                        (
+                            "-(big(2)^130) - 1",
+                            "-1361129467683753853853498429727072845825",
+                        ),
+                    ] {
+                        let value = Value::eval_string(&mut frame, expr).into_jlrs_result()?;
+                        let unboxed = value.unbox_bigint()?;
+                        assert_eq!(unboxed, expected.parse::<BigInt>().unwrap());
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn new_bigint() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let parse = Value::eval_string(&mut frame, "s -> parse(BigInt, s)")
+                        .into_jlrs_result()?;
+                    let equals =
+                        Value::eval_string(&mut frame, "(a, b) -> a == b").into_jlrs_result()?;
+
+                    for s in [
+                        "0",
+                        "-1",
+                        "18446744073709551616",
+                        "-340282366920938463463374607431768211457",
+                    ] {
+                        let bigint = s.parse::<BigInt>().unwrap();
+                        let value = Value::new_bigint(&mut frame, &bigint)?;
+
+                        let julia_str = JuliaString::new(&mut frame, s);
+                        let expected = parse
+                            .call1(&mut frame, julia_str.as_value())
+                            .into_jlrs_result()?;
+                        let is_equal = equals
+                            .call2(&mut frame, value, expected)
+                            .into_jlrs_result()?
+                            .unbox::<bool>()?;
+                        assert!(is_equal.as_bool());
+
+                        assert_eq!(value.unbox_bigint()?, bigint);
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn unbox_bigint_type_error() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let value = Value::new(&mut frame, 1isize);
+                    assert!(value.unbox_bigint().is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn bigint_tests() {
+        unbox_bigint();
+        new_bigint();
+        unbox_bigint_type_error();
+    }
+}
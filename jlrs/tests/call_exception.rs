@@ -1,7 +1,7 @@
 mod util;
 #[cfg(feature = "local-rt")]
 mod tests {
-    use jlrs::prelude::*;
+    use jlrs::{convert::into_jlrs_result::IntoJlrsResult, prelude::*};
 
     use super::util::JULIA;
 
@@ -265,6 +265,32 @@ mod tests {
         });
     }
 
+    fn exception_is_send_sync_error() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            let err = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let func = Module::main(&frame)
+                        .submodule(&frame, "JlrsTests")?
+                        .as_managed()
+                        .function(&frame, "throws_exception")?
+                        .as_managed();
+
+                    func.call0(&mut frame).into_jlrs_result()?;
+                    Ok(())
+                })
+                .unwrap_err();
+
+            let err: Box<dyn std::error::Error + Send + Sync> = err;
+            let msg = std::thread::spawn(move || err.to_string()).join().unwrap();
+            assert!(msg.starts_with("Exception: "));
+        });
+    }
+
     #[test]
     fn call_exception_tests() {
         call0_exception_is_caught();
@@ -278,5 +304,6 @@ mod tests {
         call_exception_is_caught();
         call_kw_exception_is_caught();
         method_error_is_caught();
+        exception_is_send_sync_error();
     }
 }
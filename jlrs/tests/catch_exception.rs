@@ -1,7 +1,10 @@
 mod util;
 #[cfg(feature = "local-rt")]
 mod tests {
-    use jlrs::{catch::catch_exceptions, prelude::*};
+    use jlrs::{
+        catch::{catch_exceptions, catch_exceptions_with_backtrace},
+        prelude::*,
+    };
 
     use super::util::JULIA;
 
@@ -33,8 +36,39 @@ mod tests {
         });
     }
 
+    fn exception_backtrace_is_captured() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let func = Module::main(&frame)
+                        .submodule(&frame, "JlrsTests")?
+                        .as_managed()
+                        .function(&frame, "throws_exception")?
+                        .as_managed();
+
+                    let unrooted = frame.unrooted();
+                    let mut f = || func.call_unchecked(&mut frame, []);
+
+                    let res = catch_exceptions_with_backtrace(&mut f, |e, bt| {
+                        assert!(e.is::<JuliaString>());
+                        bt.render(unrooted).ok()
+                    });
+
+                    let rendered = res.unwrap_err().unwrap();
+                    assert!(rendered.contains("throws_exception"));
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     #[test]
     fn call_exception_tests() {
         call0_exception_is_caught();
+        exception_backtrace_is_captured();
     }
 }
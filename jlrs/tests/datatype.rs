@@ -5,8 +5,8 @@ mod tests {
     use jlrs::{
         data::{
             managed::{
-                simple_vector::SimpleVector, type_name::TypeName, type_var::TypeVar, union::Union,
-                union_all::UnionAll,
+                datatype::CCallAbi, simple_vector::SimpleVector, type_name::TypeName,
+                type_var::TypeVar, union::Union, union_all::UnionAll,
             },
             types::typecheck::*,
         },
@@ -351,6 +351,36 @@ mod tests {
         })
     }
 
+    fn datatype_abstract_and_concrete() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|frame| {
+                    let number = DataType::number_type(&frame);
+                    assert!(number.is_abstract());
+                    assert!(!number.is_concrete_type());
+
+                    let float64 = DataType::float64_type(&frame);
+                    assert!(!float64.is_abstract());
+                    assert!(float64.is_concrete_type());
+
+                    // `Array{T, N}` has free type vars, it's neither abstract nor concrete.
+                    let array = UnionAll::array_type(&frame).base_type();
+                    assert!(!array.is_abstract());
+                    assert!(!array.is_concrete_type());
+
+                    let abstract_array = UnionAll::abstractarray_type(&frame).base_type();
+                    assert!(abstract_array.is_abstract());
+                    assert!(!abstract_array.is_concrete_type());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     fn datatype_zeroinit() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -442,6 +472,33 @@ mod tests {
         })
     }
 
+    fn datatype_field_names() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|frame| {
+                    let ty = DataType::uniontype_type(&frame);
+                    let names = ty
+                        .field_name_symbols()
+                        .into_iter()
+                        .map(|sym| sym.as_string())
+                        .collect::<JlrsResult<Vec<_>>>()?;
+                    assert_eq!(names, ["a", "b"]);
+
+                    let names = ty.field_names_iter().collect::<JlrsResult<Vec<_>>>()?;
+                    assert_eq!(names, ["a", "b"]);
+
+                    let ty = DataType::int64_type(&frame);
+                    assert!(ty.field_name_symbols().is_empty());
+                    assert_eq!(ty.field_names_iter().count(), 0);
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     fn datatype_field_index_unchecked() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -597,6 +654,57 @@ mod tests {
         })
     }
 
+    fn datatype_promote_type() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let i32_ty = DataType::int32_type(&frame);
+                    let f64_ty = DataType::float64_type(&frame);
+                    let promoted = i32_ty.promote_type(&mut frame, f64_ty).into_jlrs_result()?;
+                    assert_eq!(promoted, f64_ty.as_value());
+
+                    let nothing_ty = DataType::nothing_type(&frame);
+                    let promoted = nothing_ty
+                        .promote_type(&mut frame, i32_ty)
+                        .into_jlrs_result()?;
+                    assert!(promoted.is::<Union>());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn datatype_ccall_abi() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let i32_ty = DataType::int32_type(&frame);
+                    assert_eq!(i32_ty.ccall_abi(), CCallAbi::ByValue);
+
+                    let string_ty = DataType::string_type(&frame);
+                    assert_eq!(string_ty.ccall_abi(), CCallAbi::Boxed);
+
+                    let any_ty = DataType::any_type(&frame);
+                    assert_eq!(any_ty.ccall_abi(), CCallAbi::Boxed);
+
+                    let some_any_ty = unsafe { Value::eval_string(&mut frame, "Some{Any}") }
+                        .into_jlrs_result()?
+                        .cast::<DataType>()?;
+                    assert_eq!(some_any_ty.ccall_abi(), CCallAbi::Ambiguous);
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     #[test]
     fn datatype_tests() {
         datatype_methods();
@@ -615,11 +723,13 @@ mod tests {
         datatype_mutable();
         datatype_hasfreetypevast();
         datatype_concrete();
+        datatype_abstract_and_concrete();
         datatype_zeroinit();
         datatype_params();
         datatype_field_type();
         datatype_field_name();
         datatype_field_name_str();
+        datatype_field_names();
         datatype_field_index_unchecked();
         cannot_instantiate_array();
         datatype_is_const_field();
@@ -627,5 +737,7 @@ mod tests {
         compare_with_value();
         extend_lifetime();
         check_names();
+        datatype_promote_type();
+        datatype_ccall_abi();
     }
 }
@@ -0,0 +1,72 @@
+mod util;
+
+#[cfg(test)]
+#[cfg(feature = "local-rt")]
+mod tests {
+    use jlrs::prelude::*;
+
+    use super::util::JULIA;
+
+    fn deep_copy_nested_vector() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value =
+                        Value::eval_string(&mut frame, "[[1, 2], [3]]").into_jlrs_result()?;
+                    let copy = value.deep_copy(&mut frame)?;
+                    assert!(!value.egal(copy));
+
+                    let equals =
+                        Value::eval_string(&mut frame, "(a, b) -> a == b").into_jlrs_result()?;
+                    let is_equal = equals
+                        .call2(&mut frame, value, copy)
+                        .into_jlrs_result()?
+                        .unbox::<bool>()?;
+                    assert!(is_equal.as_bool());
+
+                    // The inner vectors are copied too.
+                    let first = Value::eval_string(&mut frame, "v -> v[1]").into_jlrs_result()?;
+                    let a = first.call1(&mut frame, value).into_jlrs_result()?;
+                    let b = first.call1(&mut frame, copy).into_jlrs_result()?;
+                    assert!(!a.egal(b));
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn deep_copy_exception_is_returned() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value = Value::eval_string(
+                        &mut frame,
+                        "mutable struct NoDeepCopy end
+                        Base.deepcopy_internal(::NoDeepCopy, ::IdDict) = error(\"can't copy\")
+                        NoDeepCopy()",
+                    )
+                    .into_jlrs_result()?;
+
+                    assert!(value.deep_copy(&mut frame).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn deep_copy_tests() {
+        deep_copy_nested_vector();
+        deep_copy_exception_is_returned();
+    }
+}
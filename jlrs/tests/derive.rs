@@ -1204,6 +1204,28 @@ mod tests {
         })
     }
 
+    fn isbits_into_julia_with_layout_name() {
+        JULIA_DERIVE.with(|j| {
+            let mut julia = j.borrow_mut();
+            let mut frame = StackFrame::new();
+
+            julia
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let wvt = RenamedWithValueType { a: 1 };
+                    type Renamed = RenamedWithValueTypeConstructor<ConstantBool<true>>;
+                    let v = Value::new_bits_from_layout::<Renamed, _>(&mut frame, wvt.clone())?;
+                    assert!(v.is::<WithValueType>());
+                    let wvt_unboxed = v.unbox::<RenamedWithValueType>()?;
+                    assert_eq!(wvt, wvt_unboxed);
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     fn trivial_isbits_into_julia() {
         JULIA_DERIVE.with(|j| {
             let mut julia = j.borrow_mut();
@@ -1250,6 +1272,16 @@ mod tests {
                     test_fn(StandardEnum::SeB)?;
                     test_fn(StandardEnum::SeC)?;
 
+                    // An instance that doesn't represent a known variant can't be unboxed.
+                    let unknown = unsafe {
+                        Value::eval_string(&mut frame, "reinterpret(StandardEnum, Int32(4))")
+                            .into_jlrs_result()?
+                    };
+                    assert!(unknown.is::<StandardEnum>());
+                    assert!(unknown.unbox::<StandardEnum>().is_err());
+                    assert!(StandardEnum::from_super(4).is_none());
+                    assert_eq!(StandardEnum::from_super(2), Some(StandardEnum::SeB));
+
                     Ok(())
                 })
                 .unwrap();
@@ -1331,6 +1363,7 @@ mod tests {
         derive_with_propagated_lifetimes();
         derive_string();
         isbits_into_julia();
+        isbits_into_julia_with_layout_name();
         trivial_isbits_into_julia();
         test_enums();
         test_enums_ccall();
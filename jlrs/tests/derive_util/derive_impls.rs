@@ -839,6 +839,19 @@ pub struct WithValueTypeTypeConstructor<N> {
     _n: ::std::marker::PhantomData<N>,
 }
 
+#[repr(C)]
+#[derive(Clone, Debug, Unbox, ValidLayout, Typecheck, ValidField, IsBits, PartialEq)]
+#[jlrs(julia_type = "Main.WithValueType")]
+pub struct RenamedWithValueType {
+    pub a: i64,
+}
+
+#[derive(ConstructType, HasLayout)]
+#[jlrs(julia_type = "Main.WithValueType", layout_name = "RenamedWithValueType", scope_lifetime = false, data_lifetime = false, layout_params = [], elided_params = ["N"], all_params = ["N"])]
+pub struct RenamedWithValueTypeConstructor<N> {
+    _n: ::std::marker::PhantomData<N>,
+}
+
 #[derive(ConstructType)]
 #[jlrs(julia_type = "Main.AnAbstractType")]
 pub struct AnAbstractType {}
@@ -0,0 +1,93 @@
+mod util;
+
+#[cfg(feature = "local-rt")]
+mod tests {
+    use std::collections::HashMap;
+
+    use jlrs::{data::managed::dict::JuliaDict, prelude::*};
+
+    use crate::util::JULIA;
+
+    fn create_empty_dict() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let dict = JuliaDict::<isize, f64>::new(&mut frame)?;
+                    assert_eq!(dict.len(), 0);
+                    assert!(dict.is_empty());
+
+                    let expected = unsafe {
+                        Value::eval_string(&mut frame, "Dict{Int, Float64}").into_jlrs_result()?
+                    };
+                    assert!(dict.as_value().datatype().as_value().egal(expected));
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn insert_and_get() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let mut dict = JuliaDict::<isize, f64>::new(&mut frame)?;
+                    let key = Value::new(&mut frame, 1isize);
+                    let value = Value::new(&mut frame, 2.0f64);
+                    dict.insert(&frame, key, value)?;
+                    assert_eq!(dict.len(), 1);
+
+                    let found = dict.get(&mut frame, key)?;
+                    assert_eq!(found.unwrap().unbox::<f64>()?, 2.0);
+
+                    let missing = Value::new(&mut frame, 2isize);
+                    assert!(dict.get(&mut frame, missing)?.is_none());
+
+                    let invalid = Value::new(&mut frame, 1.5f64);
+                    assert!(dict.insert(&frame, invalid, value).is_err());
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn from_hash_map_and_iter() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let map: HashMap<u8, u16> = (0..10).map(|i| (i, i as u16 * 3)).collect();
+                    let dict = JuliaDict::from_hash_map(&mut frame, map.clone())?;
+                    assert_eq!(dict.len(), map.len());
+
+                    let entries = dict
+                        .iter(&mut frame)
+                        .map(|entry| {
+                            let (key, value) = entry?;
+                            Ok((key.unbox::<u8>()?, value.unbox::<u16>()?))
+                        })
+                        .collect::<JlrsResult<HashMap<_, _>>>()?;
+                    assert_eq!(entries, map);
+
+                    let empty = JuliaDict::<u8, u16>::new(&mut frame)?;
+                    assert_eq!(empty.iter(&mut frame).count(), 0);
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    #[test]
+    fn dict_tests() {
+        create_empty_dict();
+        insert_and_get();
+        from_hash_map_and_iter();
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(feature = "tokio-rt")]
+mod dispatch_timeout {
+    use std::{
+        sync::mpsc,
+        time::{Duration, Instant},
+    };
+
+    use jlrs::prelude::*;
+
+    #[test]
+    fn dispatch_timeout() {
+        let (julia, _thread) = Builder::new()
+            .async_runtime(Tokio::<1>::new(false))
+            .channel_capacity(1)
+            .spawn()
+            .unwrap();
+
+        // Block the runtime thread until `release` is sent.
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let blocked = julia
+            .blocking_task(move |_| {
+                started_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+            })
+            .try_dispatch()
+            .ok()
+            .unwrap();
+        started_rx.recv().unwrap();
+
+        // Fill the channel.
+        let queued = julia.blocking_task(|_| 1usize).try_dispatch().ok().unwrap();
+
+        let timeout = Duration::from_millis(50);
+        let start = Instant::now();
+        let dispatch = match julia.blocking_task(|_| 2usize).dispatch_timeout(timeout) {
+            Err(Ok(dispatch)) => dispatch,
+            _ => panic!("task was dispatched while the channel was full"),
+        };
+        assert!(start.elapsed() >= timeout);
+
+        release_tx.send(()).unwrap();
+        let receiver = dispatch
+            .dispatch_timeout(Duration::from_secs(60))
+            .ok()
+            .unwrap();
+
+        blocked.blocking_recv().unwrap();
+        assert_eq!(queued.blocking_recv().unwrap(), 1);
+        assert_eq!(receiver.blocking_recv().unwrap(), 2);
+    }
+}
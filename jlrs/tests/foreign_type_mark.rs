@@ -0,0 +1,77 @@
+mod util;
+
+#[cfg(feature = "local-rt")]
+mod tests {
+    use jlrs::{
+        data::{
+            managed::value::typed::TypedValue,
+            types::foreign_type::{ForeignType, OpaqueType},
+        },
+        memory::{
+            gc::{mark_queue_obj, Gc, GcCollection},
+            PTls,
+        },
+        prelude::*,
+    };
+
+    use super::util::JULIA;
+
+    struct CachedResult {
+        value: ValueRef<'static, 'static>,
+    }
+
+    unsafe impl Send for CachedResult {}
+
+    unsafe impl ForeignType for CachedResult {
+        fn mark(ptls: PTls, data: &Self) -> usize {
+            unsafe { mark_queue_obj(ptls, data.value) as usize }
+        }
+    }
+
+    fn referenced_value_survives_gc() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let module = Module::main(&frame);
+                    let name = Symbol::new(&frame, "CachedResult");
+                    let ty = CachedResult::create_type(&mut frame, name, module);
+                    module.set_const_unchecked(name, ty.as_value());
+
+                    // The array is only reachable through the foreign value.
+                    let cached = frame.returning::<JlrsResult<_>>().scope(|mut frame| {
+                        let value =
+                            Value::eval_string(&mut frame, "Any[1, 2, 3]").into_jlrs_result()?;
+                        let cached = CachedResult {
+                            value: value.leak(),
+                        };
+                        Ok(TypedValue::new(&mut frame, cached).leak())
+                    })?;
+
+                    let cached = cached.root(&mut frame);
+                    frame.gc_collect(GcCollection::Full);
+                    frame.gc_collect(GcCollection::Full);
+
+                    let value = cached.track_shared()?.value.as_value();
+                    let sum = Module::base(&frame)
+                        .global(&frame, "sum")?
+                        .as_value()
+                        .call1(&mut frame, value)
+                        .into_jlrs_result()?
+                        .unbox::<isize>()?;
+                    assert_eq!(sum, 6);
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn foreign_type_mark_tests() {
+        referenced_value_survives_gc();
+    }
+}
@@ -2,7 +2,11 @@ mod util;
 
 #[cfg(feature = "local-rt")]
 mod tests {
-    use jlrs::prelude::*;
+    use jlrs::{
+        error::{JlrsError, RuntimeError},
+        info::Info,
+        prelude::*,
+    };
 
     use crate::util::JULIA;
 
@@ -51,9 +55,153 @@ mod tests {
         })
     }
 
+    fn call_in_world() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let func =
+                        Value::eval_string(&mut frame, "world_age_fn() = 1").into_jlrs_result()?;
+                    let world = Info::current_world();
+
+                    Value::eval_string(&mut frame, "world_age_fn() = 2").into_jlrs_result()?;
+
+                    let old = func
+                        .call_in_world(&mut frame, [], world)?
+                        .into_jlrs_result()?
+                        .unbox::<isize>()?;
+                    assert_eq!(old, 1);
+
+                    let new = func
+                        .call_in_world(&mut frame, [], Info::current_world())?
+                        .into_jlrs_result()?
+                        .unbox::<isize>()?;
+                    assert_eq!(new, 2);
+
+                    let future = func.call_in_world(&mut frame, [], Info::current_world() + 1);
+                    assert!(matches!(
+                        future.unwrap_err().as_ref(),
+                        JlrsError::RuntimeError(RuntimeError::FutureWorldAge { .. })
+                    ));
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn cconvert_and_unsafe_convert() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let i64_ty = DataType::int64_type(&frame);
+                    let converted = Value::new(&mut frame, 3i32)
+                        .cconvert(&mut frame, i64_ty)
+                        .into_jlrs_result()?
+                        .unsafe_convert(&mut frame, i64_ty)
+                        .into_jlrs_result()?;
+                    assert!(converted.is::<i64>());
+                    assert_eq!(converted.unbox::<i64>()?, 3);
+
+                    let ptr_ty = Value::eval_string(&mut frame, "Ptr{UInt8}")
+                        .into_jlrs_result()?
+                        .cast::<DataType>()?;
+                    let s = JuliaString::new(&mut frame, "cconvert");
+                    let prepared = s
+                        .as_value()
+                        .cconvert(&mut frame, ptr_ty)
+                        .into_jlrs_result()?;
+                    let ptr = prepared
+                        .unsafe_convert(&mut frame, ptr_ty)
+                        .into_jlrs_result()?;
+
+                    let unsafe_string = Module::base(&frame)
+                        .global(&mut frame, "unsafe_string")?
+                        .as_value();
+                    let roundtrip = unsafe_string
+                        .call1(&mut frame, ptr)
+                        .into_jlrs_result()?
+                        .unbox::<String>()?
+                        .unwrap();
+                    assert_eq!(roundtrip, "cconvert");
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn convert_to() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let i64_ty = DataType::int64_type(&frame);
+                    let value = Value::new(&mut frame, 3.0f64);
+                    let converted = value.convert_to(&mut frame, i64_ty)?;
+                    assert!(converted.is::<i64>());
+                    assert_eq!(converted.unbox::<i64>()?, 3);
+
+                    assert_eq!(value.convert_to_typed::<i32, _>(&frame)?, 3);
+
+                    let inexact = Value::new(&mut frame, 3.5f64);
+                    assert!(inexact.convert_to(&mut frame, i64_ty).is_err());
+                    assert!(inexact.convert_to_typed::<i64, _>(&frame).is_err());
+
+                    let s = JuliaString::new(&mut frame, "3").as_value();
+                    assert!(s.convert_to(&mut frame, i64_ty).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn collect() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let generator =
+                        Value::eval_string(&mut frame, "(2i for i in 1:3)").into_jlrs_result()?;
+
+                    let collected = generator.collect(&mut frame)?;
+                    assert_eq!(collected.length(), 3);
+
+                    let typed = generator.collect_typed::<i64, _>(&mut frame)?;
+                    assert_eq!(typed.bits_data().as_slice(), &[2, 4, 6]);
+
+                    assert!(generator.collect_typed::<f64, _>(&mut frame).is_err());
+
+                    let not_iterable = Value::new(&mut frame, Nothing);
+                    assert!(not_iterable.collect(&mut frame).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     #[test]
     fn function_tests() {
         extend_lifetime();
         has_datatype();
+        call_in_world();
+        cconvert_and_unsafe_convert();
+        convert_to();
+        collect();
     }
 }
@@ -1,7 +1,10 @@
 mod util;
 #[cfg(feature = "local-rt")]
 mod tests {
-    use jlrs::prelude::*;
+    use jlrs::{
+        error::{AccessError, JlrsError},
+        prelude::*,
+    };
 
     use super::util::JULIA;
 
@@ -654,6 +657,84 @@ mod tests {
         });
     }
 
+    fn broadcast_into() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let func = Module::base(&frame).global(&mut frame, "+")?;
+                    let dest = TypedMatrix::<f64>::new(&mut frame, [2, 2]).into_jlrs_result()?;
+                    let a = TypedMatrix::<f64>::from_slice_copied(
+                        &mut frame,
+                        [1.0, 2.0, 3.0, 4.0],
+                        [2, 2],
+                    )?
+                    .unwrap();
+                    let b = TypedVector::<f64>::from_slice_copied(&mut frame, [10.0, 20.0], 2)?
+                        .unwrap();
+
+                    func.broadcast_into(&frame, dest, [a.as_value(), b.as_value()])?
+                        .unwrap();
+                    assert_eq!(dest.bits_data().as_slice(), &[11.0, 22.0, 13.0, 24.0]);
+
+                    let c = TypedVector::<f64>::new(&mut frame, 3).into_jlrs_result()?;
+                    let res = func.broadcast_into(&frame, dest, [a.as_value(), c.as_value()]);
+                    assert!(matches!(
+                        res.unwrap_err().as_ref(),
+                        JlrsError::AccessError(AccessError::BroadcastShapeMismatch { .. })
+                    ));
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn call_handled() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let func = Module::base(&frame).global(&mut frame, "getindex")?;
+                    let arr = TypedVector::<f64>::from_slice_copied(&mut frame, [1.0, 2.0], 2)?
+                        .unwrap()
+                        .as_value();
+                    let idx = Value::new(&mut frame, 2isize);
+
+                    let res = func.call_handled(&mut frame, [arr, idx], |_| {
+                        Err(JlrsError::exception("unexpected exception"))?
+                    })?;
+                    assert_eq!(res.unbox::<f64>()?, 2.0);
+
+                    let idx = Value::new(&mut frame, 3isize);
+                    let res = func.call_handled(&mut frame, [arr, idx], |exc| {
+                        assert_eq!(exc.datatype().name(), "BoundsError");
+                        Ok(exc)
+                    })?;
+                    assert_eq!(res.datatype().name(), "BoundsError");
+
+                    let plus = Module::base(&frame).global(&mut frame, "+")?;
+                    let s = JuliaString::new(&mut frame, "a").as_value();
+                    let res = plus.call_handled(&mut frame, [arr, s], |exc| {
+                        if exc.datatype().name() == "MethodError" {
+                            Err(JlrsError::exception("no method"))?
+                        }
+                        Ok(exc)
+                    });
+                    assert!(res.is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     #[test]
     fn function_tests() {
         return_nothing();
@@ -684,5 +765,7 @@ mod tests {
         call_output();
         call_dynamic();
         call_dynamic_output();
+        broadcast_into();
+        call_handled();
     }
 }
@@ -68,10 +68,37 @@ mod tests {
         })
     }
 
+    fn gc_stats() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            let mut jlrs = jlrs.instance(&mut frame);
+
+            jlrs.returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let total_before = frame.total_allocated_bytes();
+                    let collections_before = frame.collections_count()?;
+
+                    let arr =
+                        TypedArray::<f64>::new(&mut frame, (1024, 1024)).into_jlrs_result()?;
+                    assert!(frame.total_allocated_bytes() >= total_before + 8 * 1024 * 1024);
+                    assert!(frame.live_bytes() > 0);
+
+                    frame.gc_collect(GcCollection::Full);
+                    assert!(frame.collections_count()? > collections_before);
+                    assert_eq!(arr.length(), 1024 * 1024);
+
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     #[test]
     fn gc_tests() {
         disable_enable_gc();
         collect_garbage();
         insert_safepoint();
+        gc_stats();
     }
 }
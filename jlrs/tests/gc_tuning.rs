@@ -0,0 +1,40 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use jlrs::{
+        error::{JlrsError, RuntimeError},
+        info::Info,
+        runtime::builder::{Builder, GcTuning},
+    };
+
+    #[test]
+    fn gc_tuning() {
+        let res = Builder::new()
+            .n_threads(2)
+            .n_interactive_threads(1)
+            .gc_tuning(GcTuning::new().n_gc_threads(4))
+            .start_local();
+
+        match res {
+            Err(e) => assert!(matches!(
+                *e,
+                JlrsError::RuntimeError(RuntimeError::InvalidGcTuning { .. })
+            )),
+            Ok(_) => panic!("inconsistent GC tuning was accepted"),
+        }
+
+        // Julia hasn't been initialized, so it can still be started.
+        let _julia = Builder::new()
+            .n_threads(4)
+            .gc_tuning(
+                GcTuning::new()
+                    .n_gc_threads(3)
+                    .concurrent_sweep(true)
+                    .heap_size_hint(1 << 30),
+            )
+            .start_local()
+            .unwrap();
+
+        // The thread that triggers a collection is included in the number of mark threads.
+        assert_eq!(Info::gc_threads(), (2, 1));
+    }
+}
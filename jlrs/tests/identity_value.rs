@@ -0,0 +1,89 @@
+mod util;
+
+#[cfg(test)]
+#[cfg(feature = "local-rt")]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use jlrs::{data::managed::value::identity::IdentityValue, prelude::*};
+
+    use super::util::JULIA;
+
+    fn mutable_values_are_compared_by_identity() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let a = Value::eval_string(&mut frame, "[1, 2]").into_jlrs_result()?;
+                    let b = Value::eval_string(&mut frame, "[1, 2]").into_jlrs_result()?;
+
+                    let mut set = HashSet::new();
+                    assert!(set.insert(IdentityValue::new(a)));
+                    assert!(!set.insert(IdentityValue::new(a)));
+                    assert!(set.insert(IdentityValue::new(b)));
+                    assert_eq!(set.len(), 2);
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn immutable_values_are_compared_by_contents() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let a = Value::new(&mut frame, 3usize);
+                    let b = Value::new(&mut frame, 3usize);
+
+                    let mut map = HashMap::new();
+                    map.insert(IdentityValue::from(a), "a");
+                    map.insert(IdentityValue::from(b), "b");
+                    assert_eq!(map.len(), 1);
+                    assert_eq!(map[&IdentityValue::new(a)], "b");
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn egal_is_identity_comparison() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let a = Value::eval_string(&mut frame, "[1, 2]").into_jlrs_result()?;
+                    let b = Value::eval_string(&mut frame, "[1, 2]").into_jlrs_result()?;
+                    assert!(a.egal(a));
+                    assert!(!a.egal(b));
+
+                    let c = Value::new(&mut frame, 3.0f64);
+                    let d = Value::new(&mut frame, 3.0f64);
+                    let e = Value::new(&mut frame, 3isize);
+                    assert!(c.egal(d));
+                    assert!(!c.egal(e));
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn identity_value_tests() {
+        mutable_values_are_compared_by_identity();
+        immutable_values_are_compared_by_contents();
+        egal_is_identity_comparison();
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use std::sync::Mutex;
+
+    use jlrs::prelude::*;
+
+    static CALLED: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+    #[test]
+    fn init_hooks_are_called_in_order() {
+        let julia = Builder::new()
+            .add_init_hook(|_| {
+                CALLED.lock().unwrap().push(1);
+                Ok(())
+            })
+            .add_init_hook(|unrooted| {
+                CALLED.lock().unwrap().push(2);
+                unrooted.local_scope::<_, 1>(|mut frame| unsafe {
+                    Value::eval_string(&mut frame, "init_hook_value = 3").into_jlrs_result()?;
+                    Ok(())
+                })
+            })
+            .start_local()
+            .unwrap();
+
+        assert_eq!(*CALLED.lock().unwrap(), [1, 2]);
+
+        julia.local_scope::<_, 1>(|mut frame| {
+            let value = Module::main(&frame)
+                .global(&mut frame, "init_hook_value")
+                .unwrap()
+                .unbox::<isize>()
+                .unwrap();
+            assert_eq!(value, 3);
+        });
+    }
+}
@@ -4,7 +4,7 @@ mod util;
 mod tests {
     use std::borrow::Cow;
 
-    use jlrs::prelude::*;
+    use jlrs::{info::Info, prelude::*};
 
     use crate::util::JULIA;
 
@@ -314,7 +314,6 @@ mod tests {
         })
     }
 
-    #[julia_version(until = "1.11")]
     fn set_const_twice() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -366,9 +365,14 @@ mod tests {
             let res = jlrs
                 .instance(&mut frame)
                 .returning::<JlrsResult<_>>()
-                .scope(|frame| {
+                .scope(|mut frame| {
                     let main = Module::main(&frame);
                     assert_eq!(main, main.parent());
+                    assert!(main.is_root());
+
+                    let jlrs_tests = main.submodule(&mut frame, "JlrsTests")?;
+                    assert_eq!(jlrs_tests.parent(), main);
+                    assert!(!jlrs_tests.is_root());
 
                     Ok(())
                 });
@@ -421,6 +425,117 @@ mod tests {
         })
     }
 
+    fn exported_names() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            let res = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        Value::eval_string(
+                            &mut frame,
+                            "module ExportedNames\nexport foo\nfoo() = 1\nbar() = 2\nend",
+                        )
+                        .into_jlrs_result()?;
+                    }
+
+                    let module = Module::main(&frame).submodule(&mut frame, "ExportedNames")?;
+
+                    assert!(module.is_exported("foo"));
+                    assert!(!module.is_exported("bar"));
+
+                    let names = module
+                        .exported_names(&frame)?
+                        .into_iter()
+                        .map(|sym| sym.as_string())
+                        .collect::<JlrsResult<Vec<_>>>()?;
+
+                    assert!(names.contains(&"foo".to_string()));
+                    assert!(!names.contains(&"bar".to_string()));
+
+                    Ok(())
+                });
+
+            assert!(res.is_ok());
+        })
+    }
+
+    fn names() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            let res = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        Value::eval_string(
+                            &mut frame,
+                            "module ModuleNames\nusing Base: sum\nimport Base: prod\nexport foo\nfoo() = 1\nbar() = 2\n@deprecate baz() foo()\nend",
+                        )
+                        .into_jlrs_result()?;
+                    }
+
+                    let module = Module::main(&frame).submodule(&mut frame, "ModuleNames")?;
+                    let to_strings = |names: Vec<Symbol>| {
+                        names
+                            .into_iter()
+                            .map(|sym| sym.as_string())
+                            .collect::<JlrsResult<Vec<_>>>()
+                    };
+
+                    let names = to_strings(module.names(&frame, false, false)?)?;
+                    assert!(names.contains(&"foo".to_string()));
+                    assert!(!names.contains(&"bar".to_string()));
+
+                    let names = to_strings(module.names(&frame, true, false)?)?;
+                    assert!(names.contains(&"foo".to_string()));
+                    assert!(names.contains(&"bar".to_string()));
+                    assert!(!names.contains(&"baz".to_string()));
+                    assert!(!names.contains(&"prod".to_string()));
+
+                    let names = to_strings(module.names(&frame, true, true)?)?;
+                    assert!(names.contains(&"prod".to_string()));
+
+                    Ok(())
+                });
+
+            assert!(res.is_ok());
+        })
+    }
+
+    fn reflect_type() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            let res = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    unsafe {
+                        Value::eval_string(
+                            &mut frame,
+                            "struct ReflectedType\na::Int32\nb::Float64\nend",
+                        )
+                        .into_jlrs_result()?;
+                    }
+
+                    let main = Module::main(&frame);
+                    let code = main.reflect_type(&frame, "ReflectedType")?;
+                    assert!(code.contains("pub struct ReflectedType"));
+                    assert!(code.contains("pub a: i32"));
+                    assert!(code.contains("pub b: f64"));
+                    assert!(main.reflect_type(&frame, "reflect_type_missing").is_err());
+
+                    Ok(())
+                });
+
+            assert!(res.is_ok());
+        })
+    }
+
     fn submodule_must_be_module() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -529,6 +644,62 @@ mod tests {
         })
     }
 
+    fn loaded_modules() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            let res = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|frame| {
+                    let modules = Info::loaded_modules(&frame)?;
+
+                    let (_, base) = modules
+                        .iter()
+                        .find(|(name, _)| name == "Base")
+                        .expect("Base is not loaded");
+                    assert_eq!(*base, Module::base(&frame));
+
+                    assert!(modules.iter().any(|(name, _)| name == "JlrsCore"));
+
+                    Ok(())
+                });
+
+            assert!(res.is_ok());
+        })
+    }
+
+    fn include_string() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            let res = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let module = Value::eval_string(&mut frame, "module IncludeStringTest end")
+                        .into_jlrs_result()?
+                        .cast::<Module>()?;
+
+                    let res = module
+                        .include_string(&mut frame, "const X = 2\nsquare_x() = X * X\nsquare_x()")
+                        .into_jlrs_result()?;
+                    assert_eq!(res.unbox::<isize>()?, 4);
+
+                    // The definitions are added to the module, not to `Main`.
+                    assert!(module.global(&frame, "square_x").is_ok());
+                    assert!(Module::main(&frame).global(&frame, "square_x").is_err());
+
+                    // Syntax errors are returned as exceptions.
+                    assert!(module.include_string(&mut frame, "1 +").is_err());
+
+                    Ok(())
+                });
+
+            assert!(res.is_ok());
+        })
+    }
+
     #[test]
     fn module_tests() {
         core_module();
@@ -548,17 +719,21 @@ mod tests {
         use_dyn_str_for_access();
         set_global();
         set_const();
-        #[cfg(any(feature = "julia-1-10", feature = "julia-1-11",))]
         set_const_twice();
         eval_using();
         module_parent();
         extend_lifetime_with_root();
         is_imported();
+        exported_names();
+        names();
+        reflect_type();
         submodule_must_be_module();
         #[cfg(any(feature = "julia-1-10", feature = "julia-1-11",))]
         cant_redefine_const();
         set_global_unchecked();
         set_const_unchecked();
         function_must_be_function();
+        loaded_modules();
+        include_string();
     }
 }
@@ -0,0 +1,16 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use jlrs::{info::Info, runtime::builder::Builder};
+
+    #[test]
+    fn set_n_gc_threads() {
+        let _julia = Builder::new()
+            .n_threads(4)
+            .n_gc_threads(3)
+            .start_local()
+            .unwrap();
+
+        // The thread that triggers a collection is included in the number of mark threads.
+        assert_eq!(Info::gc_threads().0, 2);
+    }
+}
@@ -46,9 +46,81 @@ mod tests {
         });
     }
 
+    fn create_named_tuple_from_slices() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let a = "a".to_symbol(&frame);
+                    let b = "b".to_symbol(&frame);
+                    let a_value = Value::new(&mut frame, 1u32);
+                    let b_value = Value::new(&mut frame, 2u64);
+
+                    let nt = Value::try_new_named_tuple(&mut frame, &[a, b], &[a_value, b_value])?;
+                    assert!(nt.is::<NamedTuple>());
+                    assert_eq!(nt.get_field(&mut frame, "a")?.unbox::<u32>()?, 1u32);
+                    assert_eq!(nt.get_field(&mut frame, "b")?.unbox::<u64>()?, 2u64);
+
+                    let empty = Value::try_new_named_tuple(&mut frame, &[], &[])?;
+                    assert!(empty.is::<NamedTuple>());
+
+                    let mismatch = Value::try_new_named_tuple(&mut frame, &[a, b], &[a_value]);
+                    assert!(mismatch.is_err());
+
+                    let duplicate =
+                        Value::try_new_named_tuple(&mut frame, &[a, a], &[a_value, b_value]);
+                    assert!(duplicate.is_err());
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn create_named_tuple_from_iter() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let a_value = Value::new(&mut frame, 1u32);
+                    let b_value = Value::new(&mut frame, 2u64);
+                    let names = [String::from("a"), String::from("b")];
+
+                    let nt = Value::named_tuple_from_iter(
+                        &mut frame,
+                        names.iter().zip([a_value, b_value]),
+                    )?;
+                    assert!(nt.is::<NamedTuple>());
+                    assert_eq!(nt.n_fields(), 2);
+                    assert_eq!(nt.get_field(&mut frame, "a")?.unbox::<u32>()?, 1u32);
+                    assert_eq!(nt.get_field(&mut frame, "b")?.unbox::<u64>()?, 2u64);
+
+                    let empty = Value::named_tuple_from_iter(
+                        &mut frame,
+                        std::iter::empty::<(&str, Value)>(),
+                    )?;
+                    assert!(empty.is::<NamedTuple>());
+                    assert_eq!(empty.n_fields(), 0);
+
+                    let duplicate =
+                        Value::named_tuple_from_iter(&mut frame, [("a", a_value), ("a", b_value)]);
+                    assert!(duplicate.is_err());
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     #[test]
     fn named_tuple_tests() {
         create_named_tuple();
         create_named_tuple_macro();
+        create_named_tuple_from_slices();
+        create_named_tuple_from_iter();
     }
 }
@@ -155,6 +155,89 @@ mod tests {
         });
     }
 
+    fn transposed_array_view() {
+        JULIA.with(|j| {
+            let mut julia = j.borrow_mut();
+            let mut frame = StackFrame::new();
+
+            julia
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let data = vec![1usize, 2, 3, 4, 5, 6];
+                    let arr = TypedArray::<usize>::from_vec(&mut frame, data, (3, 2))?
+                        .into_jlrs_result()?;
+
+                    let data = unsafe { arr.bits_data() };
+                    let view = data.array_view();
+                    assert_eq!(view.shape(), &[3, 2]);
+                    assert!(!view.is_standard_layout());
+
+                    let transposed = data.array_view_transposed();
+                    assert_eq!(transposed.shape(), &[2, 3]);
+                    assert!(transposed.is_standard_layout());
+                    assert_eq!(transposed[[1, 2]], view[[2, 1]]);
+                    assert_eq!(transposed[[1, 2]], 6);
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn transposed_array_view_mut() {
+        JULIA.with(|j| {
+            let mut julia = j.borrow_mut();
+            let mut frame = StackFrame::new();
+
+            julia
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let data = vec![1usize, 2, 3, 4, 5, 6];
+                    let mut arr = TypedArray::<usize>::from_vec(&mut frame, data, (3, 2))?
+                        .into_jlrs_result()?;
+
+                    {
+                        let mut data = arr.bits_data_mut();
+                        let mut transposed = data.array_view_mut_transposed();
+                        transposed[[0, 2]] = 7;
+                    }
+
+                    assert_eq!(arr.bits_data()[(2, 0)], 7);
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn low_rank_array_view() {
+        JULIA.with(|j| {
+            let mut julia = j.borrow_mut();
+            let mut frame = StackFrame::new();
+
+            julia
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let arr = TypedRankedArray::<f32, 0>::from_vec(&mut frame, vec![1.0], [])?
+                        .into_jlrs_result()?;
+                    let data = unsafe { arr.bits_data() };
+                    assert_eq!(data.array_view().ndim(), 0);
+                    assert_eq!(data.array_view_transposed()[[]], 1.0);
+
+                    let arr = TypedVector::<f32>::from_vec(&mut frame, vec![1.0, 2.0], 2)?
+                        .into_jlrs_result()?;
+                    let data = unsafe { arr.bits_data() };
+                    assert_eq!(data.array_view().shape(), &[2]);
+                    assert_eq!(data.array_view_transposed(), data.array_view());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     #[test]
     fn ndarray_tests() {
         bits_array_view();
@@ -162,5 +245,8 @@ mod tests {
         inline_array_view();
         copied_array_view();
         copied_array_view_mut();
+        transposed_array_view();
+        transposed_array_view_mut();
+        low_rank_array_view();
     }
 }
@@ -1,7 +1,10 @@
 mod util;
 #[cfg(feature = "local-rt")]
 mod tests {
-    use jlrs::prelude::*;
+    use jlrs::{
+        memory::gc::{Gc, GcCollection},
+        prelude::*,
+    };
 
     use super::util::JULIA;
 
@@ -57,9 +60,40 @@ mod tests {
         });
     }
 
+    fn root_slice() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            let out = jlrs
+                .instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let values = [
+                        Value::new(&mut frame, 1usize),
+                        Value::new(&mut frame, 2usize),
+                        Value::new(&mut frame, 3usize),
+                    ];
+
+                    let n_roots = frame.n_roots();
+                    let rooted = frame.root_slice(&values);
+                    assert_eq!(frame.n_roots(), n_roots + 3);
+
+                    frame.gc_collect(GcCollection::Full);
+
+                    rooted
+                        .map(|value| value.unbox::<usize>())
+                        .sum::<JlrsResult<usize>>()
+                });
+
+            assert_eq!(out.unwrap(), 6);
+        });
+    }
+
     #[test]
     fn output_frame_tests() {
         return_value_from_scope();
         return_result_from_scope();
+        root_slice();
     }
 }
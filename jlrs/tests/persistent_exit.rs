@@ -0,0 +1,88 @@
+#[cfg(feature = "tokio-rt")]
+mod persistent_exit {
+    use std::sync::mpsc::{self, Sender};
+
+    use jlrs::prelude::*;
+
+    struct FlushOnExit {
+        exited: Sender<f64>,
+    }
+
+    impl PersistentTask for FlushOnExit {
+        type State<'state> = Value<'state, 'static>;
+        type Input = f64;
+        type Output = JlrsResult<()>;
+
+        async fn init<'frame>(
+            &mut self,
+            mut frame: AsyncGcFrame<'frame>,
+        ) -> JlrsResult<Self::State<'frame>> {
+            unsafe { Value::eval_string(&mut frame, "Ref(0.0)").into_jlrs_result() }
+        }
+
+        async fn run<'frame, 'state: 'frame>(
+            &mut self,
+            mut frame: AsyncGcFrame<'frame>,
+            state: &mut Self::State<'state>,
+            input: Self::Input,
+        ) -> Self::Output {
+            let input = Value::new(&mut frame, input);
+            unsafe {
+                state
+                    .set_field(&mut frame, "x", input)?
+                    .into_jlrs_result()?;
+            }
+
+            Ok(())
+        }
+
+        async fn exit<'frame>(
+            &mut self,
+            _frame: AsyncGcFrame<'frame>,
+            state: &mut Self::State<'frame>,
+        ) {
+            let value = state
+                .field_accessor()
+                .field("x")
+                .unwrap()
+                .access::<f64>()
+                .unwrap();
+            self.exited.send(value).unwrap();
+        }
+    }
+
+    #[test]
+    fn exit_is_called_after_async_handle_is_dropped() {
+        let (julia, thread) = Builder::new()
+            .async_runtime(Tokio::<1>::new(false))
+            .spawn()
+            .unwrap();
+
+        let (exited, exit_value) = mpsc::channel();
+        let handle = julia
+            .persistent(FlushOnExit { exited })
+            .try_dispatch()
+            .ok()
+            .unwrap()
+            .blocking_recv()
+            .unwrap()
+            .unwrap();
+
+        handle
+            .call(3.0)
+            .try_dispatch()
+            .ok()
+            .unwrap()
+            .blocking_recv()
+            .unwrap()
+            .unwrap();
+
+        // The runtime waits for the persistent task to finish before shutting down.
+        std::mem::drop(julia);
+        assert!(exit_value.try_recv().is_err());
+
+        std::mem::drop(handle);
+        thread.join().unwrap();
+        assert_eq!(exit_value.recv().unwrap(), 3.0);
+    }
+}
@@ -0,0 +1,18 @@
+#[cfg(feature = "local-rt")]
+mod process_events {
+    use jlrs::prelude::*;
+
+    #[test]
+    fn process_events() {
+        let mut julia = Builder::new().start_local().unwrap();
+
+        for _ in 0..3 {
+            julia.process_events();
+        }
+
+        julia.local_scope::<_, 1>(|mut frame| {
+            let v = unsafe { Value::eval_string(&mut frame, "1 + 2") }.unwrap();
+            assert_eq!(v.unbox::<isize>().unwrap(), 3);
+        });
+    }
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use jlrs::{
+        memory::gc::{Gc, GcCollection},
+        prelude::*,
+    };
+
+    #[test]
+    fn rooted_value_outlives_scope() {
+        let julia = Builder::new().start_local().unwrap();
+
+        let value = julia
+            .local_scope::<_, 1>(|mut frame| {
+                let value = unsafe {
+                    Value::eval_string(&mut frame, "Any[1, 2, 3]")
+                        .into_jlrs_result()
+                        .unwrap()
+                };
+                julia.root_global(value)
+            })
+            .unwrap();
+
+        julia.local_scope::<_, 1>(|mut frame| unsafe {
+            frame.gc_collect(GcCollection::Full);
+
+            let sum = Module::base(&frame)
+                .global(&frame, "sum")
+                .unwrap()
+                .as_value();
+            let sum = sum
+                .call1(&mut frame, value)
+                .into_jlrs_result()
+                .unwrap()
+                .unbox::<isize>()
+                .unwrap();
+            assert_eq!(sum, 6);
+        });
+    }
+}
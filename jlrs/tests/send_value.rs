@@ -0,0 +1,43 @@
+#[cfg(feature = "multi-rt")]
+mod send_value {
+    use jlrs::{
+        data::managed::{string::JuliaString, value::Value, Managed},
+        memory::{
+            gc::{Gc, GcCollection},
+            scope::LocalScope,
+        },
+        runtime::builder::Builder,
+    };
+
+    #[test]
+    fn send_value_between_threads() {
+        Builder::new()
+            .start_mt(|julia| {
+                let t1 = julia.spawn(move |mut julia| {
+                    julia.with(|handle| {
+                        handle.local_scope::<_, 1>(|mut frame| {
+                            JuliaString::new(&mut frame, "sent")
+                                .as_value()
+                                .prepare_send()
+                        })
+                    })
+                });
+
+                let send_value = t1.join().unwrap();
+
+                let t2 = julia.spawn(move |mut julia| {
+                    julia.with(|handle| {
+                        handle.local_scope::<_, 1>(|mut frame| {
+                            frame.gc_collect(GcCollection::Full);
+                            let value: Value = send_value.receive(&mut frame);
+                            frame.gc_collect(GcCollection::Full);
+                            value.unbox::<String>().unwrap().unwrap()
+                        })
+                    })
+                });
+
+                assert_eq!(t2.join().unwrap(), "sent");
+            })
+            .unwrap();
+    }
+}
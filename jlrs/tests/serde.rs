@@ -0,0 +1,82 @@
+mod util;
+
+#[cfg(test)]
+#[cfg(all(feature = "local-rt", feature = "serde"))]
+mod tests {
+    use jlrs::{
+        convert::serde::{deserialize_value, serialize_value},
+        prelude::*,
+    };
+
+    use super::util::JULIA;
+
+    fn roundtrip_value() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let value = Value::new(&mut frame, 3.0f64);
+                    let mut bytes = Vec::new();
+                    serialize_value(value, &mut bytes)?;
+
+                    let deserialized = deserialize_value(&bytes, &mut frame)?;
+                    assert_eq!(deserialized.unbox::<f64>()?, 3.0);
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn roundtrip_circular_value() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value = Value::eval_string(&mut frame, "v = Any[1]; push!(v, v); v")
+                        .into_jlrs_result()?;
+                    let mut bytes = Vec::new();
+                    serialize_value(value, &mut bytes)?;
+
+                    let deserialized = deserialize_value(&bytes, &mut frame)?;
+                    let check =
+                        Value::eval_string(&mut frame, "v -> v[2] === v").into_jlrs_result()?;
+                    let is_circular = check.call1(&mut frame, deserialized).into_jlrs_result()?;
+                    assert!(is_circular.unbox::<bool>()?.as_bool());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn deserialize_error_is_returned() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let res = deserialize_value(&[0xff, 0x00, 0x12], &mut frame);
+                    assert!(res.is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn serde_tests() {
+        roundtrip_value();
+        roundtrip_circular_value();
+        deserialize_error_is_returned();
+    }
+}
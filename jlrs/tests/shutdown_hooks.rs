@@ -0,0 +1,32 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use std::sync::Mutex;
+
+    use jlrs::prelude::*;
+
+    static CALLED: Mutex<Vec<isize>> = Mutex::new(Vec::new());
+
+    #[test]
+    fn shutdown_hooks_are_called_in_order() {
+        let julia = Builder::new()
+            .on_shutdown(|_| {
+                CALLED.lock().unwrap().push(1);
+            })
+            .on_shutdown(|unrooted| {
+                let value = unrooted.local_scope::<_, 1>(|mut frame| unsafe {
+                    Value::eval_string(&mut frame, "1 + 1")
+                        .into_jlrs_result()
+                        .unwrap()
+                        .unbox::<isize>()
+                        .unwrap()
+                });
+                CALLED.lock().unwrap().push(value);
+            })
+            .start_local()
+            .unwrap();
+
+        assert!(CALLED.lock().unwrap().is_empty());
+        std::mem::drop(julia);
+        assert_eq!(*CALLED.lock().unwrap(), [1, 2]);
+    }
+}
@@ -112,6 +112,8 @@ mod tests {
 
                     let r: &[u8] = string.as_c_str().to_bytes();
                     assert_eq!(r.len(), 2);
+                    assert_eq!(string.as_bytes(), &[129, 2, 0, 0]);
+                    assert!(string.as_str().is_err());
                     let res = string.as_value().unbox::<String>()?;
                     assert!(res.is_err());
                     let vec = res.unwrap_err();
@@ -182,6 +184,103 @@ mod tests {
         });
     }
 
+    fn string_indices() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let string = JuliaString::new(&mut frame, "aõb");
+
+                    assert_eq!(string.nextind(&frame, 0).unwrap(), 1);
+                    assert_eq!(string.nextind(&frame, 1).unwrap(), 2);
+                    assert_eq!(string.nextind(&frame, 2).unwrap(), 4);
+                    assert_eq!(string.prevind(&frame, 4).unwrap(), 2);
+                    assert_eq!(string.prevind(&frame, 5).unwrap(), 4);
+
+                    assert_eq!(string.char_at(&frame, 2).unwrap(), 'õ');
+                    assert_eq!(string.char_at(&frame, 4).unwrap(), 'b');
+                    assert!(string.char_at(&frame, 3).is_err());
+                    assert!(string.nextind(&frame, 5).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn show_string() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let v = Value::new(&mut frame, 3usize);
+                    assert_eq!(v.show_string(&frame)?, "3");
+
+                    let s = JuliaString::new(&mut frame, "Foo").as_value();
+                    assert_eq!(s.show_string(&frame)?, "\"Foo\"");
+
+                    let mut buf = Vec::new();
+                    s.show_to_writer(&frame, &mut buf)?;
+                    assert_eq!(buf, b"\"Foo\"");
+
+                    let broken = Value::eval_string(
+                        &mut frame,
+                        "struct BrokenShow end
+                        Base.show(::IO, ::BrokenShow) = error(\"cannot show\")
+                        BrokenShow()",
+                    )
+                    .into_jlrs_result()?;
+                    assert!(broken.show_string(&frame).is_err());
+                    assert!(broken.show_to_writer(&frame, &mut buf).is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn show_compact_and_mime() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let v = Value::new(&mut frame, 1.0f64 / 3.0);
+                    assert_eq!(v.show_compact(&frame)?, "0.333333");
+
+                    let s = JuliaString::new(&mut frame, "Foo").as_value();
+                    assert_eq!(s.show_mime(&frame, "text/plain")?.unwrap(), "\"Foo\"");
+                    assert!(s.show_mime(&frame, "text/html")?.is_none());
+
+                    let md =
+                        Value::eval_string(&mut frame, "import Markdown; Markdown.parse(\"*a*\")")
+                            .into_jlrs_result()?;
+                    let html = md.show_mime(&frame, "text/html")?.unwrap();
+                    assert!(html.contains("<em>a</em>"));
+
+                    let broken = Value::eval_string(
+                        &mut frame,
+                        "struct BrokenMimeShow end
+                        Base.show(::IO, ::MIME\"text/plain\", ::BrokenMimeShow) = error(\"cannot show\")
+                        BrokenMimeShow()",
+                    )
+                    .into_jlrs_result()?;
+                    assert!(broken.show_mime(&frame, "text/plain").is_err());
+
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     #[test]
     fn string_tests() {
         create_and_unbox_str_data();
@@ -192,5 +291,8 @@ mod tests {
         create_utf8_string();
         format_string();
         extend_lifeime();
+        string_indices();
+        show_string();
+        show_compact_and_mime();
     }
 }
@@ -0,0 +1,153 @@
+#[cfg(feature = "tokio-rt")]
+mod task_cancellation {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    };
+
+    use jlrs::{async_util::cancellation_token::CancellationToken, error::JlrsError, prelude::*};
+    use tokio::sync::oneshot;
+
+    struct WaitForRelease {
+        token: Option<CancellationToken>,
+        started: mpsc::Sender<()>,
+        release: oneshot::Receiver<()>,
+        finished: Arc<AtomicBool>,
+    }
+
+    impl AsyncTask for WaitForRelease {
+        type Output = usize;
+
+        async fn run<'frame>(self, _frame: AsyncGcFrame<'frame>) -> Self::Output {
+            self.started.send(()).unwrap();
+            self.release.await.unwrap();
+
+            // Running tasks are not interrupted, they must check their token cooperatively.
+            if let Some(token) = self.token.as_ref() {
+                if token.check().is_err() {
+                    return 0;
+                }
+            }
+
+            self.finished.store(true, Ordering::SeqCst);
+            1
+        }
+
+        fn cancellation_token(&self) -> Option<CancellationToken> {
+            self.token.clone()
+        }
+    }
+
+    struct Handles {
+        started: mpsc::Receiver<()>,
+        release: oneshot::Sender<()>,
+        finished: Arc<AtomicBool>,
+    }
+
+    fn wait_for_release(token: Option<CancellationToken>) -> (WaitForRelease, Handles) {
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = oneshot::channel();
+        let finished = Arc::new(AtomicBool::new(false));
+
+        let task = WaitForRelease {
+            token,
+            started: started_tx,
+            release: release_rx,
+            finished: finished.clone(),
+        };
+
+        let handles = Handles {
+            started: started_rx,
+            release: release_tx,
+            finished,
+        };
+
+        (task, handles)
+    }
+
+    fn cancel_before_dispatch(julia: &AsyncHandle) {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let (task, handles) = wait_for_release(Some(token));
+        let receiver = julia.task(task).try_dispatch().ok().unwrap();
+
+        assert!(receiver.blocking_recv().is_err());
+        assert!(handles.started.try_recv().is_err());
+        assert!(!handles.finished.load(Ordering::SeqCst));
+    }
+
+    fn cancel_during_execution(julia: &AsyncHandle) {
+        let token = CancellationToken::new();
+        let (task, handles) = wait_for_release(Some(token.clone()));
+        let receiver = julia.task(task).try_dispatch().ok().unwrap();
+
+        handles.started.recv().unwrap();
+        token.cancel();
+        handles.release.send(()).unwrap();
+
+        assert_eq!(receiver.blocking_recv().unwrap(), 0);
+        assert!(!handles.finished.load(Ordering::SeqCst));
+    }
+
+    fn cancel_after_completion(julia: &AsyncHandle) {
+        let token = CancellationToken::new();
+        let (task, handles) = wait_for_release(Some(token.clone()));
+        let receiver = julia.task(task).try_dispatch().ok().unwrap();
+
+        handles.started.recv().unwrap();
+        handles.release.send(()).unwrap();
+
+        assert_eq!(receiver.blocking_recv().unwrap(), 1);
+        assert!(handles.finished.load(Ordering::SeqCst));
+
+        token.cancel();
+        assert!(matches!(*token.check().unwrap_err(), JlrsError::Cancelled));
+    }
+
+    fn cancel_all(julia: &AsyncHandle) {
+        // The runtime can run one task at a time, so the second task is pending while the first
+        // one is running.
+        let (running, running_handles) = wait_for_release(None);
+        let running_receiver = julia.task(running).try_dispatch().ok().unwrap();
+        running_handles.started.recv().unwrap();
+
+        let (pending, pending_handles) = wait_for_release(None);
+        let pending_receiver = julia.task(pending).try_dispatch().ok().unwrap();
+
+        julia.cancel_all();
+        running_handles.release.send(()).unwrap();
+
+        // The running task isn't interrupted, the pending task is skipped.
+        assert_eq!(running_receiver.blocking_recv().unwrap(), 1);
+        assert!(running_handles.finished.load(Ordering::SeqCst));
+        assert!(pending_receiver.blocking_recv().is_err());
+        assert!(pending_handles.started.try_recv().is_err());
+
+        // Tasks that are sent after calling `cancel_all` are not cancelled.
+        let (task, handles) = wait_for_release(None);
+        let receiver = julia.task(task).try_dispatch().ok().unwrap();
+
+        handles.started.recv().unwrap();
+        handles.release.send(()).unwrap();
+
+        assert_eq!(receiver.blocking_recv().unwrap(), 1);
+        assert!(handles.finished.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn task_cancellation() {
+        let (julia, thread) = Builder::new()
+            .async_runtime(Tokio::<1>::new(false))
+            .spawn()
+            .unwrap();
+
+        cancel_before_dispatch(&julia);
+        cancel_during_execution(&julia);
+        cancel_after_completion(&julia);
+        cancel_all(&julia);
+
+        std::mem::drop(julia);
+        thread.join().unwrap();
+    }
+}
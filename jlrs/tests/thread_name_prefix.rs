@@ -0,0 +1,29 @@
+#[cfg(feature = "multi-rt")]
+mod thread_name_prefix {
+    use std::thread;
+
+    use jlrs::runtime::builder::Builder;
+
+    #[test]
+    fn threads_are_named() {
+        Builder::new()
+            .thread_name_prefix("jlrs-test")
+            .unwrap()
+            .start_mt(|julia| {
+                assert_eq!(thread::current().name(), Some("jlrs-test-main"));
+
+                let name = julia
+                    .spawn(|_| thread::current().name().map(String::from))
+                    .join()
+                    .unwrap();
+
+                assert_eq!(name.as_deref(), Some("jlrs-test-0"));
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn null_in_prefix_is_rejected() {
+        assert!(Builder::new().thread_name_prefix("jlrs\0test").is_err());
+    }
+}
@@ -7,7 +7,7 @@ mod async_util;
 mod tests {
     use std::sync::Arc;
 
-    use jlrs::prelude::*;
+    use jlrs::{async_util::task::FutureTask, prelude::*};
     use once_cell::sync::OnceCell;
 
     use super::async_util::{async_tasks::*, ASYNC_TESTS_JL};
@@ -18,6 +18,7 @@ mod tests {
                 Builder::new()
                     .async_runtime(Tokio::<4>::new(false))
                     .n_threads(4)
+                    .n_interactive_threads(1)
                     .channel_capacity(32)
                     .spawn()
                     .expect("Could not init Julia")
@@ -116,6 +117,61 @@ mod tests {
         assert_eq!(receiver.blocking_recv().unwrap(), 20_000_004.0);
     }
 
+    #[test]
+    fn test_task_with_sender() {
+        let julia = JULIA.get_or_init(init);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        julia
+            .task_with_sender(
+                OtherRetTypeTask {
+                    dims: 4,
+                    iters: 5_000_000,
+                },
+                sender,
+            )
+            .try_dispatch()
+            .ok()
+            .unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), 20_000_004.0);
+    }
+
+    #[test]
+    fn test_blocking_task_with_closure_sender() {
+        let julia = JULIA.get_or_init(init);
+
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        julia
+            .blocking_task_with_sender(
+                |mut frame| Value::new(&mut frame, 3usize).unbox::<usize>(),
+                move |res: JlrsResult<usize>| {
+                    sender.try_send(res).ok();
+                },
+            )
+            .try_dispatch()
+            .ok()
+            .unwrap();
+
+        assert_eq!(receiver.recv().unwrap().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_future_task() {
+        let julia = JULIA.get_or_init(init);
+
+        let task = FutureTask::new(
+            async {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                3u64
+            },
+            |mut frame, n: u64| Value::new(&mut frame, n + 1).unbox::<u64>(),
+        );
+
+        let receiver = julia.task(task).try_dispatch().ok().unwrap();
+        assert_eq!(receiver.blocking_recv().unwrap().unwrap(), 4);
+    }
+
     #[test]
     fn test_kw_task() {
         let julia = JULIA.get_or_init(init);
@@ -364,6 +420,38 @@ mod tests {
         assert_eq!(receiver.blocking_recv().unwrap().unwrap(), 20_004.0);
     }
 
+    #[test]
+    fn test_interactive_task() {
+        let julia = JULIA.get_or_init(init);
+
+        let receiver = julia
+            .task(InteractiveTask {
+                dims: 4,
+                iters: 5_000,
+            })
+            .try_dispatch()
+            .ok()
+            .unwrap();
+
+        assert_eq!(receiver.blocking_recv().unwrap().unwrap(), 20_004.0);
+    }
+
+    #[test]
+    fn test_scheduling_interactive_task() {
+        let julia = JULIA.get_or_init(init);
+
+        let receiver = julia
+            .task(InteractiveSchedulingTask {
+                dims: 4,
+                iters: 5_000,
+            })
+            .try_dispatch()
+            .ok()
+            .unwrap();
+
+        assert_eq!(receiver.blocking_recv().unwrap().unwrap(), 20_004.0);
+    }
+
     #[test]
     fn test_scheduling_task() {
         let julia = JULIA.get_or_init(init);
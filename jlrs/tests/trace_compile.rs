@@ -0,0 +1,33 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use std::{env, fs};
+
+    use jlrs::prelude::*;
+
+    #[test]
+    fn compiled_methods_are_traced() {
+        let path = env::temp_dir().join(format!("jlrs_trace_compile_{}.jl", std::process::id()));
+
+        let julia = Builder::new()
+            .trace_compile(&path)
+            .unwrap()
+            .start_local()
+            .unwrap();
+
+        julia.local_scope::<_, 2>(|mut frame| unsafe {
+            Value::eval_string(&mut frame, "trace_compile_test(x) = x + 1").unwrap();
+            let res = Value::eval_string(&mut frame, "trace_compile_test(1)")
+                .unwrap()
+                .unbox::<isize>()
+                .unwrap();
+            assert_eq!(res, 2);
+        });
+
+        // The trace file is closed when Julia exits.
+        std::mem::drop(julia);
+
+        let trace = fs::read_to_string(&path).unwrap();
+        assert!(trace.contains("trace_compile_test"));
+        fs::remove_file(path).ok();
+    }
+}
@@ -74,11 +74,67 @@ mod tests {
         })
     }
 
+    fn bits_tuple_elements() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let v = Value::new(&mut frame, Tuple2(1u64, -3i32));
+                    let elements = v.tuple_elements(&mut frame)?;
+                    assert_eq!(elements.len(), 2);
+                    assert_eq!(elements[0].unbox::<u64>()?, 1);
+                    assert_eq!(elements[1].unbox::<i32>()?, -3);
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn boxed_tuple_elements() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let v = unsafe { Value::eval_string(&mut frame, "(1, \"foo\", Any[])") }
+                        .into_jlrs_result()?;
+                    let elements = v.tuple_elements(&mut frame)?;
+                    assert_eq!(elements.len(), 3);
+                    assert_eq!(elements[0].unbox::<isize>()?, 1);
+                    assert_eq!(elements[1].unbox::<String>()?.unwrap(), "foo");
+                    assert!(elements[2].is::<Array>());
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn tuple_elements_not_a_tuple() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let v = Value::new(&mut frame, 1u64);
+                    assert!(v.tuple_elements(&mut frame).is_err());
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     #[test]
     fn tuple_tests() {
         create_cast_tuple0();
         create_cast_tuple1();
         create_cast_tuple2();
         create_tuple_from_values();
+        bits_tuple_elements();
+        boxed_tuple_elements();
+        tuple_elements_not_a_tuple();
     }
 }
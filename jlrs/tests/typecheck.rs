@@ -172,6 +172,92 @@ mod tests {
         })
     }
 
+    fn type_kind_predicates() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let datatype = DataType::bool_type(&frame).as_value();
+                    assert!(datatype.is_datatype());
+                    assert!(!datatype.is_unionall());
+
+                    let unionall = UnionAll::array_type(&frame).as_value();
+                    assert!(unionall.is_unionall());
+                    assert!(!unionall.is_datatype());
+
+                    let union =
+                        Value::eval_string(&mut frame, "Union{Int, Float64}").into_jlrs_result()?;
+                    assert!(union.is_union());
+                    assert!(!union.is_typevar());
+
+                    let typevar = unionall.cast::<UnionAll>()?.var().as_value();
+                    assert!(typevar.is_typevar());
+                    assert!(!typevar.is_union());
+
+                    let value = Value::new(&mut frame, 1usize);
+                    assert!(!value.is_datatype());
+                    assert!(!value.is_unionall());
+                    assert!(!value.is_union());
+                    assert!(!value.is_typevar());
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn typeassert() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value = Value::new(&mut frame, 1i64);
+                    let int_ty = DataType::int64_type(&frame).as_value();
+                    let real_ty = Value::eval_string(&mut frame, "Real").into_jlrs_result()?;
+                    let float_ty = DataType::float64_type(&frame).as_value();
+
+                    assert_eq!(value.typeassert(int_ty)?, value);
+                    assert_eq!(value.typeassert(real_ty)?, value);
+                    assert!(value.typeassert(float_ty).is_err());
+                    assert!(value.typeassert(value).is_err());
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
+    fn isa_runtime_type() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let value = Value::new(&mut frame, 1i64);
+                    let real_ty = Value::eval_string(&mut frame, "Real").into_jlrs_result()?;
+                    let union_ty = Value::eval_string(&mut frame, "Union{Int64, String}")
+                        .into_jlrs_result()?;
+                    let float_ty = DataType::float64_type(&frame).as_value();
+                    assert!(value.isa(real_ty));
+                    assert!(value.isa(union_ty));
+                    assert!(!value.isa(float_ty));
+
+                    let arr = Value::eval_string(&mut frame, "[1, 2]").into_jlrs_result()?;
+                    let array_ty = UnionAll::array_type(&frame).as_value();
+                    assert!(arr.isa(array_ty));
+                    assert!(!value.isa(array_ty));
+
+                    // Values that aren't types are rejected.
+                    assert!(!value.isa(value));
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     fn bits_typecheck() {
         JULIA.with(|j| {
             let mut frame = StackFrame::new();
@@ -509,6 +595,9 @@ mod tests {
         void_ptr_typecheck();
         failing_void_ptr_typecheck();
         type_typecheck();
+        type_kind_predicates();
+        typeassert();
+        isa_runtime_type();
         bits_typecheck();
         abstract_typecheck();
         abstract_ref_typecheck();
@@ -0,0 +1,75 @@
+mod util;
+#[cfg(feature = "local-rt")]
+mod tests {
+    use jlrs::{
+        data::managed::value::typed::TypedValue,
+        error::{JlrsError, TypeError},
+        prelude::*,
+    };
+
+    use super::util::JULIA;
+
+    fn value_as_typed() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let v = Value::new(&mut frame, 1i64);
+                    let typed = v.as_typed::<i64, _>(&frame)?;
+                    assert_eq!(typed.unbox::<i64>()?, 1);
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn value_as_typed_mismatch() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let v = Value::new(&mut frame, 1i64);
+                    let err = v.as_typed::<f32, _>(&frame).unwrap_err();
+                    match *err {
+                        JlrsError::TypeError(TypeError::TypeMismatch { expected, found }) => {
+                            assert_eq!(expected, "Float32");
+                            assert_eq!(found, "Int64");
+                        }
+                        _ => panic!("unexpected error"),
+                    }
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    fn typed_value_from_value() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let v = Value::new(&mut frame, 1.0f32);
+                    assert!(TypedValue::<f32>::from_value(&frame, v).is_ok());
+                    assert!(TypedValue::<f64>::from_value(&frame, v).is_err());
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn typed_value_tests() {
+        value_as_typed();
+        value_as_typed_mismatch();
+        typed_value_from_value();
+    }
+}
@@ -6,6 +6,7 @@ mod tests {
 
     use jlrs::{
         convert::{into_julia::IntoJulia, unbox::Unbox},
+        error::{JlrsError, TypeError},
         prelude::*,
     };
 
@@ -107,6 +108,37 @@ mod tests {
         null_mut()
     );
 
+    fn unbox_checked() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| {
+                    let small = Value::new(&mut frame, 100i64);
+                    assert_eq!(small.unbox_checked::<u8>()?, 100);
+                    assert_eq!(small.unbox_checked::<i128>()?, 100);
+
+                    let large = Value::new(&mut frame, u64::MAX);
+                    assert_eq!(large.unbox_checked::<u64>()?, u64::MAX);
+                    let err = large.unbox_checked::<i64>().unwrap_err();
+                    assert!(matches!(
+                        err.as_ref(),
+                        JlrsError::TypeError(TypeError::IntegerOverflow { .. })
+                    ));
+
+                    let negative = Value::new(&mut frame, -1i8);
+                    assert!(negative.unbox_checked::<usize>().is_err());
+
+                    let float = Value::new(&mut frame, 1.0f64);
+                    assert!(float.unbox_checked::<i64>().is_err());
+                    Ok(())
+                })
+                .unwrap();
+        });
+    }
+
     #[test]
     fn unbox_tests() {
         unbox_u8();
@@ -135,5 +167,6 @@ mod tests {
         unbox_f32_ptr();
         unbox_f64_ptr();
         unbox_void_ptr();
+        unbox_checked();
     }
 }
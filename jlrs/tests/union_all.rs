@@ -116,10 +116,37 @@ mod tests {
         })
     }
 
+    fn apply_dict_type() {
+        JULIA.with(|j| {
+            let mut frame = StackFrame::new();
+            let mut jlrs = j.borrow_mut();
+            jlrs.instance(&mut frame)
+                .returning::<JlrsResult<_>>()
+                .scope(|mut frame| unsafe {
+                    let dict = Module::base(&frame).global(&frame, "Dict")?.as_value();
+                    let key = DataType::string_type(&frame).as_value();
+                    let value = DataType::int64_type(&frame).as_value();
+
+                    let ty = dict
+                        .apply_type(&mut frame, [key, value])
+                        .into_jlrs_result()?;
+                    let expected =
+                        Value::eval_string(&mut frame, "Dict{String, Int64}").into_jlrs_result()?;
+                    assert!(ty.egal(expected));
+
+                    let res = dict.apply_type(&mut frame, [key, value, value]);
+                    assert!(res.is_err());
+                    Ok(())
+                })
+                .unwrap();
+        })
+    }
+
     #[test]
     fn union_all_tests() {
         create_new_unionall();
         instantiate_unionall();
         apply_value_type();
+        apply_dict_type();
     }
 }
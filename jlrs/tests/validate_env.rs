@@ -0,0 +1,14 @@
+#[cfg(feature = "local-rt")]
+mod validate_env {
+    use jlrs::prelude::*;
+
+    #[test]
+    fn validate_env() {
+        let report = Builder::new().validate().unwrap();
+        assert!(report.version_matches);
+        assert!(report.missing_image_paths.is_empty());
+
+        let _julia = Builder::new().start_local().unwrap();
+        assert!(Builder::new().validate().is_err());
+    }
+}
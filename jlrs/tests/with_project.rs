@@ -0,0 +1,49 @@
+#![cfg(feature = "local-rt")]
+mod tests {
+    use std::{env, fs};
+
+    use jlrs::prelude::*;
+
+    #[test]
+    fn packages_in_project_are_accessible() {
+        let project_dir = env::temp_dir().join(format!("jlrs_with_project_{}", std::process::id()));
+        fs::create_dir_all(project_dir.join("src")).unwrap();
+        fs::write(
+            project_dir.join("Project.toml"),
+            "name = \"JlrsProjectTest\"\nuuid = \"8d3c2a5e-4f1b-4c6e-9a7d-2b5e1f0c3a91\"\n",
+        )
+        .unwrap();
+        fs::write(
+            project_dir.join("src").join("JlrsProjectTest.jl"),
+            "module JlrsProjectTest\nanswer() = 42\nend\n",
+        )
+        .unwrap();
+
+        let julia = Builder::new()
+            .with_project(&project_dir)
+            .start_local()
+            .unwrap();
+
+        unsafe { julia.using("JlrsProjectTest") }.unwrap();
+
+        julia.local_scope::<_, 2>(|mut frame| unsafe {
+            let answer = Value::eval_string(&mut frame, "JlrsProjectTest.answer()")
+                .unwrap()
+                .unbox::<isize>()
+                .unwrap();
+            assert_eq!(answer, 42);
+
+            let project = Value::eval_string(&mut frame, "dirname(Base.active_project())")
+                .unwrap()
+                .unbox::<String>()
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                fs::canonicalize(project).unwrap(),
+                fs::canonicalize(&project_dir).unwrap()
+            );
+        });
+
+        fs::remove_dir_all(project_dir).ok();
+    }
+}
@@ -65,7 +65,7 @@ impl<'a> ClassifiedFields<'a> {
 
 pub struct JlrsTypeAttrs {
     julia_type: Option<String>,
-    constructor_for: Option<String>,
+    layout_name: Option<String>,
     zst: bool,
     scope_lifetime: bool,
     data_lifetime: bool,
@@ -77,7 +77,7 @@ pub struct JlrsTypeAttrs {
 impl JlrsTypeAttrs {
     fn parse(ast: &syn::DeriveInput) -> Self {
         let mut julia_type: Option<String> = None;
-        let mut constructor_for: Option<String> = None;
+        let mut layout_name: Option<String> = None;
         let mut scope_lifetime = false;
         let mut data_lifetime = false;
         let mut layout_params = Vec::new();
@@ -102,10 +102,15 @@ impl JlrsTypeAttrs {
                                 }
                             }
                         }
-                        syn::Meta::NameValue(mnv) if mnv.path.is_ident("constructor_for") => {
+                        // `constructor_for` is the original name of this attribute and remains
+                        // supported.
+                        syn::Meta::NameValue(mnv)
+                            if mnv.path.is_ident("layout_name")
+                                || mnv.path.is_ident("constructor_for") =>
+                        {
                             if let syn::Expr::Lit(lit) = mnv.value {
                                 if let syn::Lit::Str(s) = lit.lit {
-                                    constructor_for = Some(s.value());
+                                    layout_name = Some(s.value());
                                 }
                             }
                         }
@@ -180,7 +185,7 @@ impl JlrsTypeAttrs {
         JlrsTypeAttrs {
             julia_type,
             zst,
-            constructor_for,
+            layout_name,
             scope_lifetime,
             data_lifetime,
             layout_params,
@@ -337,6 +342,35 @@ pub fn impl_unbox(ast: &syn::DeriveInput) -> TokenStream {
         }
     };
 
+    if is_enum {
+        let repr = get_repr_int(ast).unwrap();
+
+        // The discriminant is checked by `try_unbox` because it's UB if it's not a known variant.
+        // `unbox` uses the default implementation, its caller must guarantee the discriminant is
+        // valid.
+        let unbox_impl = quote! {
+            unsafe impl ::jlrs::convert::unbox::Unbox for #name {
+                type Output = Self;
+
+                unsafe fn try_unbox(value: ::jlrs::data::managed::value::Value) -> ::jlrs::error::JlrsResult<Self> {
+                    let discriminant = *value.data_ptr().cast::<#repr>().as_ref();
+                    match <Self as ::jlrs::data::layout::julia_enum::Enum>::from_super(discriminant) {
+                        Some(variant) => Ok(variant),
+                        None => Err(::jlrs::error::TypeError::UnknownEnumVariant {
+                            discriminant: discriminant.to_string(),
+                            enum_type: ::jlrs::data::managed::Managed::display_string_or(
+                                value.datatype(),
+                                "<Cannot display type>",
+                            ),
+                        })?,
+                    }
+                }
+            }
+        };
+
+        return unbox_impl.into();
+    }
+
     let unbox_impl = quote! {
         unsafe impl #generics ::jlrs::convert::unbox::Unbox for #name #generics #where_clause {
             type Output = Self;
@@ -413,7 +447,7 @@ pub fn impl_construct_type(ast: &syn::DeriveInput) -> TokenStream {
     let mut attrs = JlrsTypeAttrs::parse(ast);
     let jl_type = attrs.julia_type
         .take()
-        .expect("ConstructType can only be derived if the corresponding Julia type is set with #[julia_type = \"Main.MyModule.Submodule.StructType\"]");
+        .expect("ConstructType can only be derived if the corresponding Julia type is set with #[jlrs(julia_type = \"Main.MyModule.Submodule.StructType\")]");
 
     let lifetimes = ast.generics.lifetimes().map(|_| -> syn::LifetimeParam {
         syn::parse_quote! { 'static }
@@ -582,9 +616,9 @@ pub fn impl_has_layout(ast: &syn::DeriveInput) -> TokenStream {
     let layout_type = format_ident!(
         "{}",
         attrs
-            .constructor_for
+            .layout_name
             .as_ref()
-            .expect("HasLayout can only be implemented when a layout type is provided")
+            .expect("HasLayout can only be derived if the name of the layout type is set with #[jlrs(layout_name = \"StructType\")]")
     );
 
     let all_params = attrs.all_params.iter().map(|i| format_ident!("{}", i));
@@ -738,7 +772,7 @@ pub fn impl_valid_layout(ast: &syn::DeriveInput) -> TokenStream {
     let mut attrs = JlrsTypeAttrs::parse(ast);
     let jl_type = attrs.julia_type
         .take()
-        .expect("ValidLayout can only be derived if the corresponding Julia type is set with #[julia_type = \"Main.MyModule.Submodule.StructType\"]");
+        .expect("ValidLayout can only be derived if the corresponding Julia type is set with #[jlrs(julia_type = \"Main.MyModule.Submodule.StructType\")]");
 
     if !is_enum {
         let fields = match &ast.data {
@@ -904,6 +938,7 @@ pub fn impl_enum(ast: &syn::DeriveInput) -> TokenStream {
     }
 
     let idents = data.variants.iter().map(|x| &x.ident);
+    let from_super_idents = idents.clone();
 
     let enum_impl = quote! {
         unsafe impl ::jlrs::data::layout::julia_enum::Enum for #name {
@@ -919,6 +954,16 @@ pub fn impl_enum(ast: &syn::DeriveInput) -> TokenStream {
             fn as_super(&self) -> Self::Super {
                 *self as _
             }
+
+            fn from_super(discriminant: Self::Super) -> Option<Self> {
+                #(
+                    if discriminant == #name::#from_super_idents as #repr {
+                        return Some(#name::#from_super_idents);
+                    }
+                )*
+
+                None
+            }
         }
     };
 
@@ -40,7 +40,9 @@ use self::{constant_bytes::*, version::emit_if_compatible};
 ///     // documented.
 ///     //
 ///     // If the function doesn't need to call into Julia, you can annotate it with `#[gc_safe]`
-///     // to allow the GC to run without having to wait until the function has returned.
+///     // to allow the GC to run without having to wait until the function has returned. Only
+///     // the call itself happens in a GC-safe state, the returned data is converted after the
+///     // GC-unsafe state has been restored.
 ///
 ///     ///     bar(arr::Array)
 ///     ///
@@ -53,6 +55,32 @@ use self::{constant_bytes::*, version::emit_if_compatible};
 ///     // This syntax can be used to extend existing functions.
 ///     fn foo(arr: Array) -> usize as Base.bar!;
 ///
+///     // Exports the function `scale` with a default value for `factor`.
+///     //
+///     // Default values are Julia expressions, they're used to generate additional methods like
+///     // `scale(x::Float64) = scale(x, 1.0)`. Only trailing arguments can have a default value.
+///     // If a default can't be parsed, the exception is thrown when the module is initialized.
+///     fn scale(x: f64, factor: f64 = 1.0) -> f64;
+///
+///     // Exports the function `parse`, which returns `Result<u64, MyErr>`.
+///     //
+///     // Functions annotated with `#[throws]` can return `Result<T, E>` if `E` implements
+///     // `IntoJuliaError`. This trait is implemented for strings and common error types, which
+///     // throw an `ErrorException` with the formatted error as its message. A custom error type
+///     // can implement it to throw an exception of another type.
+///     #[throws]
+///     fn parse(s: CCallStr) -> Result<u64, MyErr>;
+///
+///     // Exports the async function `download`.
+///     //
+///     // When an `async fn` is called, its future is polled to completion on a background thread
+///     // and a `JlrsCore.BackgroundTask{T}` is returned immediately. Call `fetch` to wait for the
+///     // task and get its result; Julia is GC-safe while it waits. The future can't call into
+///     // Julia, so the arguments and output must be `Send`, `'static` isbits data. If the
+///     // `tokio-rt` feature is enabled the future is executed by a tokio runtime. Async
+///     // functions can't be annotated with `#[throws]`.
+///     async fn download(id: u64) -> usize;
+///
 ///     // Exports the struct `MyType` as `MyForeignType`. `MyType` must implement `OpaqueType`
 ///     // or `ForeignType`.
 ///     struct MyType as MyForeignType;
@@ -113,6 +141,10 @@ use self::{constant_bytes::*, version::emit_if_compatible};
 /// end
 /// ```
 ///
+/// If initializing an exported item panics, e.g. because a type it depends on can't be found, the
+/// panic is caught and `@initjlrs` throws an `ErrorException` that names the item that failed to
+/// initialize instead of aborting the process.
+///
 /// It can be rather tricky to figure out how data is passed from Julia to Rust when `ccall`ing
 /// a function written in Rust. Primitive and `isbits` types are passed by value, managed types
 /// provided directly by jlrs are guaranteed to be boxed, all other types might be passed by
@@ -178,6 +210,10 @@ pub fn is_bits_derive(input: TokenStream) -> TokenStream {
 
 /// Derive `HasLayout`.
 ///
+/// The Rust name of the layout type must be set with `#[jlrs(layout_name = "StructType")]`,
+/// it doesn't need to match the name of the Julia type set with `julia_type`. The older
+/// `constructor_for` attribute is treated as an alias of `layout_name`.
+///
 /// Should only be used in combination with layouts generated by JlrsReflect.jl
 #[cfg(feature = "derive")]
 #[proc_macro_derive(HasLayout, attributes(jlrs))]
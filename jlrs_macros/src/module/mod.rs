@@ -4,7 +4,7 @@ use std::iter::FromIterator;
 use itertools::Itertools;
 use parameters::{ParameterEnvironment, ParameterList};
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Group, Spacing, Span, TokenStream as TokenStream2, TokenTree};
 use quote::{format_ident, ToTokens};
 use syn::{
     braced, bracketed,
@@ -13,8 +13,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Brace, Bracket, Comma},
-    AttrStyle, Attribute, Error, Expr, ExprLit, FnArg, Ident, ItemFn, Lit, Meta, Path, Result,
-    ReturnType, Signature, Token, Type,
+    AttrStyle, Attribute, Error, Expr, ExprLit, FnArg, GenericArgument, Ident, ItemFn, Lit, Meta,
+    Pat, Path, PathArguments, Result, ReturnType, Signature, Token, Type, WhereClause,
 };
 
 use self::parameters::{Apply, ResolvedParameterList};
@@ -47,6 +47,10 @@ struct ExportedType {
 }
 
 impl ExportedType {
+    fn init_context(&self) -> String {
+        format!("struct {}", path_to_string(&self.name))
+    }
+
     fn init_with_env(
         &self,
         generic: &GenericEnvironment,
@@ -229,6 +233,7 @@ impl Parse for TypeVarEnv {
 }
 struct ExportedFunction {
     func: Signature,
+    defaults: Vec<Option<TokenStream2>>,
     _as_token: Option<Token![as]>,
     name_override: Option<RenameFragments>,
     exclamation_mark_token: Option<Token![!]>,
@@ -237,7 +242,7 @@ struct ExportedFunction {
 
 impl Parse for ExportedFunction {
     fn parse(input: ParseStream) -> Result<Self> {
-        let func = input.parse()?;
+        let (func, defaults) = parse_signature_with_defaults(input)?;
 
         let lookahead = input.lookahead1();
         let (as_token, name_override, exclamation_mark_token) = if lookahead.peek(Token![as]) {
@@ -260,6 +265,7 @@ impl Parse for ExportedFunction {
 
         Ok(ExportedFunction {
             func,
+            defaults,
             _as_token: as_token,
             name_override: name_override,
             exclamation_mark_token,
@@ -268,13 +274,247 @@ impl Parse for ExportedFunction {
     }
 }
 
+/// Parses a function signature whose arguments can have a default value, e.g.
+/// `fn scale(x: f64, factor: f64 = 1.0) -> f64`. The defaults are Julia expressions, they're
+/// removed from the signature and returned separately.
+fn parse_signature_with_defaults(
+    input: ParseStream,
+) -> Result<(Signature, Vec<Option<TokenStream2>>)> {
+    let (head, args) = input.step(|cursor| {
+        let mut head = TokenStream2::new();
+        let mut rest = *cursor;
+
+        while let Some((tt, next)) = rest.token_tree() {
+            rest = next;
+            match tt {
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                    return Ok(((head, group), rest));
+                }
+                tt => head.extend(std::iter::once(tt)),
+            }
+        }
+
+        Err(cursor.error("expected function arguments"))
+    })?;
+
+    let output: ReturnType = input.parse()?;
+    let where_clause: Option<WhereClause> = input.parse()?;
+
+    let (inputs, defaults) = split_defaults(args.stream())?;
+    let mut inputs = Group::new(Delimiter::Parenthesis, inputs);
+    inputs.set_span(args.span());
+
+    let func = syn::parse2(quote::quote! { #head #inputs #output #where_clause })?;
+    Ok((func, defaults))
+}
+
+fn split_defaults(args: TokenStream2) -> Result<(TokenStream2, Vec<Option<TokenStream2>>)> {
+    let mut inputs = TokenStream2::new();
+    let mut defaults = Vec::new();
+
+    let mut arg = TokenStream2::new();
+    let mut default: Option<TokenStream2> = None;
+    let mut angle_depth = 0usize;
+    let mut prev_joint = None;
+
+    let mut finish_arg =
+        |arg: &mut TokenStream2, default: &mut Option<TokenStream2>| -> Result<()> {
+            let arg = std::mem::take(arg);
+            let default = default.take();
+            if arg.is_empty() {
+                return Ok(());
+            }
+
+            match default {
+                Some(default) if default.is_empty() => {
+                    return Err(Error::new(arg.span(), "expected a default value"));
+                }
+                None if defaults.iter().any(Option::is_some) => {
+                    return Err(Error::new(
+                        arg.span(),
+                        "arguments without a default value must come before arguments with one",
+                    ));
+                }
+                _ => (),
+            }
+
+            if !inputs.is_empty() {
+                inputs.extend(quote::quote! { , });
+            }
+            inputs.extend(arg);
+            defaults.push(default);
+            Ok(())
+        };
+
+    for tt in args {
+        if let Some(default) = default.as_mut() {
+            match &tt {
+                TokenTree::Punct(p) if p.as_char() == ',' && angle_depth == 0 => {
+                    finish_arg(&mut arg, &mut Some(std::mem::take(default)))?;
+                    continue;
+                }
+                _ => {
+                    default.extend(std::iter::once(tt));
+                    continue;
+                }
+            }
+        }
+
+        if let TokenTree::Punct(p) = &tt {
+            let joint_with_prev = prev_joint.take();
+            match p.as_char() {
+                '<' => angle_depth += 1,
+                // Skip the `>` of `->`
+                '>' if joint_with_prev != Some('-') => angle_depth = angle_depth.saturating_sub(1),
+                ',' if angle_depth == 0 => {
+                    finish_arg(&mut arg, &mut default)?;
+                    continue;
+                }
+                '=' if angle_depth == 0
+                    && joint_with_prev.is_none()
+                    && p.spacing() == Spacing::Alone =>
+                {
+                    default = Some(TokenStream2::new());
+                    continue;
+                }
+                _ => (),
+            }
+
+            if p.spacing() == Spacing::Joint {
+                prev_joint = Some(p.as_char());
+            }
+        } else {
+            prev_joint = None;
+        }
+
+        arg.extend(std::iter::once(tt));
+    }
+
+    finish_arg(&mut arg, &mut default)?;
+
+    Ok((inputs, defaults))
+}
+
+/// Renders a default value as Julia code.
+fn julia_default_value(default: &TokenStream2) -> String {
+    let rendered = default.to_string();
+
+    // Quoted symbols like `:foo` are rendered as `: foo`, which isn't valid Julia code.
+    match rendered.strip_prefix(": ") {
+        Some(sym) => format!(":{sym}"),
+        None => rendered,
+    }
+}
+
 impl ExportedFunction {
+    /// Returns the expression that defines the methods that provide the default arguments of
+    /// this function for the argument types `inputs`, or `None` if no argument has a default
+    /// value.
+    fn default_args_fragment(&self, inputs: &Punctuated<FnArg, Comma>) -> Result<Option<Expr>> {
+        let Some(first_default) = self.defaults.iter().position(Option::is_some) else {
+            return Ok(None);
+        };
+
+        let mut name = self
+            .name_override
+            .as_ref()
+            .and_then(|parts| parts.last())
+            .unwrap_or(&self.func.ident)
+            .to_string();
+
+        if self.exclamation_mark_token.is_some() {
+            name.push('!')
+        }
+
+        let arg_names = inputs.iter().enumerate().map(|(idx, arg)| match arg {
+            FnArg::Typed(ty) => match ty.pat.as_ref() {
+                Pat::Ident(ident) => ident.ident.to_string(),
+                _ => format!("arg{idx}"),
+            },
+            _ => format!("arg{idx}"),
+        });
+
+        let defaults = self.defaults[first_default..]
+            .iter()
+            .map(|default| julia_default_value(default.as_ref().unwrap()));
+
+        let (_, julia_arg_types) = arg_type_fragments(inputs)?;
+        let override_module_fragment = override_module_fragment(&self.name_override);
+        let env_expr = type_var_env_fragment(&self.type_var_env);
+
+        let ex = parse_quote! {
+            frame.scope(|mut frame| {
+                let env = #env_expr;
+                let module = #override_module_fragment;
+                let arg_types = [#(#julia_arg_types.as_value()),*];
+
+                ::jlrs::runtime::handle::ccall::CCall::define_default_methods(
+                    &mut frame,
+                    module,
+                    #name,
+                    env.to_svec(),
+                    &[#(#arg_names),*],
+                    &arg_types,
+                    &[#(#defaults),*],
+                )
+            })?
+        };
+
+        Ok(Some(ex))
+    }
+
+    fn default_args_with_env(
+        &self,
+        generic: &GenericEnvironment,
+        env: Option<&ParameterEnvironment>,
+    ) -> Result<Vec<Expr>> {
+        let env = ParameterEnvironment::new(generic, env);
+        let n_combinations = env.n_combinations();
+
+        let mut list = ParameterList::new(&env);
+        let mut resolver = list.resolver();
+
+        let mut exprs = Vec::new();
+        for i in 0..n_combinations {
+            env.nth_combination(&mut list, i);
+            list.resolve(&mut resolver);
+
+            let inputs = resolver.apply(&self.func.inputs);
+            exprs.extend(self.default_args_fragment(&inputs)?);
+        }
+
+        Ok(exprs)
+    }
+}
+
+/// Returns the expression that creates the `TypeVarEnv` of a function.
+fn type_var_env_fragment(type_var_env: &Option<TypeVarEnv>) -> Expr {
+    if let Some(x) = type_var_env.as_ref() {
+        match &x.macro_or_type {
+            MacroOrType::Macro(m) => {
+                parse_quote! { <#m as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
+            }
+            MacroOrType::Type(t) => {
+                parse_quote! { <#t as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
+            }
+        }
+    } else {
+        parse_quote! { ::jlrs::data::types::construct_type::TypeVarEnv::empty(&frame) }
+    }
+}
+
+impl ExportedFunction {
+    fn init_context(&self) -> String {
+        format!("fn {}", self.func.ident)
+    }
+
     fn init_with_env(
         &self,
         generic: &GenericEnvironment,
         env: Option<&ParameterEnvironment>,
         offset: &mut usize,
         gc_safe: bool,
+        throws: bool,
     ) -> Result<Expr> {
         let n_args = self.func.inputs.len();
         let name_ident = &self.func.ident;
@@ -305,7 +545,15 @@ impl ExportedFunction {
 
             let inputs = resolver.apply(&self.func.inputs);
             let (ccall_arg_types, function_arg_types) = arg_type_fragments(&inputs)?;
-            let ret_ty = resolver.apply(&self.func.output);
+            let fn_ret_ty = resolver.apply(&self.func.output);
+            let is_async = is_async(&self.func, throws)?;
+            let ret_ty = if throws {
+                throws_return_type(&fn_ret_ty)?
+            } else if is_async {
+                async_return_type(&fn_ret_ty)?
+            } else {
+                fn_ret_ty.clone()
+            };
             let (ccall_ret_type, julia_ret_type) = return_type_fragments(&ret_ty);
             let new_ret_ty = as_return_as(&ret_ty);
             let ret_ty = take_type(ret_ty.clone());
@@ -320,12 +568,18 @@ impl ExportedFunction {
             });
             let names = Punctuated::<_, Comma>::from_iter(names);
 
-            let call_expr: Expr = if gc_safe {
+            let mut call_expr: Expr = if gc_safe && !is_async {
                 parse_quote! {  ::jlrs::memory::gc::gc_safe(|| #name_ident(#names)) }
             } else {
                 parse_quote! { #name_ident(#names) }
             };
 
+            if throws {
+                call_expr = throws_call_expr(call_expr, &fn_ret_ty);
+            } else if is_async {
+                call_expr = async_call_expr(call_expr, &fn_ret_ty);
+            }
+
             let span = self.func.span();
             let invoke_fn: ItemFn = parse_quote_spanned! {
                 span=> unsafe extern "C" fn invoke(#args) #new_ret_ty {
@@ -334,18 +588,7 @@ impl ExportedFunction {
                 }
             };
 
-            let env_expr: Expr = if let Some(x) = self.type_var_env.as_ref() {
-                match &x.macro_or_type {
-                    MacroOrType::Macro(m) => {
-                        parse_quote! { <#m as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-                    }
-                    MacroOrType::Type(t) => {
-                        parse_quote! { <#t as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-                    }
-                }
-            } else {
-                parse_quote! { ::jlrs::data::types::construct_type::TypeVarEnv::empty(&frame) }
-            };
+            let env_expr = type_var_env_fragment(&self.type_var_env);
 
             let ex = parse_quote! {
                 {
@@ -479,6 +722,11 @@ impl Parse for ExportedMethod {
 }
 
 impl ExportedMethod {
+    fn init_context(&self) -> String {
+        let parent = self.parent.to_token_stream().to_string().replace(' ', "");
+        format!("fn {}::{}", parent, self.func.ident)
+    }
+
     fn init_with_env(
         &self,
         generic: &GenericEnvironment,
@@ -522,18 +770,7 @@ impl ExportedMethod {
 
             let (ccall_arg_types, julia_arg_types, invoke_fn) = method_arg_type_fragments_in_env(self, &resolver, untracked_self, gc_safe);
 
-            let env_expr: Expr = if let Some(x) = self.type_var_env.as_ref() {
-                match &x.macro_or_type {
-                    MacroOrType::Macro(m) => {
-                        parse_quote! { <#m as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-                    }
-                    MacroOrType::Type(t) => {
-                        parse_quote! { <#t as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-                    }
-                }
-            } else {
-                parse_quote! { ::jlrs::data::types::construct_type::TypeVarEnv::empty(&frame) }
-            };
+            let env_expr = type_var_env_fragment(&self.type_var_env);
 
             let ex = parse_quote! {
                 {
@@ -632,6 +869,12 @@ struct ExportedConst {
     name_override: Option<Ident>,
 }
 
+impl ExportedConst {
+    fn init_context(&self) -> String {
+        format!("const {}", self.name)
+    }
+}
+
 impl Parse for ExportedConst {
     fn parse(input: ParseStream) -> Result<Self> {
         let const_token = input.parse()?;
@@ -674,6 +917,12 @@ struct ExportedGlobal {
     name_override: Option<Ident>,
 }
 
+impl ExportedGlobal {
+    fn init_context(&self) -> String {
+        format!("static {}", self.name)
+    }
+}
+
 impl Parse for ExportedGlobal {
     fn parse(input: ParseStream) -> Result<Self> {
         let static_token = input.parse()?;
@@ -714,6 +963,12 @@ struct ExportedAlias {
     ty: Type,
 }
 
+impl ExportedAlias {
+    fn init_context(&self) -> String {
+        format!("type {}", self.name)
+    }
+}
+
 impl Parse for ExportedAlias {
     fn parse(input: ParseStream) -> Result<Self> {
         let type_token = input.parse()?;
@@ -888,7 +1143,7 @@ impl<'a> GenericEnvironment<'a> {
             .copied()
             .filter(|it| it.is_exported_type())
             .map(|it| it.get_exported_type())
-            .map(|it| it.init_with_env(self, env));
+            .map(|it| with_init_context(it.init_context(), it.init_with_env(self, env)));
 
         out.extend(exprs);
     }
@@ -915,7 +1170,7 @@ impl<'a> GenericEnvironment<'a> {
             .copied()
             .filter(|it| it.is_exported_type())
             .map(|it| it.get_exported_type())
-            .map(|it| it.reinit_with_env(self, env));
+            .map(|it| with_init_context(it.init_context(), it.reinit_with_env(self, env)));
 
         out.extend(exprs);
     }
@@ -941,10 +1196,13 @@ impl<'a> GenericEnvironment<'a> {
             .map(|it| it.get_exported_fn())
             .map(|it| {
                 let mut gc_safe = false;
+                let mut throws = false;
                 if let Some(attrs) = it.1 {
                     gc_safe = has_outer_path_attr(attrs, "gc_safe");
+                    throws = has_outer_path_attr(attrs, "throws");
                 }
-                it.0.init_with_env(self, env, offset, gc_safe)
+                it.0.init_with_env(self, env, offset, gc_safe, throws)
+                    .map(|ex| with_init_context(it.0.init_context(), ex))
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -958,6 +1216,24 @@ impl<'a> GenericEnvironment<'a> {
         Ok(ex)
     }
 
+    fn default_args_fragments_env(
+        &'a self,
+        env: Option<&ParameterEnvironment<'a>>,
+        out: &mut Vec<Expr>,
+    ) -> Result<()> {
+        for sub_env in self.subenvs.iter() {
+            let env = ParameterEnvironment::new(self, env);
+            sub_env.default_args_fragments_env(Some(&env), out)?;
+        }
+
+        for item in self.items.iter().copied().filter(|it| it.is_exported_fn()) {
+            let exprs = item.get_exported_fn().0.default_args_with_env(self, env)?;
+            out.extend(exprs);
+        }
+
+        Ok(())
+    }
+
     fn init_method_fragments_env(
         &'a self,
         env: Option<&ParameterEnvironment<'a>>,
@@ -985,6 +1261,7 @@ impl<'a> GenericEnvironment<'a> {
                     gc_safe = has_outer_path_attr(attrs, "gc_safe");
                 }
                 it.0.init_with_env(self, env, offset, untracked_self, gc_safe)
+                    .map(|ex| with_init_context(it.0.init_context(), ex))
             }) // TODO: attrs
             .collect::<Result<Vec<_>>>()?;
 
@@ -1239,7 +1516,7 @@ impl Parse for ModuleItem {
             input.parse().map(ModuleItem::InitFn)
         } else if lookahead.peek(Token![struct]) {
             input.parse().map(ModuleItem::ExportedType)
-        } else if lookahead.peek(Token![fn]) {
+        } else if lookahead.peek(Token![fn]) || lookahead.peek(Token![async]) {
             input.parse().map(ModuleItem::ExportedFunction)
         } else if lookahead.peek(Token![in]) {
             input.parse().map(ModuleItem::ExportedMethod)
@@ -1290,6 +1567,7 @@ impl JuliaModule {
         let alias_fragments = AliasFragments::generate(&self, init_fn);
         let global_fragments = GlobalFragments::generate(&self, init_fn);
         let doc_fragments = DocFragments::generate(&self, init_fn)?;
+        let default_arg_fragments = DefaultArgFragments::generate(&self, init_fn)?;
 
         let type_init_fn = type_fragments.type_init_fn;
         let type_init_fn_ident = type_fragments.type_init_ident;
@@ -1315,6 +1593,8 @@ impl JuliaModule {
         let global_init_fn_ident = global_fragments.global_init_ident;
         let doc_init_fn = doc_fragments.init_docs_fn;
         let doc_init_fn_ident = doc_fragments.init_docs_fn_ident;
+        let default_args_init_fn = default_arg_fragments.default_args_init_fn;
+        let default_args_init_fn_ident = default_arg_fragments.default_args_init_ident;
 
         let invoke_type_init: Expr = parse_quote! {
             if precompiling == 1 {
@@ -1344,6 +1624,14 @@ impl JuliaModule {
             }
         };
 
+        let invoke_default_args_init: Expr = parse_quote! {
+            if precompiling == 1 {
+                if let Err(exc) = #default_args_init_fn_ident(&mut frame, module) {
+                    return Ok(Err(exc));
+                }
+            }
+        };
+
         let invoke_global_init: Expr = parse_quote! {
             if precompiling == 1 {
                 #global_init_fn_ident(&mut frame, module);
@@ -1381,59 +1669,85 @@ impl JuliaModule {
 
                 #doc_init_fn
 
+                #default_args_init_fn
+
                 static IS_INIT: ::std::sync::atomic::AtomicBool = ::std::sync::atomic::AtomicBool::new(false);
                 if IS_INIT.compare_exchange(false, true, ::std::sync::atomic::Ordering::Relaxed, ::std::sync::atomic::Ordering::Relaxed).is_err() {
                     let unrooted = <::jlrs::data::managed::module::Module as ::jlrs::data::managed::Managed>::unrooted_target(module);
                     return ::jlrs::data::managed::value::Value::nothing(&unrooted).as_ref().leak();
                 }
 
-                let mut stack_frame = ::jlrs::memory::stack_frame::StackFrame::new();
-                let mut ccall = ::jlrs::runtime::handle::ccall::CCall::new(&mut stack_frame);
-
-                ccall.init_jlrs(&::jlrs::InstallJlrsCore::Default);
-
-                ccall.scope(|mut frame| {
-                    let wrap_mod = ::jlrs::data::managed::module::Module::jlrs_core(&frame)
-                        .submodule(&frame, "Wrap")
-                        .unwrap()
-                        .as_managed();
-
-                    let function_info_ty = wrap_mod
-                        .global(&frame, "JlrsFunctionInfo")
-                        .unwrap()
-                        .as_value()
-                        .cast_unchecked::<::jlrs::data::managed::datatype::DataType>();
-
-                    let doc_item_ty = wrap_mod
-                        .global(&frame, "DocItem")
-                        .unwrap()
-                        .as_value()
-                        .cast_unchecked::<::jlrs::data::managed::datatype::DataType>();
+                // Panics are caught and converted to an exception that names the item that
+                // failed to initialize. The exception is thrown after all local data has been
+                // dropped.
+                let res = ::jlrs::runtime::handle::ccall::CCall::catch_init(|| {
+                    let mut stack_frame = ::jlrs::memory::stack_frame::StackFrame::new();
+                    let mut ccall = ::jlrs::runtime::handle::ccall::CCall::new(&mut stack_frame);
+
+                    ccall.init_jlrs(&::jlrs::InstallJlrsCore::Default);
+
+                    ccall.scope(|mut frame| {
+                        ::jlrs::runtime::handle::ccall::CCall::set_init_context("JlrsCore.Wrap");
+                        let wrap_mod = ::jlrs::data::managed::module::Module::jlrs_core(&frame)
+                            .submodule(&frame, "Wrap")
+                            .unwrap()
+                            .as_managed();
+
+                        let function_info_ty = wrap_mod
+                            .global(&frame, "JlrsFunctionInfo")
+                            .unwrap()
+                            .as_value()
+                            .cast_unchecked::<::jlrs::data::managed::datatype::DataType>();
+
+                        let doc_item_ty = wrap_mod
+                            .global(&frame, "DocItem")
+                            .unwrap()
+                            .as_value()
+                            .cast_unchecked::<::jlrs::data::managed::datatype::DataType>();
+
+                        let module_info_ty = wrap_mod
+                            .global(&frame, "JlrsModuleInfo")
+                            .unwrap()
+                            .as_value()
+                            .cast_unchecked::<::jlrs::data::managed::datatype::DataType>();
+
+                        #invoke_type_init;
+                        #invoke_generic_type_init;
+                        #invoke_const_init;
+                        #invoke_global_init;
+                        #invoke_alias_init;
+
+                        let mut arr = ::jlrs::data::managed::array::Vector::new_for_unchecked(&mut frame, function_info_ty.as_value(), 0);
+                        #function_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
+                        #generic_function_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
+                        #method_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
+                        #generic_method_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
+                        ::jlrs::runtime::handle::ccall::CCall::set_init_context("default arguments");
+                        #invoke_default_args_init;
+
+                        ::jlrs::runtime::handle::ccall::CCall::set_init_context("documentation");
+                        let mut doc_items = ::jlrs::data::managed::array::Vector::new_for_unchecked(&mut frame, doc_item_ty.as_value(), 0);
+                        if precompiling == 1 {
+                            #doc_init_fn_ident(&mut frame, &mut doc_items, module, doc_item_ty);
+                        }
+                        Ok(Ok(module_info_ty.instantiate_unchecked(&frame, [arr.as_value(), doc_items.as_value()]).leak()))
+                    }).unwrap()
+                });
 
-                    let module_info_ty = wrap_mod
-                        .global(&frame, "JlrsModuleInfo")
-                        .unwrap()
-                        .as_value()
-                        .cast_unchecked::<::jlrs::data::managed::datatype::DataType>();
-
-                    #invoke_type_init;
-                    #invoke_generic_type_init;
-                    #invoke_const_init;
-                    #invoke_global_init;
-                    #invoke_alias_init;
-
-                    let mut arr = ::jlrs::data::managed::array::Vector::new_for_unchecked(&mut frame, function_info_ty.as_value(), 0);
-                    #function_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
-                    #generic_function_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
-                    #method_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
-                    #generic_method_init_fn_ident(&mut frame, &mut arr, module, function_info_ty);
-
-                    let mut doc_items = ::jlrs::data::managed::array::Vector::new_for_unchecked(&mut frame, doc_item_ty.as_value(), 0);
-                    if precompiling == 1 {
-                        #doc_init_fn_ident(&mut frame, &mut doc_items, module, doc_item_ty);
+                match res {
+                    Ok(Ok(module_info)) => module_info,
+                    // An exception thrown by Julia while initializing the module is rethrown.
+                    // Items may have been partially defined, so a failed module can't be
+                    // initialized again and later calls return `nothing`.
+                    Ok(Err(exc)) => {
+                        ::jlrs::runtime::handle::ccall::CCall::throw_exception(exc)
+                    }
+                    Err(msg) => {
+                        let unrooted = <::jlrs::data::managed::module::Module as ::jlrs::data::managed::Managed>::unrooted_target(module);
+                        let exc = ::jlrs::error::IntoJuliaError::into_julia_error(msg, unrooted).leak();
+                        ::jlrs::runtime::handle::ccall::CCall::throw_exception(exc)
                     }
-                    Ok(module_info_ty.instantiate_unchecked(&frame, [arr.as_value(), doc_items.as_value()]).leak())
-                }).unwrap()
+                }
             }
         };
 
@@ -1579,7 +1893,10 @@ impl FunctionFragments {
         let fragments = module
             .get_exported_functions()
             .enumerate()
-            .map(function_info_fragment)
+            .map(|it| {
+                let context = it.1 .0.init_context();
+                function_info_fragment(it).map(|ex| with_init_context(context, ex))
+            })
             .collect::<Result<Vec<_>>>()?;
 
         let init_functions_fn = parse_quote! {
@@ -1655,7 +1972,7 @@ impl MethodFragments {
         let method_init_fragments = module
             .get_exported_methods()
             .enumerate()
-            .map(method_info_fragment);
+            .map(|it| with_init_context(it.1 .0.init_context(), method_info_fragment(it)));
 
         let init_methods_fn = parse_quote! {
             unsafe fn #init_methods_fn_ident(
@@ -1733,7 +2050,9 @@ struct TypeFragments {
 impl TypeFragments {
     fn generate(info: &JuliaModule, init_fn: &InitFn) -> Self {
         let init_types_fn_ident = format_ident!("{}_types", init_fn.init_fn);
-        let init_types_fragments = info.get_exported_types().map(init_type_fragment);
+        let init_types_fragments = info
+            .get_exported_types()
+            .map(|it| with_init_context(it.init_context(), init_type_fragment(it)));
 
         let type_init_fn = parse_quote! {
             unsafe fn #init_types_fn_ident(
@@ -1751,7 +2070,9 @@ impl TypeFragments {
         };
 
         let reinit_types_fn_ident = format_ident!("{}_reinittypes", init_fn.init_fn);
-        let reinit_types_fragments = info.get_exported_types().map(reinit_type_fragment);
+        let reinit_types_fragments = info
+            .get_exported_types()
+            .map(|it| with_init_context(it.init_context(), reinit_type_fragment(it)));
 
         let type_reinit_fn = parse_quote! {
             unsafe fn #reinit_types_fn_ident(
@@ -1840,7 +2161,9 @@ impl ConstFragments {
     fn generate(module: &JuliaModule, init_fn: &InitFn) -> Self {
         let const_init_ident = format_ident!("{}_consts", init_fn.init_fn);
 
-        let const_init_fragments = module.get_exported_consts().map(const_info_fragment);
+        let const_init_fragments = module
+            .get_exported_consts()
+            .map(|it| with_init_context(it.init_context(), const_info_fragment(it)));
 
         let const_init_fn = parse_quote! {
             unsafe fn #const_init_ident(
@@ -1870,7 +2193,9 @@ impl AliasFragments {
     fn generate(module: &JuliaModule, init_fn: &InitFn) -> Self {
         let alias_init_ident = format_ident!("{}_aliases", init_fn.init_fn);
 
-        let const_init_fragments = module.get_exported_aliases().map(alias_info_fragment);
+        let const_init_fragments = module
+            .get_exported_aliases()
+            .map(|it| with_init_context(it.init_context(), alias_info_fragment(it)));
 
         let alias_init_fn = parse_quote! {
             unsafe fn #alias_init_ident(
@@ -1900,7 +2225,9 @@ impl GlobalFragments {
     fn generate(module: &JuliaModule, init_fn: &InitFn) -> Self {
         let global_init_ident = format_ident!("{}_globals", init_fn.init_fn);
 
-        let global_init_fragments = module.get_exported_globals().map(global_info_fragment);
+        let global_init_fragments = module
+            .get_exported_globals()
+            .map(|it| with_init_context(it.init_context(), global_info_fragment(it)));
 
         let global_init_fn = parse_quote! {
             unsafe fn #global_init_ident(
@@ -1921,6 +2248,48 @@ impl GlobalFragments {
     }
 }
 
+struct DefaultArgFragments {
+    default_args_init_fn: ItemFn,
+    default_args_init_ident: Ident,
+}
+
+impl DefaultArgFragments {
+    fn generate(module: &JuliaModule, init_fn: &InitFn) -> Result<Self> {
+        let default_args_init_ident = format_ident!("{}_default_args", init_fn.init_fn);
+
+        let mut default_args_init_fragments = Vec::new();
+        for (func, _) in module.get_exported_functions() {
+            default_args_init_fragments.extend(func.default_args_fragment(&func.func.inputs)?);
+        }
+
+        for generics in module.get_exported_generics() {
+            generics
+                .to_generic_environment()
+                .default_args_fragments_env(None, &mut default_args_init_fragments)?;
+        }
+
+        let default_args_init_fragments = default_args_init_fragments.into_iter().unique();
+
+        let default_args_init_fn = parse_quote! {
+            unsafe fn #default_args_init_ident(
+                frame: &mut ::jlrs::memory::target::frame::GcFrame,
+                module: ::jlrs::data::managed::module::Module,
+            ) -> ::std::result::Result<(), ::jlrs::data::managed::value::ValueRet> {
+                #(
+                    #default_args_init_fragments;
+                )*
+
+                Ok(())
+            }
+        };
+
+        Ok(DefaultArgFragments {
+            default_args_init_ident,
+            default_args_init_fn,
+        })
+    }
+}
+
 fn doc_info_fragment((index, info): (usize, &ItemWithAttrs)) -> Result<Expr> {
     match info.item.as_ref() {
         ModuleItem::InitFn(i) => Err(syn::Error::new_spanned(
@@ -2107,11 +2476,24 @@ fn function_info_fragment(
         rename.push('!')
     }
 
-    let ret_ty = &info.func.output;
-    let new_ret_ty = as_return_as(&ret_ty);
-    let ret_ty = take_type(ret_ty.clone());
+    let mut gc_safe = false;
+    let mut throws = false;
+    if let Some(attrs) = attrs {
+        gc_safe = has_outer_path_attr(attrs, "gc_safe");
+        throws = has_outer_path_attr(attrs, "throws");
+    }
 
-    let (ccall_ret_type, julia_ret_type) = return_type_fragments(&info.func.output);
+    let is_async = is_async(&info.func, throws)?;
+    let ret_ty = if throws {
+        throws_return_type(&info.func.output)?
+    } else if is_async {
+        async_return_type(&info.func.output)?
+    } else {
+        info.func.output.clone()
+    };
+    let new_ret_ty = as_return_as(&ret_ty);
+    let (ccall_ret_type, julia_ret_type) = return_type_fragments(&ret_ty);
+    let ret_ty = take_type(ret_ty);
 
     let ccall_arg_idx = 0..n_args;
     let julia_arg_idx = 0..n_args;
@@ -2125,17 +2507,18 @@ fn function_info_fragment(
     });
     let names = Punctuated::<_, Comma>::from_iter(names);
 
-    let mut gc_safe = false;
-    if let Some(attrs) = attrs {
-        gc_safe = has_outer_path_attr(attrs, "gc_safe");
-    }
-
-    let call_expr: Expr = if gc_safe {
+    let mut call_expr: Expr = if gc_safe && !is_async {
         parse_quote! {  ::jlrs::memory::gc::gc_safe(|| #name_ident(#names)) }
     } else {
         parse_quote! { #name_ident(#names) }
     };
 
+    if throws {
+        call_expr = throws_call_expr(call_expr, &info.func.output);
+    } else if is_async {
+        call_expr = async_call_expr(call_expr, &info.func.output);
+    }
+
     let span = info.func.span();
     let invoke_fn: ItemFn = parse_quote_spanned! {
         span=> unsafe extern "C" fn invoke(#args) #new_ret_ty {
@@ -2144,18 +2527,7 @@ fn function_info_fragment(
         }
     };
 
-    let env_expr: Expr = if let Some(x) = info.type_var_env.as_ref() {
-        match &x.macro_or_type {
-            MacroOrType::Macro(m) => {
-                parse_quote! { <#m as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-            }
-            MacroOrType::Type(t) => {
-                parse_quote! { <#t as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-            }
-        }
-    } else {
-        parse_quote! { ::jlrs::data::types::construct_type::TypeVarEnv::empty(&frame) }
-    };
+    let env_expr = type_var_env_fragment(&info.type_var_env);
 
     let expr = parse_quote! {
         {
@@ -2290,6 +2662,104 @@ fn return_type_fragments(ret_ty: &ReturnType) -> (Expr, Expr) {
     }
 }
 
+// Replaces the error type of a function annotated with `#[throws]`, `Result<T, E>` becomes
+// `Result<T, ValueRet>`. Aliases with a single parameter like `anyhow::Result<T>` are accepted
+// too, the error type is only used by `throws_call_expr`.
+fn throws_return_type(ret_ty: &ReturnType) -> Result<ReturnType> {
+    let err = || {
+        Error::new(
+            ret_ty.span(),
+            "functions annotated with #[throws] must return Result<T, E>",
+        )
+    };
+
+    let ReturnType::Type(_, ty) = ret_ty else {
+        return Err(err());
+    };
+
+    let Type::Path(path) = ty.as_ref() else {
+        return Err(err());
+    };
+
+    let last = path.path.segments.last().ok_or_else(err)?;
+    if last.ident != "Result" {
+        return Err(err());
+    }
+
+    let PathArguments::AngleBracketed(ref args) = last.arguments else {
+        return Err(err());
+    };
+
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+
+    match (types.next(), types.next(), types.next()) {
+        (Some(ok_ty), _, None) => Ok(parse_quote! {
+            -> ::std::result::Result<#ok_ty, ::jlrs::data::managed::value::ValueRet>
+        }),
+        _ => Err(err()),
+    }
+}
+
+// Converts the error returned by a function annotated with `#[throws]` to an exception, the
+// exception is thrown by `CCallReturn::return_or_throw`.
+fn throws_call_expr(call_expr: Expr, fn_ret_ty: &ReturnType) -> Expr {
+    // The return type has been checked by `throws_return_type`.
+    let fn_ret_ty = take_type(fn_ret_ty.clone());
+    parse_quote! {
+        match { let res: #fn_ret_ty = #call_expr; res } {
+            Ok(t) => Ok(t),
+            Err(e) => Err(::jlrs::runtime::handle::ccall::CCall::infallible_local_scope::<_, _, 1>(
+                |mut frame| ::jlrs::error::IntoJuliaError::into_julia_error(e, &mut frame).leak(),
+            )),
+        }
+    }
+}
+
+// Returns `true` if `func` is an `async fn`. These functions can't be annotated with
+// `#[throws]`, errors must be handled by the future itself.
+fn is_async(func: &Signature, throws: bool) -> Result<bool> {
+    match func.asyncness {
+        Some(asyncness) if throws => Err(Error::new(
+            asyncness.span(),
+            "async functions can't be annotated with #[throws]",
+        )),
+        Some(_) => Ok(true),
+        None => Ok(false),
+    }
+}
+
+// The generated function of an `async fn` returns a `BackgroundTask` that can be fetched to
+// get the output of the future.
+fn async_return_type(ret_ty: &ReturnType) -> Result<ReturnType> {
+    let ReturnType::Type(_, ty) = ret_ty else {
+        return Err(Error::new(
+            ret_ty.span(),
+            "async functions must return a value",
+        ));
+    };
+
+    Ok(parse_quote! { -> ::jlrs::data::managed::background_task::BackgroundTaskRet<#ty> })
+}
+
+// Spawns the future returned by an `async fn` on a background thread.
+fn async_call_expr(call_expr: Expr, fn_ret_ty: &ReturnType) -> Expr {
+    // The return type has been checked by `async_return_type`.
+    let fn_ret_ty = take_type(fn_ret_ty.clone());
+    parse_quote! {
+        ::jlrs::runtime::handle::ccall::CCall::infallible_local_scope::<_, _, 1>(|mut frame| {
+            let future = #call_expr;
+            ::jlrs::data::managed::background_task::spawn_background_future::<#fn_ret_ty, _, _>(
+                &mut frame,
+                async move { Ok(future.await) },
+            )
+            .leak()
+        })
+    }
+}
+
 fn arg_type_fragments<'a>(
     inputs: &'a Punctuated<FnArg, Comma>,
 ) -> Result<(
@@ -2342,6 +2812,21 @@ fn arg_type_fragments<'a>(
     Ok((ccall_arg_types, julia_arg_types))
 }
 
+// Set the item that is being initialized before `fragment` is evaluated, so it can be named if
+// initialization fails.
+fn with_init_context(context: String, fragment: Expr) -> Expr {
+    parse_quote! {
+        {
+            ::jlrs::runtime::handle::ccall::CCall::set_init_context(#context);
+            #fragment
+        }
+    }
+}
+
+fn path_to_string(path: &Path) -> String {
+    path.segments.iter().map(|s| s.ident.to_string()).join("::")
+}
+
 fn init_type_fragment(info: &ExportedType) -> Expr {
     let override_module_fragment = override_module_fragment(&info.name_override);
     let name_ident = &info.name.segments.last().unwrap().ident;
@@ -2434,18 +2919,7 @@ fn method_info_fragment<'a>(
     let (ccall_arg_types, julia_arg_types, invoke_fn) =
         method_arg_type_fragments(info, untracked_self, gc_safe);
 
-    let env_expr: Expr = if let Some(x) = info.type_var_env.as_ref() {
-        match &x.macro_or_type {
-            MacroOrType::Macro(m) => {
-                parse_quote! { <#m as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-            }
-            MacroOrType::Type(t) => {
-                parse_quote! { <#t as ::jlrs::data::types::construct_type::TypeVars>::into_env(&mut frame) }
-            }
-        }
-    } else {
-        parse_quote! { ::jlrs::data::types::construct_type::TypeVarEnv::empty(&frame) }
-    };
+    let env_expr = type_var_env_fragment(&info.type_var_env);
 
     parse_quote! {
         {
@@ -13,15 +13,35 @@ using Test
     @test JuliaModuleTest.takes_usize_returns_usize(UInt(3)) == 4
     @inferred JuliaModuleTest.takes_usize_returns_usize(UInt(3))
 
+    @test JuliaModuleTest.scale(3.0, 3.0) == 9.0
+    @test JuliaModuleTest.scale(3.0) == 6.0
+    @test hasmethod(JuliaModuleTest.scale, Tuple{Float64})
+    @test !hasmethod(JuliaModuleTest.scale, Tuple{String})
+
+    task = JuliaModuleTest.async_adds_one(UInt(3))
+    @test task isa JlrsCore.BackgroundTask{UInt}
+    @test fetch(task) == 4
+
     @test JuliaModuleTest.takes_ref_usize(UInt(3)) == 4
     @test JuliaModuleTest.takes_ref_module(Main) == 0
     @test JuliaModuleTest.takes_ref_any(Main) == 0
     @test JuliaModuleTest.takes_ref_number(1) == 0
 
+    @test JuliaModuleTest.takes_c_str("hello") == 5
+    @test_throws ArgumentError JuliaModuleTest.takes_c_str("hel\0lo")
+
     @test JuliaModuleTest.returns_jlrs_result(false) == 3
     @inferred JuliaModuleTest.returns_jlrs_result(false)
     @test_throws JlrsCore.JlrsError JuliaModuleTest.returns_jlrs_result(true)
 
+    @test JuliaModuleTest.parses_u64("42") == 42
+    @inferred JuliaModuleTest.parses_u64("42")
+    @test_throws ErrorException JuliaModuleTest.parses_u64("forty-two")
+
+    @test JuliaModuleTest.parses_f64_anyhow("4.5") == 4.5
+    @inferred JuliaModuleTest.parses_f64_anyhow("4.5")
+    @test_throws ErrorException JuliaModuleTest.parses_f64_anyhow("four")
+
     @test JuliaModuleTest.returns_ref_bool()
     @inferred JuliaModuleTest.returns_ref_bool()
 
@@ -42,6 +62,9 @@ end
     @test JuliaModuleTest.takes_typed_ranked_array(Vector{UInt32}()) == 4
     @inferred JuliaModuleTest.takes_typed_ranked_array(Vector{UInt32}())
 
+    @test JuliaModuleTest.sums_typed_array(UInt8[1, 2, 3]) == 6
+    @inferred JuliaModuleTest.sums_typed_array(UInt8[1, 2, 3])
+
     @test JuliaModuleTest.takes_typed_value(UInt(3)) == 3
     @inferred JuliaModuleTest.takes_typed_value(UInt(3))
 
@@ -174,6 +197,16 @@ end
     @test JuliaModuleTest.popaque_get(p64) === Float64(1.0)
 end
 
+@testset "RustIter" begin
+    it = JuliaModuleTest.squares(4)
+    items = Int[]
+    for x in it
+        push!(items, x)
+    end
+    @test items == [0, 1, 4, 9]
+    @test iterate(it) === nothing
+end
+
 @testset "has_generic" begin
     @test JuliaModuleTest.has_generic(Float32(1.0)) == Float32(1.0)
     @test JuliaModuleTest.has_generic(Float64(1.0)) == Float64(1.0)
@@ -188,6 +221,10 @@ end
 
     arr = Int[]
     @test_throws MethodError JuliaModuleTest.takes_generics_from_env(arr, 1)
+
+    @test JuliaModuleTest.returns_last_generic(1, 2, 3) == 3
+    @test JuliaModuleTest.returns_last_generic(1, 2) == 1
+    @test !hasmethod(JuliaModuleTest.returns_last_generic, Tuple{Int, Float64})
 end
 
 @testset "POpaqueTwo" begin
@@ -46,6 +46,12 @@ pub fn takes_typed_array(a: TypedArray<u32>) -> usize {
     }
 }
 
+// Doesn't call into Julia, exported with #[gc_safe]
+pub fn sums_typed_array(a: TypedArray<u8>) -> u64 {
+    // Safety: no mutable accessors to this data exist.
+    unsafe { a.bits_data().as_slice().iter().map(|&x| x as u64).sum() }
+}
+
 pub fn takes_typed_ranked_array(a: TypedRankedArray<u32, 1>) -> usize {
     let elty = a.element_type();
 
@@ -1,4 +1,8 @@
-use jlrs::{error::JlrsResult, prelude::Bool};
+use std::num::ParseIntError;
+
+use anyhow::Context;
+
+use jlrs::{data::managed::ccall_str::CCallStr, error::JlrsResult, prelude::Bool};
 
 pub fn returns_jlrs_result(throw_err: Bool) -> JlrsResult<i32> {
     if throw_err.as_bool() {
@@ -7,3 +11,15 @@ pub fn returns_jlrs_result(throw_err: Bool) -> JlrsResult<i32> {
         Ok(3)
     }
 }
+
+pub fn parses_u64(s: CCallStr) -> Result<u64, ParseIntError> {
+    s.as_c_str().to_string_lossy().parse()
+}
+
+pub fn parses_f64_anyhow(s: CCallStr) -> anyhow::Result<f64> {
+    let s = s.as_c_str().to_str()?;
+    let n = s
+        .parse()
+        .with_context(|| format!("cannot parse {s:?} as f64"))?;
+    Ok(n)
+}
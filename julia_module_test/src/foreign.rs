@@ -9,6 +9,7 @@ use jlrs::{
         types::{
             construct_type::ConstructType,
             foreign_type::{ForeignType, OpaqueType, ParametricBase, ParametricVariant},
+            rust_iter::RustIter,
         },
     },
     impl_type_parameters, impl_variant_parameters,
@@ -163,3 +164,7 @@ impl UnexportedType {
         1
     }
 }
+
+pub fn squares(n: i64) -> TypedValueRet<RustIter<i64>> {
+    RustIter::new_ret((0..n).map(|i| i * i))
+}
@@ -1,6 +1,9 @@
 use jlrs::{
     data::{
-        managed::value::typed::{TypedValue, TypedValueRet},
+        managed::value::{
+            typed::{TypedValue, TypedValueRet},
+            ValueRet,
+        },
         types::abstract_type::{AbstractArray, AbstractFloat},
     },
     prelude::*,
@@ -87,6 +90,15 @@ pub type GenericEnv = tvars!(
 );
 pub fn takes_generics_from_env(_array: TypedValue<tvar!('A')>, _data: TypedValue<tvar!('T')>) {}
 
+pub type SharedEnv = tvars!(tvar!('T'));
+pub fn returns_last_generic<'scope>(
+    _a: TypedValue<'scope, 'static, tvar!('T')>,
+    _b: TypedValue<'scope, 'static, tvar!('T')>,
+    c: TypedValue<'scope, 'static, tvar!('T')>,
+) -> ValueRet {
+    c.as_value().as_ref().leak()
+}
+
 pub fn has_generic<T>(t: T) -> T {
     t
 }
@@ -7,3 +7,11 @@ pub fn takes_no_args_returns_usize() -> usize {
 pub fn takes_usize_returns_usize(a: usize) -> usize {
     a + 1
 }
+
+pub fn scale(x: f64, factor: f64) -> f64 {
+    x * factor
+}
+
+pub async fn async_adds_one(a: usize) -> usize {
+    a + 1
+}
@@ -1,8 +1,11 @@
+use std::num::ParseIntError;
+
 use jlrs::{
     data::{
         managed::{
             array::{ArrayRet, RankedArrayRet, TypedArrayRet, TypedRankedArrayRet},
             ccall_ref::{CCallRef, CCallRefRet},
+            ccall_str::CCallStr,
             value::{
                 typed::{TypedValue, TypedValueRet},
                 ValueRet,
@@ -11,6 +14,7 @@ use jlrs::{
         types::{
             abstract_type::{AnyType, Number},
             construct_type::{ArrayTypeConstructor, ConstantIsize},
+            rust_iter::RustIter,
         },
     },
     prelude::*,
@@ -42,14 +46,19 @@ julia_module! {
     fn takes_no_args_returns_usize() -> usize;
 
     fn takes_usize_returns_usize(a: usize) -> usize;
+    fn scale(x: f64, factor: f64 = 2.0) -> f64;
+    async fn async_adds_one(a: usize) -> usize;
     fn takes_array(a: Array) -> usize;
     fn takes_ranked_array(a: RankedArray<1>) -> usize;
     fn takes_typed_array(a: TypedArray<u32>) -> usize;
     fn takes_typed_ranked_array(a: TypedRankedArray<u32, 1>) -> usize;
+    #[gc_safe]
+    fn sums_typed_array(a: TypedArray<u8>) -> u64;
     fn takes_ref_usize(usize_ref: CCallRef<usize>) -> usize;
     fn takes_ref_any(value_ref: CCallRef<AnyType>) -> usize;
     fn takes_ref_module(module_ref: CCallRef<Module>) -> usize;
     fn takes_ref_number(value_ref: CCallRef<Number>) -> usize;
+    fn takes_c_str(s: CCallStr) -> usize;
     fn takes_typed_value(a: TypedValue<usize>) -> usize;
     fn returns_array(dt: DataType) -> ArrayRet;
     fn returns_rank0_array(dt: DataType) -> RankedArrayRet<0>;
@@ -59,9 +68,18 @@ julia_module! {
     fn returns_typed_array() -> TypedArrayRet<f32>;
     fn returns_typed_rank2_array() -> TypedRankedArrayRet<f32, 2>;
     fn returns_jlrs_result(throw_err: Bool) -> JlrsResult<i32>;
+    #[throws]
+    fn parses_u64(s: CCallStr) -> Result<u64, ParseIntError>;
+    #[throws]
+    fn parses_f64_anyhow(s: CCallStr) -> anyhow::Result<f64>;
     fn returns_ref_bool() -> CCallRefRet<bool>;
     fn returns_typed_value() -> TypedValueRet<bool>;
     fn takes_generics_from_env(array: TypedValue<tvar!('A')>, data: TypedValue<tvar!('T')>) use GenericEnv;
+    fn returns_last_generic(
+        a: TypedValue<'_, 'static, tvar!('T')>,
+        b: TypedValue<'_, 'static, tvar!('T')>,
+        c: TypedValue<'_, 'static, tvar!('T')> = a,
+    ) -> ValueRet use SharedEnv;
     fn takes_generic_typed_ranked_arrays_ctor(
         a: TypedValue<ArrayTypeConstructor<tvar!('T'), ConstantIsize<1>>>,
         _b: TypedValue<ArrayTypeConstructor<tvar!('T'), ConstantIsize<2>>>,
@@ -162,4 +180,12 @@ julia_module! {
 
     type POpaque64 = POpaque<f64>;
     in POpaque<f64> fn new(value: f64) -> TypedValueRet<POpaque<f64>> as POpaque64;
+
+    for T in [i64] {
+        struct RustIter<T>;
+        in RustIter<T> fn iterate(&mut self) -> ValueRet as Base.iterate;
+        in RustIter<T> fn iterate_with_state(&mut self, state: Value) -> ValueRet as Base.iterate;
+    };
+
+    fn squares(n: i64) -> TypedValueRet<RustIter<i64>>;
 }
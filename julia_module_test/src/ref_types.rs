@@ -1,6 +1,9 @@
 use jlrs::{
     data::{
-        managed::ccall_ref::{CCallRef, CCallRefRet},
+        managed::{
+            ccall_ref::{CCallRef, CCallRefRet},
+            ccall_str::CCallStr,
+        },
         types::abstract_type::{AnyType, Number},
     },
     prelude::{Managed, Module, Value},
@@ -26,6 +29,10 @@ pub fn takes_ref_number(value_ref: CCallRef<Number>) -> usize {
     0
 }
 
+pub fn takes_c_str(s: CCallStr) -> usize {
+    s.as_c_str().to_bytes().len()
+}
+
 pub fn returns_ref_bool() -> CCallRefRet<bool> {
     let weak_handle = unsafe { weak_handle_unchecked!() };
 